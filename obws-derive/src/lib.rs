@@ -0,0 +1,380 @@
+//! Derive macros for [obws](https://docs.rs/obws). Use them through the re-exports in the
+//! `obws::derive` module with the `derive` feature enabled, instead of depending on this crate
+//! directly.
+
+#![warn(missing_docs, rust_2018_idioms, clippy::all)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Field, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Result, Type,
+};
+
+/// Implement `SourceKindSettings`, `Serialize` and `Deserialize` for typed source settings, for
+/// example of sources from third-party plugins. The settings can then be used with
+/// `Scenes::add_source` and `Sources::get_source_settings` like the built-in ones.
+///
+/// ```ignore
+/// use chrono::Duration;
+/// use obws::derive::SourceSettings;
+/// use obws::requests::RGBA8;
+///
+/// #[derive(SourceSettings)]
+/// #[obs(kind = "my_plugin_source")]
+/// struct MyPluginSource {
+///     url: String,
+///     #[obs(rename = "refresh_interval", duration_millis)]
+///     refresh: Duration,
+///     #[obs(rgba_inverse)]
+///     background: Option<RGBA8>,
+/// }
+///
+/// assert_eq!("my_plugin_source", MyPluginSource::KIND);
+/// ```
+///
+/// The struct requires the `#[obs(kind = "...")]` attribute, which defines the `KIND` constant.
+/// Fields are (de)serialized with their name as key and can be customized with these
+/// attributes:
+///
+/// - `rename = "..."`: Use a different key.
+/// - `default`: Use the default value if the key is missing when deserializing.
+/// - `duration_millis`: A `chrono::Duration` as whole milliseconds.
+/// - `rgba_inverse`: An `RGBA8` color as integer in the inverse order (ABGR). Fields of type
+///   `obws::common::Color` use this encoding already and need no attribute.
+/// - `bitflags`: Flags as `u8` integer, for types that implement `Into<u8>` and `TryFrom<u8>`.
+///
+/// `Option` fields are skipped when serializing if they're `None`, and are `None` if the key is
+/// missing when deserializing. `Deserialize` is only implemented for structs without generics.
+#[proc_macro_derive(SourceSettings, attributes(obs))]
+pub fn derive_source_settings(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Special (de)serialization of a field, matching the conventions of OBS.
+#[derive(Clone, Copy)]
+enum Convention {
+    /// Plain serde (de)serialization.
+    None,
+    /// `chrono::Duration` as whole milliseconds.
+    DurationMillis,
+    /// `RGBA8` as integer in inverse order (ABGR).
+    RgbaInverse,
+    /// Bitflags as `u8` integer.
+    Bitflags,
+}
+
+struct SettingsField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    key: String,
+    /// Inner type if the field is an `Option`.
+    option: Option<&'a Type>,
+    convention: Convention,
+    default: bool,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let kind = parse_kind(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(parse_field)
+                .collect::<Result<Vec<_>>>()?,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "SourceSettings can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "SourceSettings can only be derived for structs",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let serialize = expand_serialize(&input, &fields);
+    let deserialize = if input.generics.params.is_empty() {
+        expand_deserialize(&input, &fields)
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Internal kind of the source these settings belong to.
+            pub const KIND: &'static str = #kind;
+        }
+
+        impl #impl_generics ::obws::requests::SourceKindSettings for #ident #ty_generics
+        #where_clause
+        {
+            fn kind(&self) -> &str {
+                Self::KIND
+            }
+        }
+
+        #serialize
+        #deserialize
+    })
+}
+
+fn expand_serialize(input: &DeriveInput, fields: &[SettingsField<'_>]) -> TokenStream2 {
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let len = fields.len();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = fields.iter().map(|field| {
+        let ident = field.ident;
+        let key = &field.key;
+        let value = wrap_ser(field.convention, quote! { value });
+
+        if field.option.is_some() {
+            quote! {
+                match &self.#ident {
+                    ::std::option::Option::Some(value) => {
+                        state.serialize_field(#key, &#value)?;
+                    }
+                    ::std::option::Option::None => state.skip_field(#key)?,
+                }
+            }
+        } else {
+            quote! {
+                let value = &self.#ident;
+                state.serialize_field(#key, &#value)?;
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::obws::__private::serde::Serialize for #ident #ty_generics
+        #where_clause
+        {
+            fn serialize<__S>(
+                &self,
+                serializer: __S,
+            ) -> ::std::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::obws::__private::serde::Serializer,
+            {
+                use ::obws::__private::serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct(#name, #len)?;
+                #(#fields)*
+                state.end()
+            }
+        }
+    }
+}
+
+fn wrap_ser(convention: Convention, value: TokenStream2) -> TokenStream2 {
+    match convention {
+        Convention::None => value,
+        Convention::DurationMillis => quote! { ::obws::common::DurationMillis::from(*#value) },
+        Convention::RgbaInverse => quote! { ::obws::__private::RgbaInverseSer(#value) },
+        Convention::Bitflags => quote! { ::obws::__private::BitflagsSer(#value) },
+    }
+}
+
+fn expand_deserialize(input: &DeriveInput, fields: &[SettingsField<'_>]) -> TokenStream2 {
+    let ident = &input.ident;
+
+    let mirror_fields = fields.iter().map(|field| {
+        let ident = field.ident;
+        let key = &field.key;
+        let ty = mirror_type(field);
+        let default = if field.default || field.option.is_some() {
+            quote! { #[serde(default)] }
+        } else {
+            TokenStream2::new()
+        };
+
+        quote! {
+            #[serde(rename = #key)]
+            #default
+            #ident: #ty,
+        }
+    });
+
+    let assignments = fields.iter().map(|field| {
+        let ident = field.ident;
+        let value = match (field.convention, field.option.is_some() || field.default) {
+            (Convention::None, _) => quote! { mirror.#ident },
+            (_, false) => quote! { mirror.#ident.0 },
+            (_, true) if field.option.is_some() => quote! { mirror.#ident.map(|value| value.0) },
+            (_, true) => quote! { mirror.#ident.map(|value| value.0).unwrap_or_default() },
+        };
+
+        quote! { #ident: #value, }
+    });
+
+    quote! {
+        impl<'de> ::obws::__private::serde::Deserialize<'de> for #ident {
+            fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+            where
+                __D: ::obws::__private::serde::Deserializer<'de>,
+            {
+                #[derive(::obws::__private::serde::Deserialize)]
+                #[serde(crate = "::obws::__private::serde")]
+                struct Mirror {
+                    #(#mirror_fields)*
+                }
+
+                let mirror = Mirror::deserialize(deserializer)?;
+
+                ::std::result::Result::Ok(Self {
+                    #(#assignments)*
+                })
+            }
+        }
+    }
+}
+
+/// Type of the field in the helper struct for deserialization, wrapping it in the type that
+/// implements its convention.
+fn mirror_type(field: &SettingsField<'_>) -> TokenStream2 {
+    let wrap = |inner: TokenStream2| match field.convention {
+        Convention::None => inner,
+        Convention::DurationMillis => quote! { ::obws::common::DurationMillis },
+        Convention::RgbaInverse => quote! { ::obws::__private::RgbaInverseDe },
+        Convention::Bitflags => quote! { ::obws::__private::BitflagsDe<#inner> },
+    };
+
+    match (field.option, field.convention) {
+        (Some(inner), _) => {
+            let inner = wrap(quote! { #inner });
+            quote! { ::std::option::Option<#inner> }
+        }
+        (None, Convention::None) => {
+            let ty = &field_type(field);
+            quote! { #ty }
+        }
+        (None, _) if field.default => {
+            let inner = wrap(field_type(field));
+            quote! { ::std::option::Option<#inner> }
+        }
+        (None, _) => wrap(field_type(field)),
+    }
+}
+
+fn field_type(field: &SettingsField<'_>) -> TokenStream2 {
+    let ty = field.ty();
+    quote! { #ty }
+}
+
+impl SettingsField<'_> {
+    fn ty(&self) -> &Type {
+        self.option.unwrap_or(self.ty)
+    }
+}
+
+fn parse_kind(input: &DeriveInput) -> Result<LitStr> {
+    let mut kind = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("obs"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("kind") {
+                kind = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown attribute, expected `kind`"))
+            }
+        })?;
+    }
+
+    kind.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "missing source kind, add `#[obs(kind = \"...\")]`",
+        )
+    })
+}
+
+fn parse_field(field: &Field) -> Result<SettingsField<'_>> {
+    let ident = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| Error::new_spanned(field, "field must be named"))?;
+
+    let mut settings = SettingsField {
+        ident,
+        ty: &field.ty,
+        key: ident.to_string(),
+        option: option_inner(&field.ty),
+        convention: Convention::None,
+        default: false,
+    };
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("obs"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let convention = if meta.path.is_ident("rename") {
+                settings.key = meta.value()?.parse::<LitStr>()?.value();
+                return Ok(());
+            } else if meta.path.is_ident("default") {
+                settings.default = true;
+                return Ok(());
+            } else if meta.path.is_ident("duration_millis") {
+                Convention::DurationMillis
+            } else if meta.path.is_ident("rgba_inverse") {
+                Convention::RgbaInverse
+            } else if meta.path.is_ident("bitflags") {
+                Convention::Bitflags
+            } else {
+                return Err(meta.error(
+                    "unknown attribute, expected one of `rename`, `default`, `duration_millis`, \
+                    `rgba_inverse` or `bitflags`",
+                ));
+            };
+
+            if !matches!(settings.convention, Convention::None) {
+                return Err(meta.error("only one serialization convention allowed per field"));
+            }
+
+            settings.convention = convention;
+            Ok(())
+        })?;
+    }
+
+    Ok(settings)
+}
+
+/// Get the inner type `T`, if the type is an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,135 @@
+use sha2::{Digest, Sha256};
+
+/// Error returned by the closures of an [`Auth`], when the credentials couldn't be loaded.
+pub type AuthError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Credentials for [`Client::login_with`](super::Client::login_with), that are only loaded once
+/// obs-websocket asks for authentication. This allows to keep the plaintext password out of the
+/// client, for example when loading it from the keychain of the operating system.
+///
+/// With the `zeroize` feature, the password, the secret derived from it and the authentication
+/// response are overwritten in memory once they're no longer needed.
+///
+/// ```no_run
+/// use obws::{client::Auth, Client};
+///
+/// # async fn run() -> obws::Result<()> {
+/// let client = Client::connect("localhost", 4444).await?;
+///
+/// client
+///     .login_with(Auth::password(|| {
+///         // Load the password from a keychain instead.
+///         Ok(std::env::var("OBS_PASSWORD")?)
+///     }))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Auth<'a>(Provider<'a>);
+
+type PasswordProvider<'a> = Box<dyn FnOnce() -> Result<String, AuthError> + Send + 'a>;
+type SecretProvider<'a> = Box<dyn FnOnce(&str) -> Result<String, AuthError> + Send + 'a>;
+
+enum Provider<'a> {
+    Password(PasswordProvider<'a>),
+    Secret(SecretProvider<'a>),
+}
+
+impl<'a> Auth<'a> {
+    /// Create credentials from a closure that returns the plaintext password.
+    pub fn password<F>(provider: F) -> Self
+    where
+        F: FnOnce() -> Result<String, AuthError> + Send + 'a,
+    {
+        Self(Provider::Password(Box::new(provider)))
+    }
+
+    /// Create credentials from a closure that returns the secret for the salt it receives, as
+    /// created by [`secret_for`](Self::secret_for). The salt of an obs-websocket instance only
+    /// changes with its password, so the secret can be stored in place of the password.
+    pub fn secret<F>(provider: F) -> Self
+    where
+        F: FnOnce(&str) -> Result<String, AuthError> + Send + 'a,
+    {
+        Self(Provider::Secret(Box::new(provider)))
+    }
+
+    /// Compute the secret from a password and the salt of obs-websocket, which is reported by
+    /// [`get_auth_required`](super::General::get_auth_required). It's the base64 encoded SHA-256
+    /// hash of both, and can be used with [`Auth::secret`] later on.
+    pub fn secret_for(password: &str, salt: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(password.as_bytes());
+        hasher.update(salt.as_bytes());
+
+        base64::encode(hasher.finalize())
+    }
+
+    /// Load the secret for the given salt.
+    pub(super) fn load_secret(self, salt: &str) -> Result<String, AuthError> {
+        match self.0 {
+            Provider::Password(provider) => {
+                let password = provider()?;
+                let secret = Self::secret_for(&password, salt);
+                wipe(password);
+                Ok(secret)
+            }
+            Provider::Secret(provider) => provider(salt),
+        }
+    }
+}
+
+/// Create the response to an authentication challenge from the secret.
+pub(super) fn response(secret: &str, challenge: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(challenge.as_bytes());
+
+    base64::encode(hasher.finalize())
+}
+
+/// Drop a string that holds credentials, overwriting its memory first if the `zeroize` feature is
+/// enabled.
+pub(super) fn wipe(value: String) {
+    #[cfg(feature = "zeroize")]
+    {
+        let mut value = value;
+        zeroize::Zeroize::zeroize(&mut value);
+    }
+    #[cfg(not(feature = "zeroize"))]
+    drop(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "supersecretpassword";
+    const SALT: &str = "lM1GncleQOaCu9lT1yeUZhFYnqhsLLP1G5lAGo3ixaI=";
+    const SECRET: &str = "H1IfVz1pSREUQzbFTVnX/Tyb+gMhMik5x7yUBCY0PTs=";
+
+    #[test]
+    fn load_secret() {
+        assert_eq!(SECRET, Auth::secret_for(PASSWORD, SALT));
+        assert_eq!(
+            SECRET,
+            Auth::password(|| Ok(PASSWORD.to_owned()))
+                .load_secret(SALT)
+                .unwrap()
+        );
+        assert_eq!(
+            SECRET,
+            Auth::secret(|salt| Ok(Auth::secret_for(PASSWORD, salt)))
+                .load_secret(SALT)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn auth_response() {
+        assert_eq!(
+            "1Ct943GAT+6YQUUX47Ia/ncufilbe6+oD6lY+5kaCu4=",
+            response(SECRET, "+IxH4CnCiqpX1rM9scsNynZzbOe4KhDeYcTNS3PDaeY=")
+        );
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "events")]
+use futures_util::{future, stream::Stream, StreamExt};
+#[cfg(feature = "events")]
+use log::debug;
+#[cfg(feature = "events")]
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::Client;
+#[cfg(feature = "events")]
+use crate::events::EventType;
+use crate::requests::RequestType;
+use crate::{Error, Result};
+
+/// Typed publish/subscribe layer on top of custom broadcast messages, that allows multiple
+/// clients to coordinate through OBS.
+pub struct CustomMessages<'a> {
+    pub(super) client: &'a Client,
+}
+
+impl<'a> CustomMessages<'a> {
+    /// Publish a message to all connected WebSocket clients.
+    ///
+    /// - `realm`: Identifier to be choosen by the client. Subscribers only receive messages of the
+    ///   realm they subscribed to.
+    /// - `data`: User-defined data, that must serialize to a JSON object.
+    pub async fn send<T>(&self, realm: &str, data: &T) -> Result<()>
+    where
+        T: Serialize,
+    {
+        self.client
+            .send_message(RequestType::BroadcastCustomMessage {
+                realm,
+                data: &serde_json::to_value(data).map_err(Error::SerializeCustomData)?,
+            })
+            .await
+    }
+
+    /// Get a stream of all messages that are published within the given realm. Each call to this
+    /// function creates a new listener, therefore it's recommended to keep the stream around and
+    /// iterate over it.
+    ///
+    /// Messages of the realm that can't be deserialized into `T` are skipped.
+    ///
+    /// **Note**: To be able to iterate over the stream you have to pin it with
+    /// [`futures_util::pin_mut`] for example.
+    ///
+    /// - `realm`: Identifier of the messages to receive.
+    ///
+    /// # Errors
+    ///
+    /// Subscribing fails with [`Error::Disconnected`] if the client is disconnected from
+    /// obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn subscribe<T>(&self, realm: &str) -> Result<impl Stream<Item = T>>
+    where
+        T: DeserializeOwned,
+    {
+        let realm = realm.to_owned();
+
+        self.client.events().map(move |events| {
+            events.filter_map(move |event| {
+                future::ready(match event.ty {
+                    EventType::BroadcastCustomMessage {
+                        realm: msg_realm,
+                        data,
+                    } if msg_realm == realm => {
+                        serde_json::from_value(serde_json::Value::Object(data))
+                            .map_err(|e| debug!("skipping invalid custom message: {:?}", e))
+                            .ok()
+                    }
+                    _ => None,
+                })
+            })
+        })
+    }
+}
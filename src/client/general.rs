@@ -1,7 +1,18 @@
+use chrono::Duration;
+use either::Either;
+use futures_util::stream::{self, Stream};
+#[cfg(feature = "events")]
+use futures_util::{future, StreamExt};
 use serde::Serialize;
+use tokio::time::{self, Instant};
 
 use super::Client;
-use crate::requests::{KeyModifier, Projector, ProjectorInternal, QtGeometry, RequestType};
+use crate::common::{Presence, PresenceState, PRESENCE_REALM};
+#[cfg(feature = "events")]
+use crate::events::{EventType, Heartbeat};
+use crate::requests::{
+    Key, KeyModifier, KeyModifiers, Projector, ProjectorInternal, QtGeometry, RequestType,
+};
 use crate::responses;
 use crate::{Error, Result};
 
@@ -58,6 +69,100 @@ impl<'a> General<'a> {
             .map(|s| s.stats)
     }
 
+    /// Poll the OBS stats in a fixed interval and get a stream of the results, together with
+    /// metrics derived from the difference to the previous poll.
+    ///
+    /// A failed poll yields the error and the stream continues with the next interval.
+    ///
+    /// **Note**: To be able to iterate over the stream you have to pin it with
+    /// [`futures_util::pin_mut`] for example.
+    ///
+    /// - `interval`: Time between two polls.
+    pub fn stats_stream(&self, interval: Duration) -> impl Stream<Item = Result<StatsSample>> + 'a {
+        let client = self.client;
+        let ticker = time::interval(
+            interval
+                .to_std()
+                .unwrap_or_default()
+                .max(std::time::Duration::from_millis(1)),
+        );
+
+        stream::unfold(
+            (ticker, None),
+            move |(mut ticker, previous): (_, Option<(Instant, responses::ObsStats)>)| async move {
+                ticker.tick().await;
+
+                match client.general().get_stats().await {
+                    Ok(stats) => {
+                        let now = Instant::now();
+                        let sample = StatsSample::new(
+                            stats.clone(),
+                            previous
+                                .as_ref()
+                                .map(|(instant, prev)| (prev, now.duration_since(*instant))),
+                        );
+                        Some((Ok(sample), (ticker, Some((now, stats)))))
+                    }
+                    Err(e) => Some((Err(e), (ticker, previous))),
+                }
+            },
+        )
+    }
+
+    /// Enable/disable sending of the [`Heartbeat`](crate::events::EventType::Heartbeat) event.
+    ///
+    /// - `enable`: Starts/Stops emitting heartbeat messages.
+    pub async fn set_heartbeat(&self, enable: bool) -> Result<()> {
+        self.client
+            .send_message(RequestType::SetHeartbeat { enable })
+            .await
+    }
+
+    /// Enable the heartbeat and get a stream of the received heartbeats, to watch over the health
+    /// of OBS. If no heartbeat arrives within the given interval, the stream yields an
+    /// [`Error::HeartbeatTimeout`] and continues to wait for the next one. That allows to detect an
+    /// OBS instance that is still connected, but doesn't respond anymore.
+    ///
+    /// OBS sends a heartbeat every 2 seconds, so the interval should be comfortably above that.
+    ///
+    /// **Note**: To be able to iterate over the stream you have to pin it with
+    /// [`futures_util::pin_mut`] for example.
+    ///
+    /// - `interval`: Maximum time to wait for the next heartbeat.
+    ///
+    /// # Errors
+    ///
+    /// Creating the stream fails with [`Error::Disconnected`] if the client is disconnected from
+    /// obs-websocket.
+    #[cfg(feature = "events")]
+    pub async fn heartbeat(
+        &self,
+        interval: Duration,
+    ) -> Result<impl Stream<Item = Result<Heartbeat>>> {
+        let events = self.client.events()?;
+        self.set_heartbeat(true).await?;
+
+        let timeout = interval.to_std().unwrap_or_default();
+        let heartbeats = events.filter_map(|event| {
+            future::ready(match event.ty {
+                EventType::Heartbeat(heartbeat) => Some(heartbeat),
+                _ => None,
+            })
+        });
+
+        Ok(async_stream::stream! {
+            futures_util::pin_mut!(heartbeats);
+
+            loop {
+                match time::timeout(timeout, heartbeats.next()).await {
+                    Ok(Some(heartbeat)) => yield Ok(heartbeat),
+                    Ok(None) => break,
+                    Err(_) => yield Err(Error::HeartbeatTimeout(interval)),
+                }
+            }
+        })
+    }
+
     /// Broadcast custom message to all connected WebSocket clients.
     ///
     /// - `realm`: Identifier to be choosen by the client.
@@ -74,6 +179,38 @@ impl<'a> General<'a> {
             .await
     }
 
+    /// Announce that the given session is online to all other connected WebSocket clients.
+    ///
+    /// This is an opt-in mechanism built on top of
+    /// [`broadcast_custom_message`](Self::broadcast_custom_message), using the
+    /// [`PRESENCE_REALM`] as realm. Other clients can pick up the announcement with
+    /// [`EventType::presence`](crate::events::EventType::presence).
+    ///
+    /// - `session`: Application-provided session label. Reuse the same label across restarts and
+    ///   reconnects to let cooperating clients detect each other.
+    pub async fn announce_presence(&self, session: &str) -> Result<()> {
+        self.send_presence(session, PresenceState::Online).await
+    }
+
+    /// Announce that the given session is going offline to all other connected WebSocket clients.
+    /// Counterpart to [`announce_presence`](Self::announce_presence).
+    ///
+    /// - `session`: Application-provided session label.
+    pub async fn withdraw_presence(&self, session: &str) -> Result<()> {
+        self.send_presence(session, PresenceState::Offline).await
+    }
+
+    async fn send_presence(&self, session: &str, state: PresenceState) -> Result<()> {
+        self.broadcast_custom_message(
+            PRESENCE_REALM,
+            &Presence {
+                session: session.to_owned(),
+                state,
+            },
+        )
+        .await
+    }
+
     /// Get basic OBS video information.
     pub async fn get_video_info(&self) -> Result<responses::VideoInfo> {
         self.client.send_message(RequestType::GetVideoInfo).await
@@ -114,11 +251,163 @@ impl<'a> General<'a> {
     ) -> Result<()> {
         self.client
             .send_message(RequestType::TriggerHotkeyBySequence {
-                key_id,
-                key_modifiers,
+                key_id: Either::Left(key_id),
+                key_modifiers: key_modifiers
+                    .iter()
+                    .fold(KeyModifiers::empty(), |acc, m| acc | m.into()),
+            })
+            .await
+    }
+
+    /// Executes hotkey routine, identified by bound combination of keys. This is the typed variant
+    /// of [`trigger_hotkey_by_sequence`](Self::trigger_hotkey_by_sequence).
+    ///
+    /// ```no_run
+    /// # async fn run(client: &obws::Client) -> obws::Result<()> {
+    /// use obws::requests::{Key, KeyModifiers};
+    ///
+    /// client
+    ///     .general()
+    ///     .trigger_hotkey_sequence(Key::F5, KeyModifiers::CONTROL)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `key`: Main key of the combination.
+    /// - `modifiers`: Modifier keys that are held while pressing the main key.
+    pub async fn trigger_hotkey_sequence(&self, key: Key, modifiers: KeyModifiers) -> Result<()> {
+        self.client
+            .send_message(RequestType::TriggerHotkeyBySequence {
+                key_id: Either::Right(key),
+                key_modifiers: modifiers,
             })
             .await
     }
 
     // TODO: Add `ExecuteBatch` request
 }
+
+/// Single item of the [`General::stats_stream`], containing the polled stats and metrics derived
+/// from the previous poll.
+///
+/// The derived metrics are `None` for the first poll and whenever the frame counters went
+/// backwards, for example because OBS was restarted in between.
+#[derive(Clone, Debug)]
+pub struct StatsSample {
+    /// The raw stats as reported by OBS.
+    pub stats: responses::ObsStats,
+    /// Time elapsed since the previous poll.
+    pub elapsed: Option<Duration>,
+    /// Frames skipped due to encoding lag, per second since the previous poll.
+    pub skipped_frames_per_second: Option<f64>,
+    /// Frames missed due to rendering lag, per second since the previous poll.
+    pub missed_frames_per_second: Option<f64>,
+    /// Ratio (`0.0..=1.0`) of missed frames to all rendered frames since the previous poll.
+    pub render_missed_ratio: Option<f64>,
+    /// Ratio (`0.0..=1.0`) of skipped frames to all output frames since the previous poll.
+    pub output_skipped_ratio: Option<f64>,
+}
+
+impl StatsSample {
+    fn new(
+        stats: responses::ObsStats,
+        previous: Option<(&responses::ObsStats, std::time::Duration)>,
+    ) -> Self {
+        let deltas = previous.and_then(|(prev, elapsed)| {
+            Some((
+                elapsed,
+                stats
+                    .render_total_frames
+                    .checked_sub(prev.render_total_frames)?,
+                stats
+                    .render_missed_frames
+                    .checked_sub(prev.render_missed_frames)?,
+                stats
+                    .output_total_frames
+                    .checked_sub(prev.output_total_frames)?,
+                stats
+                    .output_skipped_frames
+                    .checked_sub(prev.output_skipped_frames)?,
+            ))
+        });
+
+        let per_second = |frames: u64, elapsed: std::time::Duration| {
+            let secs = elapsed.as_secs_f64();
+            (secs > 0.0).then(|| frames as f64 / secs)
+        };
+        let ratio = |part: u64, total: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                part as f64 / total as f64
+            }
+        };
+
+        Self {
+            elapsed: previous.and_then(|(_, elapsed)| Duration::from_std(elapsed).ok()),
+            skipped_frames_per_second: deltas
+                .and_then(|(elapsed, _, _, _, skipped)| per_second(skipped, elapsed)),
+            missed_frames_per_second: deltas
+                .and_then(|(elapsed, _, missed, _, _)| per_second(missed, elapsed)),
+            render_missed_ratio: deltas.map(|(_, rendered, missed, _, _)| ratio(missed, rendered)),
+            output_skipped_ratio: deltas.map(|(_, _, _, output, skipped)| ratio(skipped, output)),
+            stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(rendered: u64, missed: u64, output: u64, skipped: u64) -> responses::ObsStats {
+        responses::ObsStats {
+            fps: 60.0,
+            render_total_frames: rendered,
+            render_missed_frames: missed,
+            output_total_frames: output,
+            output_skipped_frames: skipped,
+            average_frame_time: 1.0,
+            cpu_usage: 1.0,
+            memory_usage: 1.0,
+            free_disk_space: 1.0,
+        }
+    }
+
+    #[test]
+    fn stats_sample_first_poll() {
+        let sample = StatsSample::new(stats(100, 1, 100, 1), None);
+        assert!(sample.elapsed.is_none());
+        assert!(sample.skipped_frames_per_second.is_none());
+        assert!(sample.render_missed_ratio.is_none());
+    }
+
+    #[test]
+    fn stats_sample_deltas() {
+        let previous = stats(100, 10, 100, 5);
+        let sample = StatsSample::new(
+            stats(300, 30, 200, 25),
+            Some((&previous, std::time::Duration::from_secs(2))),
+        );
+
+        assert_eq!(Some(Duration::seconds(2)), sample.elapsed);
+        assert_eq!(Some(10.0), sample.skipped_frames_per_second);
+        assert_eq!(Some(10.0), sample.missed_frames_per_second);
+        assert_eq!(Some(0.1), sample.render_missed_ratio);
+        assert_eq!(Some(0.2), sample.output_skipped_ratio);
+    }
+
+    #[test]
+    fn stats_sample_counter_reset() {
+        let previous = stats(100, 10, 100, 5);
+        let sample = StatsSample::new(
+            stats(50, 0, 50, 0),
+            Some((&previous, std::time::Duration::from_secs(2))),
+        );
+
+        assert!(sample.elapsed.is_some());
+        assert!(sample.skipped_frames_per_second.is_none());
+        assert!(sample.render_missed_ratio.is_none());
+    }
+}
@@ -0,0 +1,164 @@
+use either::Either;
+use serde::de::DeserializeOwned;
+
+use super::Client;
+use crate::common::{SceneName, SourceName, Volume};
+use crate::requests::{SceneItemRender, SceneItemTransform, SceneOrGroup};
+use crate::responses;
+use crate::Result;
+
+/// Handle to a single scene, created with [`Client::scene`]. It keeps the scene name in one place
+/// instead of passing it to every call.
+pub struct SceneHandle<'a> {
+    pub(super) client: &'a Client,
+    pub(super) name: &'a SceneName,
+}
+
+impl<'a> SceneHandle<'a> {
+    /// Name of the scene.
+    pub fn name(&self) -> &'a SceneName {
+        self.name
+    }
+
+    /// Get a handle to an item of this scene.
+    ///
+    /// - `item`: Name of the scene item.
+    pub fn item(&self, item: &'a SourceName) -> SceneItemHandle<'a> {
+        SceneItemHandle {
+            client: self.client,
+            scene: self.name,
+            name: item,
+        }
+    }
+
+    /// Switch the program output to this scene.
+    pub async fn activate(&self) -> Result<()> {
+        self.client.scenes().set_current_scene(self.name).await
+    }
+
+    /// Get a list of all items in this scene.
+    pub async fn items(&self) -> Result<Vec<responses::SceneItemListItem>> {
+        self.client
+            .scene_items()
+            .get_scene_item_list(Some(SceneOrGroup::Scene(self.name)))
+            .await
+            .map(|list| list.scene_items)
+    }
+}
+
+/// Handle to an item in a scene, created with [`SceneHandle::item`].
+pub struct SceneItemHandle<'a> {
+    client: &'a Client,
+    scene: &'a SceneName,
+    name: &'a SourceName,
+}
+
+impl<'a> SceneItemHandle<'a> {
+    /// Name of the scene item.
+    pub fn name(&self) -> &'a SourceName {
+        self.name
+    }
+
+    /// Make the item visible.
+    pub async fn show(&self) -> Result<()> {
+        self.set_visible(true).await
+    }
+
+    /// Hide the item.
+    pub async fn hide(&self) -> Result<()> {
+        self.set_visible(false).await
+    }
+
+    /// Show or hide the item.
+    pub async fn set_visible(&self, visible: bool) -> Result<()> {
+        self.client
+            .scene_items()
+            .set_scene_item_render(SceneItemRender {
+                scene_name: Some(SceneOrGroup::Scene(self.scene)),
+                source: self.name,
+                item: None,
+                render: visible,
+            })
+            .await
+    }
+
+    /// Apply a transform to the item. Only the values that were set on the `transform` are
+    /// changed.
+    pub async fn set_transform(&self, transform: SceneItemTransform) -> Result<()> {
+        self.client
+            .scene_items()
+            .set_scene_item_transform(
+                Some(SceneOrGroup::Scene(self.scene)),
+                Either::Left(self.name),
+                transform,
+            )
+            .await
+    }
+
+    /// Get all scene specific properties of the item.
+    pub async fn properties(&self) -> Result<responses::SceneItemProperties> {
+        self.client
+            .scene_items()
+            .get_scene_item_properties(
+                Some(SceneOrGroup::Scene(self.scene)),
+                Either::Left(self.name),
+            )
+            .await
+    }
+}
+
+/// Handle to a single source, created with [`Client::source`]. It keeps the source name in one
+/// place instead of passing it to every call.
+pub struct SourceHandle<'a> {
+    pub(super) client: &'a Client,
+    pub(super) name: &'a SourceName,
+}
+
+impl<'a> SourceHandle<'a> {
+    /// Name of the source.
+    pub fn name(&self) -> &'a SourceName {
+        self.name
+    }
+
+    /// Mute the source.
+    pub async fn mute(&self) -> Result<()> {
+        self.client.sources().set_mute(self.name, true).await
+    }
+
+    /// Unmute the source.
+    pub async fn unmute(&self) -> Result<()> {
+        self.client.sources().set_mute(self.name, false).await
+    }
+
+    /// Invert the mute status of the source.
+    pub async fn toggle_mute(&self) -> Result<()> {
+        self.client.sources().toggle_mute(self.name).await
+    }
+
+    /// Set the volume of the source.
+    pub async fn set_volume(&self, volume: Volume) -> Result<()> {
+        self.client.sources().set_volume(self.name, volume).await
+    }
+
+    /// Set the volume of the source in decibels, where `0.0` is the original volume.
+    pub async fn set_volume_db(&self, volume: f64) -> Result<()> {
+        self.set_volume(Volume::Db(volume)).await
+    }
+
+    /// Set the text, if this is a text source.
+    pub async fn set_text(&self, text: &str) -> Result<()> {
+        self.client.sources().set_text(self.name, text).await
+    }
+
+    /// Get the settings of the source.
+    pub async fn settings<T>(&self) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.client
+            .sources()
+            .get_source_settings(self.name, None)
+            .await
+            .map(|settings| settings.source_settings)
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Hooks that are called for every request, response and event of a client, registered with
+/// [`Client::add_interceptor`](super::Client::add_interceptor). This allows logging, metrics,
+/// auditing or a dry-run mode on the user side.
+///
+/// All functions receive the type name and the raw JSON payload of the message. They're called
+/// from the task that sends the request or receives the message, and should return quickly.
+///
+/// ```
+/// use obws::client::{InterceptAction, Interceptor};
+///
+/// /// Logs all requests, but doesn't send those that change something in OBS.
+/// struct DryRun;
+///
+/// impl Interceptor for DryRun {
+///     fn before_request(&self, request_type: &str, payload: &str) -> InterceptAction {
+///         println!("{}: {}", request_type, payload);
+///
+///         if request_type.starts_with("Get") {
+///             InterceptAction::Send
+///         } else {
+///             InterceptAction::Respond(r#"{"status":"ok"}"#.to_owned())
+///         }
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called before a request is sent, and decides whether it's actually sent.
+    fn before_request(&self, request_type: &str, payload: &str) -> InterceptAction {
+        let _ = (request_type, payload);
+        InterceptAction::Send
+    }
+
+    /// Called after the response to a request was received, including error responses, and the
+    /// time it took since sending the request.
+    fn after_response(&self, request_type: &str, payload: &str, elapsed: Duration) {
+        let _ = (request_type, payload, elapsed);
+    }
+
+    /// Called for every received event.
+    fn on_event(&self, event_type: &str, payload: &str) {
+        let _ = (event_type, payload);
+    }
+}
+
+/// Decision of an [`Interceptor`] about a request that is about to be sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterceptAction {
+    /// Send the request as usual.
+    Send,
+    /// Don't send the request and use the given JSON as its response instead. The response is
+    /// processed like one from obs-websocket, so `{"status":"ok"}` is enough for requests that
+    /// don't return any data, and `{"status":"error","error":"..."}` fails the request. Later
+    /// interceptors are not called anymore.
+    Respond(String),
+}
+
+/// Shared list of interceptors, used by all clones of a client and its background task.
+pub(super) type Interceptors = Arc<RwLock<Vec<Arc<dyn Interceptor>>>>;
+
+/// Get a snapshot of the current interceptors, so the lock isn't held while calling them.
+pub(super) fn snapshot(interceptors: &Interceptors) -> Vec<Arc<dyn Interceptor>> {
+    interceptors
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Extract the type name of a serialized request.
+pub(super) fn request_type(json: &str) -> String {
+    #[derive(Deserialize)]
+    struct RequestName<'a> {
+        #[serde(rename = "request-type", borrow)]
+        request_type: std::borrow::Cow<'a, str>,
+    }
+
+    serde_json::from_str::<RequestName<'_>>(json)
+        .map(|name| name.request_type.into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_request_type() {
+        assert_eq!(
+            "GetVersion",
+            request_type(r#"{"message-id":"1","request-type":"GetVersion"}"#)
+        );
+        assert_eq!("", request_type(r#"{"message-id":"1"}"#));
+    }
+}
@@ -1,9 +1,14 @@
 use chrono::Duration;
+use serde::de::IgnoredAny;
 
 use super::Client;
-use crate::requests::RequestType;
-use crate::responses;
-use crate::Result;
+use crate::common::{SourceKind, SourceName};
+use crate::requests::{CustomSource, FfmpegSource, RequestType, SourceSettingsPatch};
+use crate::responses::{self, MediaState};
+use crate::{Error, Result};
+
+/// Range of playback speeds in percent, that the ffmpeg source accepts.
+const SPEED_RANGE: std::ops::RangeInclusive<u32> = 1..=200;
 
 /// API functions related to media control.
 pub struct MediaControl<'a> {
@@ -15,10 +20,15 @@ impl<'a> MediaControl<'a> {
     ///
     /// - `source_name`: Source name.
     /// - `play_pause`: Whether to pause or play the source. `false` for play, `true` for pause.
-    pub async fn play_pause_media(&self, source_name: &str, play_pause: bool) -> Result<()> {
+    pub async fn play_pause_media(
+        &self,
+        source_name: impl Into<SourceName>,
+        play_pause: bool,
+    ) -> Result<()> {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::PlayPauseMedia {
-                source_name,
+                source_name: &source_name,
                 play_pause,
             })
             .await
@@ -27,18 +37,24 @@ impl<'a> MediaControl<'a> {
     /// Restart a media source. Supports ffmpeg and vlc media sources (as of OBS v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn restart_media(&self, source_name: &str) -> Result<()> {
+    pub async fn restart_media(&self, source_name: impl Into<SourceName>) -> Result<()> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::RestartMedia { source_name })
+            .send_message(RequestType::RestartMedia {
+                source_name: &source_name,
+            })
             .await
     }
 
     /// Stop a media source. Supports ffmpeg and vlc media sources (as of OBS v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn stop_media(&self, source_name: &str) -> Result<()> {
+    pub async fn stop_media(&self, source_name: impl Into<SourceName>) -> Result<()> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::StopMedia { source_name })
+            .send_message(RequestType::StopMedia {
+                source_name: &source_name,
+            })
             .await
     }
 
@@ -46,9 +62,12 @@ impl<'a> MediaControl<'a> {
     /// v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn next_media(&self, source_name: &str) -> Result<()> {
+    pub async fn next_media(&self, source_name: impl Into<SourceName>) -> Result<()> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::NextMedia { source_name })
+            .send_message(RequestType::NextMedia {
+                source_name: &source_name,
+            })
             .await
     }
 
@@ -56,9 +75,12 @@ impl<'a> MediaControl<'a> {
     /// v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn previous_media(&self, source_name: &str) -> Result<()> {
+    pub async fn previous_media(&self, source_name: impl Into<SourceName>) -> Result<()> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::PreviousMedia { source_name })
+            .send_message(RequestType::PreviousMedia {
+                source_name: &source_name,
+            })
             .await
     }
 
@@ -69,9 +91,12 @@ impl<'a> MediaControl<'a> {
     /// duration can be off by upwards of 50ms.
     ///
     /// - `source_name`: Source name.
-    pub async fn get_media_duration(&self, source_name: &str) -> Result<Duration> {
+    pub async fn get_media_duration(&self, source_name: impl Into<SourceName>) -> Result<Duration> {
+        let source_name = source_name.into();
         self.client
-            .send_message::<responses::MediaDuration>(RequestType::GetMediaDuration { source_name })
+            .send_message::<responses::MediaDuration>(RequestType::GetMediaDuration {
+                source_name: &source_name,
+            })
             .await
             .map(|md| md.media_duration)
     }
@@ -80,9 +105,12 @@ impl<'a> MediaControl<'a> {
     /// (as of OBS v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn get_media_time(&self, source_name: &str) -> Result<Duration> {
+    pub async fn get_media_time(&self, source_name: impl Into<SourceName>) -> Result<Duration> {
+        let source_name = source_name.into();
         self.client
-            .send_message::<responses::MediaTime>(RequestType::GetMediaTime { source_name })
+            .send_message::<responses::MediaTime>(RequestType::GetMediaTime {
+                source_name: &source_name,
+            })
             .await
             .map(|mt| mt.timestamp)
     }
@@ -92,10 +120,15 @@ impl<'a> MediaControl<'a> {
     ///
     /// - `source_name`: Source name.
     /// - `timestamp`: Milliseconds to set the timestamp to.
-    pub async fn set_media_time(&self, source_name: &str, timestamp: Duration) -> Result<()> {
+    pub async fn set_media_time(
+        &self,
+        source_name: impl Into<SourceName>,
+        timestamp: Duration,
+    ) -> Result<()> {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::SetMediaTime {
-                source_name,
+                source_name: &source_name,
                 timestamp,
             })
             .await
@@ -110,10 +143,15 @@ impl<'a> MediaControl<'a> {
     /// - `source_name`: Source name.
     /// - `time_offset`: Millisecond offset (positive or negative) to offset the current media
     ///   position.
-    pub async fn scrub_media(&self, source_name: &str, time_offset: Duration) -> Result<()> {
+    pub async fn scrub_media(
+        &self,
+        source_name: impl Into<SourceName>,
+        time_offset: Duration,
+    ) -> Result<()> {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::ScrubMedia {
-                source_name,
+                source_name: &source_name,
                 time_offset,
             })
             .await
@@ -123,10 +161,87 @@ impl<'a> MediaControl<'a> {
     /// of OBS v25.0.8).
     ///
     /// - `source_name`: Source name.
-    pub async fn get_media_state(&self, source_name: &str) -> Result<responses::MediaState> {
+    pub async fn get_media_state(
+        &self,
+        source_name: impl Into<SourceName>,
+    ) -> Result<responses::MediaState> {
+        let source_name = source_name.into();
         self.client
-            .send_message::<responses::GetMediaState>(RequestType::GetMediaState { source_name })
+            .send_message::<responses::GetMediaState>(RequestType::GetMediaState {
+                source_name: &source_name,
+            })
             .await
             .map(|msr| msr.media_state)
     }
+
+    /// Change the playback speed of a media source, while it's playing. Only the ffmpeg source
+    /// ("Media Source") has a speed setting, VLC sources always play at normal speed.
+    ///
+    /// The ffmpeg source only picks up a new speed when the media is opened again. If the media is
+    /// currently playing or paused, it's therefore restarted and seeked back to the previous
+    /// position, and paused again if it was paused before. This may cause a short stutter in the
+    /// output.
+    ///
+    /// - `source_name`: Source name.
+    /// - `speed_percent`: Playback speed in percent of the normal speed, within `1..=200`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::InvalidPlaybackSpeed`] if the speed is outside of the valid range, or
+    /// with [`Error::NoPlaybackSpeed`] if the source isn't an ffmpeg source.
+    pub async fn set_playback_speed(
+        &self,
+        source_name: impl Into<SourceName>,
+        speed_percent: u32,
+    ) -> Result<()> {
+        if !SPEED_RANGE.contains(&speed_percent) {
+            return Err(Error::InvalidPlaybackSpeed(speed_percent));
+        }
+
+        let source_name = source_name.into();
+        let source_type = self
+            .client
+            .sources()
+            .get_source_settings::<IgnoredAny>(&source_name, None)
+            .await?
+            .source_type;
+
+        if *SourceKind::from(source_type.as_str()).base() != SourceKind::FfmpegSource {
+            return Err(Error::NoPlaybackSpeed(source_type));
+        }
+
+        let state = self.get_media_state(&source_name).await?;
+        let paused = matches!(state, MediaState::Paused);
+        let position = match state {
+            MediaState::Playing | MediaState::Paused => {
+                Some(self.get_media_time(&source_name).await?)
+            }
+            _ => None,
+        };
+
+        self.client
+            .sources()
+            .patch_source_settings::<_, IgnoredAny>(SourceSettingsPatch {
+                source_name: &source_name,
+                source_settings: &CustomSource {
+                    kind: &source_type,
+                    settings: FfmpegSource {
+                        speed_percent: Some(speed_percent),
+                        ..FfmpegSource::default()
+                    },
+                },
+            })
+            .await?;
+
+        if let Some(position) = position {
+            self.restart_media(&source_name).await?;
+            self.set_media_time(&source_name, position).await?;
+
+            if paused {
+                self.play_pause_media(&source_name, true).await?;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -6,11 +6,12 @@ use std::{
     collections::HashMap,
     future::Future,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
 };
 
+use chrono::Duration;
 #[cfg(feature = "events")]
 use futures_util::stream::Stream;
 use futures_util::{
@@ -18,38 +19,51 @@ use futures_util::{
     stream::{SplitSink, StreamExt},
 };
 use log::{debug, error, trace};
-use semver::{Comparator, Op, Prerelease};
+use semver::{Comparator, Op, Prerelease, Version};
 use serde::de::DeserializeOwned;
 #[cfg(feature = "events")]
 use tokio::sync::broadcast;
 use tokio::{
     net::TcpStream,
-    sync::{oneshot, Mutex},
+    sync::{oneshot, watch, Mutex},
     task::JoinHandle,
+    time::{self, Instant},
 };
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
+use self::interceptor::Interceptors;
 #[cfg(feature = "events")]
 use crate::events::{Event, EventType};
+#[cfg(feature = "move-transition")]
+use crate::vendors::move_transition::MoveTransition;
 use crate::{
+    common::{SceneName, SourceName},
     requests::{Request, RequestType},
-    responses::{AuthRequired, Response},
+    responses::{AuthRequired, MessageHeader, Response},
     Error, Result,
 };
 
 pub use self::{
-    general::General, media_control::MediaControl, outputs::Outputs, profiles::Profiles,
-    recording::Recording, replay_buffer::ReplayBuffer, scene_collections::SceneCollections,
-    scene_items::SceneItems, scenes::Scenes, sources::Sources, streaming::Streaming,
-    studio_mode::StudioMode, transitions::Transitions,
+    auth::Auth, auth::AuthError, custom_messages::CustomMessages, general::General,
+    general::StatsSample, handles::SceneHandle, handles::SceneItemHandle, handles::SourceHandle,
+    interceptor::InterceptAction, interceptor::Interceptor, media_control::MediaControl,
+    outputs::Outputs, profiles::Profiles, recording::Recording, replay_buffer::ReplayBuffer,
+    retry::RetryPolicy, scene_collections::SceneCollections, scene_items::SceneItems,
+    scenes::Scenes, sources::Sources, streaming::CaptionFeeder, streaming::Streaming,
+    studio_mode::StudioMode, transitions::TBarHandle, transitions::Transitions,
 };
 
+mod auth;
+mod custom_messages;
 mod general;
+mod handles;
+mod interceptor;
 mod media_control;
 mod outputs;
 mod profiles;
 mod recording;
 mod replay_buffer;
+mod retry;
 mod scene_collections;
 mod scene_items;
 mod scenes;
@@ -72,29 +86,109 @@ enum InnerError {
 /// The client is the main entry point to access the obs-websocket API. It allows to call various
 /// functions to remote control an OBS instance as well as to listen to events caused by the user
 /// by interacting with OBS.
+///
+/// The client is cheap to clone, as all clones share the same connection. This allows to move a
+/// clone into each task that needs access to OBS, instead of wrapping the client in an [`Arc`].
+/// The connection is closed once the last clone is dropped.
+///
+/// ```no_run
+/// # async fn run() -> obws::Result<()> {
+/// let client = obws::Client::connect("localhost", 4444).await?;
+///
+/// let task_client = client.clone();
+/// tokio::spawn(async move {
+///     task_client.streaming().start_streaming(None).await
+/// });
+///
+/// client.recording().start_recording().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
 pub struct Client {
     /// The writer handle to the websocket stream.
-    write: Mutex<MessageWriter>,
+    write: Arc<Mutex<MessageWriter>>,
     /// Global counter for requests that help to find out what response belongs to what previously
     /// sent request.
-    id_counter: AtomicU64,
+    id_counter: Arc<AtomicU64>,
     /// A list of currently waiting requests to get a response back. The key is the string version
     /// of a request ID and the value is a oneshot sender that allows to send the response back to
-    /// the other end that waits for the response.
-    receivers: Arc<Mutex<HashMap<u64, oneshot::Sender<serde_json::Value>>>>,
+    /// the other end that waits for the response. Once the connection ends, all remaining ones
+    /// are completed with [`Error::Disconnected`].
+    receivers: Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String>>>>>,
     /// Broadcast sender that distributes received events to all current listeners. Events are
     /// dropped if nobody listens.
     #[cfg(feature = "events")]
     event_sender: Weak<broadcast::Sender<Event>>,
     /// Handle to the background task that receives messages and distributes them to waiting
     /// receivers and event listeners. It allows to shut down all the machinery once the client is
-    /// no longer needed.
-    handle: Option<JoinHandle<()>>,
+    /// no longer needed, and is shared between all clones.
+    handle: Arc<TaskGuard>,
+    /// Set once [`disconnect`](Self::disconnect) was called, to tell a local disconnect apart
+    /// from the server closing the connection.
+    closing: Arc<AtomicBool>,
+    /// Reason why the connection ended, set by the background task once it finishes.
+    close_reason: watch::Receiver<Option<CloseReason>>,
+    /// Version of the connected obs-websocket plugin, to reject requests it doesn't support.
+    /// Only `None` while connecting.
+    obs_websocket_version: Option<Version>,
+    /// Policy to retry failed requests with, if enabled.
+    retry_policy: Option<RetryPolicy>,
+    /// Hooks that are called for all requests, responses and events, shared between all clones.
+    interceptors: Interceptors,
+}
+
+/// Reason why the connection to obs-websocket ended, as reported by [`Client::closed`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CloseReason {
+    /// The client disconnected itself, either through [`Client::disconnect`] or by dropping the
+    /// last clone of it.
+    Disconnected,
+    /// obs-websocket closed the connection, for example because OBS is shutting down.
+    Closed {
+        /// Status code of the websocket close frame. Like in the websocket protocol, `1005` means
+        /// the close frame had no code and `1006` that the connection ended without a close frame.
+        code: u16,
+        /// Reason given in the close frame, which is usually empty.
+        reason: String,
+    },
+    /// obs-websocket didn't answer a keepalive ping in time, see
+    /// [`ConnectConfig::ping_interval`]. The connection is likely dead, for example because a
+    /// router dropped it silently.
+    Timeout,
+    /// The connection failed, with the description of the error.
+    Error(String),
+}
+
+/// Owner of the background task of a [`Client`], that stops the task once the last clone of the
+/// client is dropped.
+struct TaskGuard(std::sync::Mutex<Option<JoinHandle<()>>>);
+
+impl TaskGuard {
+    fn take(&self) -> Option<JoinHandle<()>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        // We can't wait for the task to fully shut down here (except spinning up a new tokio
+        // runtime), so it's only aborted.
+        if let Some(handle) = self.take() {
+            handle.abort();
+        }
+    }
 }
 
 /// Shorthand for the writer side of a websocket stream that has been split into reader and writer.
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 
+/// Maximum time that [`Client::disconnect`] waits for in-flight requests to complete, and again
+/// for obs-websocket to acknowledge the close frame.
+const DISCONNECT_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(1);
+/// Time between two checks whether all in-flight requests completed while disconnecting.
+const DRAIN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
 /// Default broadcast capacity used when not overwritten by the user.
 #[cfg(feature = "events")]
 const DEFAULT_CAPACITY: usize = 100;
@@ -117,6 +211,14 @@ where
     /// not be send to listeners anymore.
     #[cfg_attr(not(feature = "events"), allow(dead_code))]
     pub broadcast_capacity: Option<usize>,
+    /// Interval to send websocket pings in, while no other messages are received. This keeps idle
+    /// connections alive, that would otherwise be dropped by routers, and detects dead
+    /// connections. The default is `None`, which disables pings.
+    pub ping_interval: Option<Duration>,
+    /// Time to wait for any message after sending a ping, before the connection is considered
+    /// dead and closed with [`CloseReason::Timeout`]. Defaults to the
+    /// [`ping_interval`](Self::ping_interval).
+    pub ping_timeout: Option<Duration>,
 }
 
 const OBS_STUDIO_VERSION: Comparator = Comparator {
@@ -138,6 +240,15 @@ impl<H> ConnectConfig<H>
 where
     H: AsRef<str>,
 {
+    /// Keepalive settings as interval and timeout, if pings are enabled with a valid interval.
+    fn keepalive(&self) -> Option<(std::time::Duration, std::time::Duration)> {
+        let to_std = |d: Duration| d.to_std().ok().filter(|d| !d.is_zero());
+        let interval = self.ping_interval.and_then(to_std)?;
+        let timeout = self.ping_timeout.and_then(to_std).unwrap_or(interval);
+
+        Some((interval, timeout))
+    }
+
     #[cfg(feature = "tls")]
     fn tls(&self) -> bool {
         self.tls
@@ -158,6 +269,8 @@ impl Client {
             #[cfg(feature = "tls")]
             tls: false,
             broadcast_capacity: None,
+            ping_interval: None,
+            ping_timeout: None,
         })
         .await
     }
@@ -171,9 +284,12 @@ impl Client {
             config.port
         ))
         .await
-        .map_err(Error::Connect)?;
+        .map_err(|e| Error::Connect(Box::new(e)))?;
 
         let (write, mut read) = socket.split();
+        let write = Arc::new(Mutex::new(write));
+        let write2 = Arc::clone(&write);
+        let keepalive = config.keepalive();
         let receivers = Arc::new(Mutex::new(HashMap::<_, oneshot::Sender<_>>::new()));
         let receivers2 = Arc::clone(&receivers);
         #[cfg(feature = "events")]
@@ -184,8 +300,62 @@ impl Client {
         #[cfg(feature = "events")]
         let events_tx = Arc::clone(&event_sender);
 
+        let closing = Arc::new(AtomicBool::new(false));
+        let closing2 = Arc::clone(&closing);
+        let interceptors = Interceptors::default();
+        let interceptors2 = Arc::clone(&interceptors);
+        let (close_tx, close_reason) = watch::channel(None);
+
         let handle = tokio::spawn(async move {
-            while let Some(Ok(msg)) = read.next().await {
+            let mut ping_sent = None;
+
+            let reason = loop {
+                let next = match (keepalive, ping_sent) {
+                    (Some((interval, _)), None) => time::timeout(interval, read.next()).await,
+                    (Some((_, timeout)), Some(sent)) => {
+                        time::timeout_at(sent + timeout, read.next()).await
+                    }
+                    (None, _) => Ok(read.next().await),
+                };
+
+                let next = match next {
+                    Ok(next) => next,
+                    Err(_) if ping_sent.is_some() => break CloseReason::Timeout,
+                    Err(_) => {
+                        trace!("sending keepalive ping");
+                        if let Err(e) = write2.lock().await.send(Message::Ping(Vec::new())).await {
+                            break CloseReason::Error(e.to_string());
+                        }
+                        ping_sent = Some(Instant::now());
+                        continue;
+                    }
+                };
+                ping_sent = None;
+
+                let msg = match next {
+                    Some(Ok(Message::Close(frame))) => {
+                        break frame.map_or(
+                            CloseReason::Closed {
+                                code: 1005,
+                                reason: String::new(),
+                            },
+                            |frame| CloseReason::Closed {
+                                code: frame.code.into(),
+                                reason: frame.reason.into_owned(),
+                            },
+                        );
+                    }
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                    Some(Ok(msg)) => msg,
+                    Some(Err(e)) => break CloseReason::Error(e.to_string()),
+                    None => {
+                        break CloseReason::Closed {
+                            code: 1006,
+                            reason: String::new(),
+                        }
+                    }
+                };
+
                 trace!("{}", msg);
                 let res: Result<(), InnerError> = async {
                     let text = msg.into_text().map_err(InnerError::IntoText)?;
@@ -196,23 +366,27 @@ impl Client {
                         text
                     };
 
-                    let json = serde_json::from_str::<serde_json::Value>(&text)
+                    let header = serde_json::from_str::<MessageHeader<'_>>(&text)
                         .map_err(InnerError::DeserializeMessage)?;
 
-                    if let Some(message_id) = json
-                        .as_object()
-                        .and_then(|obj| obj.get("message-id"))
-                        .and_then(|id| id.as_str())
-                        .and_then(|id| id.parse().ok())
+                    if let Some(message_id) = header
+                        .message_id
+                        .as_deref()
+                        .and_then(|id| id.parse::<u64>().ok())
                     {
                         debug!("got message with id {}", message_id);
                         if let Some(tx) = receivers2.lock().await.remove(&message_id) {
-                            tx.send(json).ok();
+                            tx.send(Ok(text)).ok();
                         }
                     } else {
+                        let event_type = header.update_type.as_deref().unwrap_or_default();
+                        for interceptor in interceptor::snapshot(&interceptors2) {
+                            interceptor.on_event(event_type, &text);
+                        }
+
                         #[cfg(feature = "events")]
                         {
-                            let event = serde_json::from_value(json)
+                            let event = serde_json::from_str(&text)
                                 .map_err(InnerError::DeserializeEvent)?;
                             events_tx.send(event).ok();
                         }
@@ -225,7 +399,14 @@ impl Client {
                 if let Err(e) = res {
                     error!("failed handling message: {:?}", e);
                 }
-            }
+            };
+
+            let reason = if closing2.load(Ordering::SeqCst) {
+                CloseReason::Disconnected
+            } else {
+                reason
+            };
+            debug!("connection closed: {:?}", reason);
 
             #[cfg(feature = "events")]
             {
@@ -237,29 +418,39 @@ impl Client {
                 events_tx.send(event).ok();
             }
 
-            // clear all outstanding receivers to stop them from waiting forever on responses
-            // they'll never receive.
-            receivers2.lock().await.clear();
+            // complete all outstanding receivers, no matter whether the connection was closed or
+            // timed out, to stop them from waiting forever on responses they'll never receive.
+            // The reason is set before draining, so requests sent in the meantime either notice
+            // the closed connection or are completed as well.
+            close_tx.send(Some(reason)).ok();
+            for (_, tx) in receivers2.lock().await.drain() {
+                tx.send(Err(Error::Disconnected)).ok();
+            }
         });
 
-        let write = Mutex::new(write);
-        let id_counter = AtomicU64::new(1);
+        let id_counter = Arc::new(AtomicU64::new(1));
 
-        let client = Self {
+        let mut client = Self {
             write,
             id_counter,
             receivers,
             #[cfg(feature = "events")]
             event_sender: Arc::downgrade(&event_sender),
-            handle: Some(handle),
+            handle: Arc::new(TaskGuard(std::sync::Mutex::new(Some(handle)))),
+            closing,
+            close_reason,
+            obs_websocket_version: None,
+            retry_policy: None,
+            interceptors,
         };
 
-        client.verify_versions().await?;
+        client.obs_websocket_version = Some(client.verify_versions().await?);
 
         Ok(client)
     }
 
-    async fn verify_versions(&self) -> Result<()> {
+    /// Check the versions of OBS and obs-websocket, returning the obs-websocket version.
+    async fn verify_versions(&self) -> Result<Version> {
         let version = self.general().get_version().await?;
 
         if !OBS_STUDIO_VERSION.matches(&version.obs_studio_version) {
@@ -276,13 +467,86 @@ impl Client {
             ));
         }
 
-        Ok(())
+        Ok(version.obs_websocket_version)
+    }
+
+    /// Get a clone of this client that retries failed requests according to the given policy. The
+    /// clone shares the connection with this client, so both can be used side by side, for
+    /// example to only retry requests that are known to be safe to repeat.
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(policy),
+            ..self.clone()
+        }
+    }
+
+    /// Register an interceptor that is called for all requests, responses and events from now on.
+    /// Interceptors are shared between all clones of the client and called in the order they were
+    /// added.
+    pub fn add_interceptor(&self, interceptor: impl Interceptor + 'static) {
+        self.interceptors
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Arc::new(interceptor));
+    }
+
+    /// Version of the connected obs-websocket plugin.
+    pub fn obs_websocket_version(&self) -> &Version {
+        self.obs_websocket_version
+            .as_ref()
+            .expect("version is set while connecting")
+    }
+
+    /// Fail with [`Error::UnsupportedRequest`] if the connected obs-websocket version is too old
+    /// for the request.
+    fn check_supported(&self, req: &RequestType<'_>) -> Result<()> {
+        match (req.min_version(), &self.obs_websocket_version) {
+            (Some(required), Some(actual)) if *actual < required => {
+                Err(Error::UnsupportedRequest {
+                    required,
+                    actual: actual.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
     }
 
     async fn send_message<T>(&self, req: RequestType<'_>) -> Result<T>
     where
         T: DeserializeOwned,
     {
+        let resp = self.send_message_raw(req).await?;
+
+        serde_json::from_str::<Response<T>>(&resp)
+            .map(|r| r.details)
+            .map_err(Error::DeserializeResponse)
+    }
+
+    /// Like [`send_message`](Self::send_message), but deserializes the whole message as `T`,
+    /// instead of flattening the response details. `T` must ignore the common `message-id` and
+    /// `status` fields. Unlike flattened values, this supports types that need the original
+    /// deserializer, like [`RawValue`](serde_json::value::RawValue).
+    async fn send_message_direct<T>(&self, req: RequestType<'_>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let resp = self.send_message_raw(req).await?;
+
+        serde_json::from_str(&resp).map_err(Error::DeserializeResponse)
+    }
+
+    /// Send a request and return the raw text of its response, failing if it is an error.
+    async fn send_message_raw(&self, req: RequestType<'_>) -> Result<String> {
+        self.check_supported(&req)?;
+
+        match &self.retry_policy {
+            Some(policy) => policy.run(|| self.send_message_once(&req)).await,
+            None => self.send_message_once(&req).await,
+        }
+    }
+
+    /// Send a request once and return the raw text of its response, failing if it is an error.
+    async fn send_message_once(&self, req: &RequestType<'_>) -> Result<String> {
         let id = self.id_counter.fetch_add(1, Ordering::SeqCst);
         let req = Request {
             message_id: &id.to_string(),
@@ -290,54 +554,179 @@ impl Client {
         };
         let json = serde_json::to_string(&req).map_err(Error::SerializeMessage)?;
 
-        let (tx, rx) = oneshot::channel();
-        self.receivers.lock().await.insert(id, tx);
+        let interceptors = interceptor::snapshot(&self.interceptors);
+        let request_type = if interceptors.is_empty() {
+            String::new()
+        } else {
+            interceptor::request_type(&json)
+        };
+        let start = Instant::now();
+
+        let resp = match intercept(&interceptors, &request_type, &json) {
+            Some(resp) => resp,
+            None => {
+                let (tx, rx) = oneshot::channel();
+                self.receivers.lock().await.insert(id, tx);
 
-        debug!("sending message: {}", json);
-        let write_result = self
-            .write
-            .lock()
-            .await
-            .send(Message::Text(json))
-            .await
-            .map_err(Error::Send);
+                if self.close_reason.borrow().is_some() {
+                    self.receivers.lock().await.remove(&id);
+                    return Err(Error::Disconnected);
+                }
 
-        if let Err(e) = write_result {
-            self.receivers.lock().await.remove(&id);
-            return Err(e);
+                debug!("sending message: {}", json);
+                let write_result = self
+                    .write
+                    .lock()
+                    .await
+                    .send(Message::Text(json))
+                    .await
+                    .map_err(|e| Error::Send(Box::new(e)));
+
+                if let Err(e) = write_result {
+                    self.receivers.lock().await.remove(&id);
+                    return Err(e);
+                }
+
+                rx.await.map_err(Error::ReceiveMessage)??
+            }
+        };
+
+        for interceptor in &interceptors {
+            interceptor.after_response(&request_type, &resp, start.elapsed());
         }
 
-        let mut resp = rx.await.map_err(Error::ReceiveMessage)?;
+        let header =
+            serde_json::from_str::<MessageHeader<'_>>(&resp).map_err(Error::DeserializeResponse)?;
 
-        if let Some(error) = extract_error(&mut resp) {
-            return Err(Error::Api(error));
+        if let Some(error) = header.error {
+            return Err(Error::Api(error.into_owned()));
         }
 
-        serde_json::from_value::<Response<T>>(resp)
-            .map(|r| r.details)
-            .map_err(Error::DeserializeResponse)
+        Ok(resp)
     }
 
-    /// Disconnect from obs-websocket and shut down all machinery.
+    /// Send a request without waiting for its response. The message is serialized right away but
+    /// written to the websocket from a background task, which allows to send requests from
+    /// synchronous contexts like [`Drop`] implementations.
     ///
-    /// This is called automatically when dropping the client but doesn't wait for all background
-    /// tasks to complete. Therefore, it is recommended to call this manually once the client is
-    /// no longer needed.
+    /// Any response to the request is silently discarded. If no tokio runtime is available, the
+    /// request is not sent at all.
+    fn send_message_detached(&self, req: RequestType<'_>) -> Result<()> {
+        self.check_supported(&req)?;
+
+        let id = self.id_counter.fetch_add(1, Ordering::SeqCst);
+        let req = Request {
+            message_id: &id.to_string(),
+            ty: &req,
+        };
+        let json = serde_json::to_string(&req).map_err(Error::SerializeMessage)?;
+
+        let interceptors = interceptor::snapshot(&self.interceptors);
+        if !interceptors.is_empty()
+            && intercept(&interceptors, &interceptor::request_type(&json), &json).is_some()
+        {
+            return Ok(());
+        }
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let write = Arc::clone(&self.write);
+            runtime.spawn(async move {
+                debug!("sending detached message: {}", json);
+                if let Err(e) = write.lock().await.send(Message::Text(json)).await {
+                    error!("failed sending detached message: {:?}", e);
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Disconnect from obs-websocket and shut down all machinery. As all clones of the client
+    /// share the same connection, this disconnects all of them.
+    ///
+    /// In-flight requests get a short grace period to receive their response, before a close
+    /// frame is sent to obs-websocket. Afterwards, the background task is given the same grace
+    /// period to receive the acknowledgement, and is aborted if that doesn't happen in time.
+    ///
+    /// This is called automatically when dropping the last clone of the client but then the
+    /// connection is aborted right away. Therefore, it is recommended to call this manually once
+    /// the client is no longer needed.
     pub fn disconnect(&mut self) -> impl Future {
-        let handle = self.handle.take().map(|h| {
-            h.abort();
-            h
-        });
+        let guard = TaskGuard(std::sync::Mutex::new(self.handle.take()));
+        let write = Arc::clone(&self.write);
+        let receivers = Arc::clone(&self.receivers);
+        let closing = Arc::clone(&self.closing);
+
+        async move {
+            let mut handle = match guard.take() {
+                Some(handle) => handle,
+                None => return,
+            };
+            closing.store(true, Ordering::SeqCst);
+
+            let drained = time::timeout(DISCONNECT_GRACE_PERIOD, async {
+                while !receivers.lock().await.is_empty() {
+                    time::sleep(DRAIN_POLL_INTERVAL).await;
+                }
+            })
+            .await;
+            if drained.is_err() {
+                debug!("in-flight requests didn't complete before disconnecting");
+            }
 
-        async {
-            if let Some(h) = handle {
-                h.await.ok();
+            if let Err(e) = write.lock().await.send(Message::Close(None)).await {
+                debug!("failed sending close frame: {:?}", e);
+            }
+
+            if time::timeout(DISCONNECT_GRACE_PERIOD, &mut handle)
+                .await
+                .is_err()
+            {
+                handle.abort();
+                handle.await.ok();
+            }
+        }
+    }
+
+    /// Wait until the connection to obs-websocket ends, and get the reason why it ended. This
+    /// allows to coordinate the shutdown of an application with the connection, for example to
+    /// reconnect or exit once OBS closes.
+    ///
+    /// The future resolves right away if the connection already ended.
+    pub fn closed(&self) -> impl Future<Output = CloseReason> {
+        let mut close_reason = self.close_reason.clone();
+
+        async move {
+            loop {
+                if let Some(reason) = close_reason.borrow().clone() {
+                    return reason;
+                }
+                if close_reason.changed().await.is_err() {
+                    // The background task was aborted before it could report a reason.
+                    return close_reason
+                        .borrow()
+                        .clone()
+                        .unwrap_or(CloseReason::Disconnected);
+                }
             }
         }
     }
 
     /// Login to the OBS websocket if an authentication is required.
     pub async fn login(&self, password: Option<impl AsRef<str>>) -> Result<()> {
+        let password = password.map(|password| password.as_ref().to_owned());
+
+        self.login_inner(password.map(|password| Auth::password(move || Ok(password))))
+            .await
+    }
+
+    /// Login to the OBS websocket if an authentication is required, loading the credentials only
+    /// once obs-websocket asks for them. See [`Auth`] for details.
+    pub async fn login_with(&self, auth: Auth<'_>) -> Result<()> {
+        self.login_inner(Some(auth)).await
+    }
+
+    async fn login_inner(&self, auth: Option<Auth<'_>>) -> Result<()> {
         let auth_required = self.general().get_auth_required().await?;
 
         if let AuthRequired {
@@ -346,38 +735,21 @@ impl Client {
             salt: Some(salt),
         } = auth_required
         {
-            match password {
-                Some(password) => {
-                    let auth = Self::create_auth_response(&challenge, &salt, password.as_ref());
-                    self.general().authenticate(&auth).await?;
-                }
-                None => return Err(Error::NoPassword),
-            }
+            let secret = auth
+                .ok_or(Error::NoPassword)?
+                .load_secret(&salt)
+                .map_err(Error::LoadCredentials)?;
+            let response = auth::response(&secret, &challenge);
+            auth::wipe(secret);
+
+            let result = self.general().authenticate(&response).await;
+            auth::wipe(response);
+            result?;
         }
 
         Ok(())
     }
 
-    fn create_auth_response(challenge: &str, salt: &str, password: &str) -> String {
-        use sha2::{Digest, Sha256};
-
-        let mut hasher = Sha256::new();
-        hasher.update(password.as_bytes());
-        hasher.update(salt.as_bytes());
-
-        let mut auth = String::with_capacity(Sha256::output_size() * 4 / 3 + 4);
-
-        base64::encode_config_buf(hasher.finalize_reset(), base64::STANDARD, &mut auth);
-
-        hasher.update(auth.as_bytes());
-        hasher.update(challenge.as_bytes());
-        auth.clear();
-
-        base64::encode_config_buf(hasher.finalize(), base64::STANDARD, &mut auth);
-
-        auth
-    }
-
     /// Get a stream of events. Each call to this function creates a new listener, therefore it's
     /// recommended to keep the stream around and iterate over it.
     ///
@@ -409,6 +781,17 @@ impl Client {
         General { client: self }
     }
 
+    /// Access the typed publish/subscribe layer for custom messages.
+    pub fn custom_messages(&self) -> CustomMessages<'_> {
+        CustomMessages { client: self }
+    }
+
+    /// Access API functions of the Move Transition plugin.
+    #[cfg(feature = "move-transition")]
+    pub fn move_transition(&self) -> MoveTransition<'_> {
+        MoveTransition { client: self }
+    }
+
     /// Access API functions related to media control.
     pub fn media_control(&self) -> MediaControl<'_> {
         MediaControl { client: self }
@@ -468,29 +851,33 @@ impl Client {
     pub fn transitions(&self) -> Transitions<'_> {
         Transitions { client: self }
     }
-}
 
-fn extract_error(value: &mut serde_json::Value) -> Option<String> {
-    value
-        .as_object_mut()
-        .and_then(|o| o.get_mut("error"))
-        .and_then(|e| {
-            if let serde_json::Value::String(msg) = e.take() {
-                Some(msg)
-            } else {
-                None
-            }
-        })
-}
+    /// Get a handle to the scene with the given name.
+    pub fn scene<'a>(&'a self, name: &'a SceneName) -> SceneHandle<'a> {
+        SceneHandle { client: self, name }
+    }
 
-impl Drop for Client {
-    fn drop(&mut self) {
-        // We simply drop the future as the background task has been aborted but we have no way here
-        // to wait for it to fully shut down (except spinning up a new tokio runtime).
-        drop(self.disconnect());
+    /// Get a handle to the source with the given name.
+    pub fn source<'a>(&'a self, name: &'a SourceName) -> SourceHandle<'a> {
+        SourceHandle { client: self, name }
     }
 }
 
+/// Let the interceptors decide about a request, returning the response to use instead of sending
+/// the request, if any of them provides one.
+fn intercept(
+    interceptors: &[Arc<dyn Interceptor>],
+    request_type: &str,
+    json: &str,
+) -> Option<String> {
+    interceptors.iter().find_map(|interceptor| {
+        match interceptor.before_request(request_type, json) {
+            InterceptAction::Send => None,
+            InterceptAction::Respond(resp) => Some(resp),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use semver::Version;
@@ -499,14 +886,69 @@ mod tests {
 
     #[test]
     fn verify_version_req() {
-        assert!(OBS_STUDIO_VERSION.matches(&Version::new(26, 1, 0)));
-        assert!(OBS_STUDIO_VERSION.matches(&Version::new(26, 1, 100)));
-        assert!(OBS_STUDIO_VERSION.matches(&Version::new(26, 100, 100)));
-        assert!(!OBS_STUDIO_VERSION.matches(&Version::new(27, 0, 0)));
+        assert!(OBS_STUDIO_VERSION.matches(&Version::new(27, 0, 0)));
+        assert!(OBS_STUDIO_VERSION.matches(&Version::new(27, 0, 100)));
+        assert!(OBS_STUDIO_VERSION.matches(&Version::new(27, 100, 100)));
+        assert!(!OBS_STUDIO_VERSION.matches(&Version::new(26, 1, 0)));
+        assert!(!OBS_STUDIO_VERSION.matches(&Version::new(28, 0, 0)));
 
         assert!(OBS_WEBSOCKET_VERSION.matches(&Version::new(4, 9, 0)));
         assert!(OBS_WEBSOCKET_VERSION.matches(&Version::new(4, 9, 100)));
         assert!(!OBS_WEBSOCKET_VERSION.matches(&Version::new(4, 100, 100)));
         assert!(!OBS_WEBSOCKET_VERSION.matches(&Version::new(5, 0, 0)));
     }
+
+    #[test]
+    fn parse_message_header() {
+        let text = r#"{"message-id":"5","status":"error","error":"not \"found\""}"#;
+        let header = serde_json::from_str::<MessageHeader<'_>>(text).unwrap();
+        assert_eq!(Some("5"), header.message_id.as_deref());
+        assert_eq!(Some(r#"not "found""#), header.error.as_deref());
+
+        let text = r#"{"update-type":"Heartbeat","pulse":true}"#;
+        let header = serde_json::from_str::<MessageHeader<'_>>(text).unwrap();
+        assert!(header.message_id.is_none());
+        assert!(header.error.is_none());
+    }
+
+    #[test]
+    fn client_is_shareable() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<Client>();
+    }
+
+    #[test]
+    fn keepalive_config() {
+        let config = |interval, timeout| ConnectConfig {
+            host: "localhost",
+            port: 4444,
+            #[cfg(feature = "tls")]
+            tls: false,
+            broadcast_capacity: None,
+            ping_interval: interval,
+            ping_timeout: timeout,
+        };
+        let secs = std::time::Duration::from_secs;
+
+        assert_eq!(None, config(None, Some(Duration::seconds(1))).keepalive());
+        assert_eq!(None, config(Some(Duration::zero()), None).keepalive());
+        assert_eq!(
+            Some((secs(10), secs(10))),
+            config(Some(Duration::seconds(10)), None).keepalive()
+        );
+        assert_eq!(
+            Some((secs(10), secs(3))),
+            config(Some(Duration::seconds(10)), Some(Duration::seconds(3))).keepalive()
+        );
+    }
+
+    #[test]
+    fn request_min_version() {
+        assert_eq!(None, RequestType::GetVersion.min_version());
+        assert_eq!(
+            Some(Version::new(4, 9, 1)),
+            RequestType::GetSourceActive { source_name: "a" }.min_version()
+        );
+        assert!(OBS_WEBSOCKET_VERSION.matches(&Version::new(4, 9, 1)));
+    }
 }
@@ -1,5 +1,7 @@
 use super::Client;
 use crate::requests::RequestType;
+#[cfg(feature = "ndi")]
+use crate::requests::NDI_MAIN_OUTPUT;
 use crate::responses;
 use crate::Result;
 
@@ -51,4 +53,28 @@ impl<'a> Outputs<'a> {
             .send_message(RequestType::StopOutput { output_name, force })
             .await
     }
+
+    /// Start the main output of the [obs-ndi](https://github.com/Palakis/obs-ndi) plugin, which
+    /// sends the program of OBS over the network. Requires the plugin to be installed.
+    ///
+    /// Note: Controlling outputs is an experimental feature of obs-websocket, see
+    /// [`start_output`](Self::start_output).
+    #[cfg(feature = "ndi")]
+    pub async fn start_ndi_output(&self) -> Result<()> {
+        self.start_output(NDI_MAIN_OUTPUT).await
+    }
+
+    /// Stop the main output of the NDI plugin.
+    ///
+    /// - `force`: Force stop (default: false).
+    #[cfg(feature = "ndi")]
+    pub async fn stop_ndi_output(&self, force: Option<bool>) -> Result<()> {
+        self.stop_output(NDI_MAIN_OUTPUT, force).await
+    }
+
+    /// Get information about the main output of the NDI plugin.
+    #[cfg(feature = "ndi")]
+    pub async fn get_ndi_output_info(&self) -> Result<responses::Output> {
+        self.get_output_info(NDI_MAIN_OUTPUT).await
+    }
 }
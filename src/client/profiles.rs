@@ -1,7 +1,22 @@
+use std::time::Duration;
+
+#[cfg(feature = "events")]
+use futures_util::{future, pin_mut, StreamExt};
+use tokio::time::{self, Instant};
+
 use super::Client;
-use crate::requests::RequestType;
+use crate::common::SceneName;
+#[cfg(feature = "events")]
+use crate::events::EventType;
+use crate::requests::{CanvasSize, RequestType};
 use crate::responses;
-use crate::Result;
+use crate::{Error, Result};
+
+/// Maximum time to wait for OBS to switch the profile and apply its canvas size, before giving up.
+const SWITCH_TIMEOUT: Duration = Duration::from_secs(5);
+/// Time between two checks whether OBS finished switching the profile or applied the new canvas
+/// size.
+const SWITCH_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// API functions related to profiles.
 pub struct Profiles<'a> {
@@ -18,6 +33,100 @@ impl<'a> Profiles<'a> {
             .await
     }
 
+    /// Set the currently active profile and adjust the layout of the given scenes to the canvas
+    /// size of the new profile.
+    ///
+    /// obs-websocket doesn't allow to change the video settings directly, but each profile carries
+    /// its own base (canvas) resolution. After switching the profile, the scene items of each of
+    /// the `scenes` are rescaled with
+    /// [`rescale_scene_items`](crate::client::SceneItems::rescale_scene_items). Scenes that are
+    /// not listed are left untouched.
+    ///
+    /// OBS loads the profile in the background, so this waits until the switch is reported and the
+    /// new canvas size applied. If the profile is already the current one, nothing is changed.
+    ///
+    /// - `profile_name`: Name of the desired profile.
+    /// - `scenes`: Names of the scenes to rescale.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::CanvasNotChanged`] if OBS didn't switch to a different canvas size in
+    /// time. This is also the case if the new profile has the same canvas size as the previous
+    /// one, where [`set_current_profile`](Self::set_current_profile) is enough. No scenes are
+    /// rescaled in that case.
+    pub async fn set_current_profile_rescaled(
+        &self,
+        profile_name: &str,
+        scenes: &[SceneName],
+    ) -> Result<()> {
+        if self.get_current_profile().await? == profile_name {
+            return Ok(());
+        }
+
+        let general = self.client.general();
+        let from = CanvasSize::from(&general.get_video_info().await?);
+        let deadline = Instant::now() + SWITCH_TIMEOUT;
+
+        self.switch_and_wait(profile_name, deadline).await?;
+
+        let to = loop {
+            let to = CanvasSize::from(&general.get_video_info().await?);
+            if to != from {
+                break to;
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::CanvasNotChanged(profile_name.to_owned()));
+            }
+            time::sleep(SWITCH_POLL_INTERVAL).await;
+        };
+
+        let scene_items = self.client.scene_items();
+        for scene in scenes {
+            scene_items.rescale_scene_items(scene, from, to).await?;
+        }
+
+        Ok(())
+    }
+
+    /// OBS loads the profile in the background, so wait until it reports the new one as current
+    /// before continuing.
+    #[cfg(feature = "events")]
+    async fn switch_and_wait(&self, profile_name: &str, deadline: Instant) -> Result<()> {
+        // Subscribe before switching, so the event can't be missed.
+        let events = self.client.events()?;
+        self.set_current_profile(profile_name).await?;
+
+        let changed = events.filter(|event| {
+            future::ready(matches!(
+                &event.ty,
+                EventType::ProfileChanged { profile } if profile == profile_name
+            ))
+        });
+        pin_mut!(changed);
+
+        match time::timeout_at(deadline, changed.next()).await {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(Error::Disconnected),
+            Err(_) => Err(Error::CanvasNotChanged(profile_name.to_owned())),
+        }
+    }
+
+    /// OBS loads the profile in the background, so wait until it reports the new one as current
+    /// before continuing.
+    #[cfg(not(feature = "events"))]
+    async fn switch_and_wait(&self, profile_name: &str, deadline: Instant) -> Result<()> {
+        self.set_current_profile(profile_name).await?;
+
+        while Instant::now() < deadline {
+            if self.get_current_profile().await? == profile_name {
+                return Ok(());
+            }
+            time::sleep(SWITCH_POLL_INTERVAL).await;
+        }
+
+        Err(Error::CanvasNotChanged(profile_name.to_owned()))
+    }
+
     /// Get the name of the current profile.
     pub async fn get_current_profile(&self) -> Result<String> {
         self.client
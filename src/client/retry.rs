@@ -0,0 +1,152 @@
+use std::future::Future;
+
+use chrono::Duration;
+use log::debug;
+use tokio::time;
+
+use crate::{Error, Result};
+
+/// Policy to retry requests that failed, for example because OBS is still loading or switching
+/// the scene collection. Used for all requests of a client with
+/// [`Client::with_retry_policy`](super::Client::with_retry_policy), or for single operations with
+/// [`run`](Self::run).
+///
+/// Retries are opt-in, as not all requests are safe to repeat. A request that failed may still
+/// have changed something in OBS.
+///
+/// ```
+/// use chrono::Duration;
+/// use obws::client::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 5,
+///     initial_backoff: Duration::milliseconds(200),
+///     ..RetryPolicy::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. A value of `1` or less disables
+    /// retries.
+    pub max_attempts: u32,
+    /// Time to wait before the first retry. The wait time doubles with each further retry.
+    pub initial_backoff: Duration,
+    /// Upper limit for the time to wait between two attempts.
+    pub max_backoff: Duration,
+    /// Decides whether a failed attempt is retried, by default
+    /// [`is_api_error`](Self::is_api_error).
+    pub retry_on: fn(&Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::milliseconds(100),
+            max_backoff: Duration::seconds(2),
+            retry_on: Self::is_api_error,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default predicate that retries all errors reported by obs-websocket. It does **not**
+    /// distinguish transient from permanent errors, as obs-websocket only reports errors as plain
+    /// messages. Permanent errors like a missing scene are retried as well, so a custom predicate
+    /// is needed to retry only specific errors. Local errors, like a lost connection or invalid
+    /// values, are never retried.
+    pub fn is_api_error(error: &Error) -> bool {
+        matches!(error, Error::Api(_))
+    }
+
+    /// Run the operation, and retry it according to this policy if it fails.
+    ///
+    /// - `operation`: Creates the future for each attempt.
+    pub async fn run<F, Fut, T>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let max_backoff = self.max_backoff.to_std().unwrap_or_default();
+        let mut backoff = self
+            .initial_backoff
+            .to_std()
+            .unwrap_or_default()
+            .min(max_backoff);
+        let mut attempt = 1;
+
+        loop {
+            match operation().await {
+                Err(e) if attempt < self.max_attempts && (self.retry_on)(&e) => {
+                    debug!(
+                        "attempt {} failed, retrying in {:?}: {}",
+                        attempt, backoff, e
+                    );
+                    time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(max_backoff);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::milliseconds(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_until_success() {
+        let attempts = AtomicU32::new(0);
+        let result = policy(3)
+            .run(|| async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(Error::Api("not ready".to_owned())),
+                    n => Ok(n),
+                }
+            })
+            .await;
+
+        assert_eq!(1, result.unwrap());
+        assert_eq!(2, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn stops_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+        let result = policy(3)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(Error::Api("not ready".to_owned()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::Api(_))));
+        assert_eq!(3, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn skips_local_errors() {
+        let attempts = AtomicU32::new(0);
+        let result = policy(3)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(Error::Disconnected)
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::Disconnected)));
+        assert_eq!(1, attempts.load(Ordering::SeqCst));
+    }
+}
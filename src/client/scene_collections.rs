@@ -1,7 +1,23 @@
+use std::future::Future;
+use std::time::Duration;
+
+#[cfg(feature = "events")]
+use futures_util::{future, pin_mut, StreamExt};
+use tokio::time;
+
 use super::Client;
+#[cfg(feature = "events")]
+use crate::events::EventType;
 use crate::requests::RequestType;
 use crate::responses;
-use crate::Result;
+use crate::setup::{Change, SceneCollectionDump, Setup};
+use crate::{Error, Result};
+
+/// Maximum time to wait for OBS to finish switching the scene collection, before giving up.
+const SWITCH_TIMEOUT: Duration = Duration::from_secs(5);
+/// Time between two checks whether OBS finished switching the scene collection.
+#[cfg(not(feature = "events"))]
+const SWITCH_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// API functions related to scene collections.
 pub struct SceneCollections<'a> {
@@ -35,4 +51,112 @@ impl<'a> SceneCollections<'a> {
             .await
             .map(|sc| sc.scene_collections)
     }
+
+    /// Export the scenes and sources of a scene collection into a serializable structure, for
+    /// example to back up the layout of an OBS instance whose files aren't reachable.
+    ///
+    /// **Note**: If the scene collection isn't the current one, OBS switches to it for the export,
+    /// which replaces all scenes and sources in the program output, stream and recording while it
+    /// runs. The previous scene collection is restored afterwards, even if the export failed.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::SceneCollectionNotLoaded`] if OBS didn't finish switching to the scene
+    /// collection in time. Nothing is exported in that case.
+    ///
+    /// - `sc_name`: Name of the scene collection to export.
+    pub async fn export(&self, sc_name: &str) -> Result<SceneCollectionDump> {
+        let setup = self
+            .with_collection(sc_name, Setup::capture(self.client))
+            .await?;
+
+        Ok(SceneCollectionDump {
+            name: sc_name.to_owned(),
+            setup,
+        })
+    }
+
+    /// Restore a scene collection from an [`export`](Self::export), by creating or updating all
+    /// scenes and sources that differ from the dump. See [`Setup::apply`] for details.
+    ///
+    /// **Note**: If the scene collection isn't the current one, OBS switches to it for the import,
+    /// which replaces all scenes and sources in the program output, stream and recording while it
+    /// runs. The previous scene collection is restored afterwards, even if the import failed.
+    ///
+    /// obs-websocket can't create new scene collections, so the collection named in the dump must
+    /// already exist.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::SceneCollectionNotLoaded`] if OBS didn't finish switching to the scene
+    /// collection in time. No scenes or sources are touched in that case.
+    pub async fn import(&self, dump: &SceneCollectionDump) -> Result<Vec<Change>> {
+        self.with_collection(&dump.name, dump.setup.apply(self.client))
+            .await
+    }
+
+    /// Run the given task while the given scene collection is the current one. If OBS has to
+    /// switch to it first, the previous scene collection is restored afterwards, no matter whether
+    /// the switch or the task failed.
+    async fn with_collection<T>(
+        &self,
+        sc_name: &str,
+        task: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let previous = self.get_current_scene_collection().await?;
+        if previous == sc_name {
+            return task.await;
+        }
+
+        let result = match self.switch_and_wait(sc_name).await {
+            Ok(()) => task.await,
+            Err(e) => Err(e),
+        };
+        let restored = self.switch_and_wait(&previous).await;
+
+        let value = result?;
+        restored?;
+        Ok(value)
+    }
+
+    /// OBS loads the scene collection in the background, so wait until it reports the new one as
+    /// current before continuing.
+    #[cfg(feature = "events")]
+    async fn switch_and_wait(&self, sc_name: &str) -> Result<()> {
+        // Subscribe before switching, so the event can't be missed.
+        let events = self.client.events()?;
+        self.set_current_scene_collection(sc_name).await?;
+
+        let changed = events.filter(|event| {
+            future::ready(matches!(
+                &event.ty,
+                EventType::SceneCollectionChanged { scene_collection }
+                    if scene_collection == sc_name
+            ))
+        });
+        pin_mut!(changed);
+
+        match time::timeout(SWITCH_TIMEOUT, changed.next()).await {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(Error::Disconnected),
+            Err(_) => Err(Error::SceneCollectionNotLoaded(sc_name.to_owned())),
+        }
+    }
+
+    /// OBS loads the scene collection in the background, so wait until it reports the new one as
+    /// current before continuing.
+    #[cfg(not(feature = "events"))]
+    async fn switch_and_wait(&self, sc_name: &str) -> Result<()> {
+        self.set_current_scene_collection(sc_name).await?;
+
+        let attempts = SWITCH_TIMEOUT.as_millis() / SWITCH_POLL_INTERVAL.as_millis();
+        for _ in 0..attempts {
+            if self.get_current_scene_collection().await? == sc_name {
+                return Ok(());
+            }
+            time::sleep(SWITCH_POLL_INTERVAL).await;
+        }
+
+        Err(Error::SceneCollectionNotLoaded(sc_name.to_owned()))
+    }
 }
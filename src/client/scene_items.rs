@@ -1,9 +1,11 @@
 use either::Either;
 
 use super::Client;
+use crate::common::{Alignment, BoundsType, SceneItemId, SceneItemSnapshot, SceneName};
 use crate::requests::{
-    AddSceneItem, DuplicateSceneItem, RequestType, SceneItemProperties, SceneItemRender,
-    SceneItemSpecification,
+    AddSceneItem, Bounds, CanvasSize, Corner, DuplicateSceneItem, ItemRef, Position, RequestType,
+    Scale, SceneItemProperties, SceneItemRender, SceneItemSpecification, SceneItemTransform,
+    SceneOrGroup,
 };
 use crate::responses;
 use crate::Result;
@@ -14,27 +16,45 @@ pub struct SceneItems<'a> {
 }
 
 impl<'a> SceneItems<'a> {
-    /// Get a list of all scene items in a scene.
+    /// Get a list of all scene items in a scene or group.
     ///
-    /// - `scene_name`: Name of the scene to get the list of scene items from. Defaults to the
-    ///   current scene if not specified.
+    /// - `scene_name`: Name of the scene or group to get the list of scene items from. Defaults to
+    ///   the current scene if not specified.
     pub async fn get_scene_item_list(
         &self,
-        scene_name: Option<&str>,
+        scene_name: Option<SceneOrGroup<'_>>,
     ) -> Result<responses::SceneItemList> {
         self.client
             .send_message(RequestType::GetSceneItemList { scene_name })
             .await
     }
 
+    /// Like [`get_scene_item_list`](Self::get_scene_item_list), but returns the response as
+    /// received. Decode it with [`RawResponse::parse`](responses::RawResponse::parse) into a
+    /// [`SceneItemListRef`](responses::SceneItemListRef), which borrows the names from the response
+    /// instead of allocating them.
+    ///
+    /// - `scene_name`: Name of the scene or group to get the list of scene items from. Defaults to
+    ///   the current scene if not specified.
+    pub async fn get_scene_item_list_raw(
+        &self,
+        scene_name: Option<SceneOrGroup<'_>>,
+    ) -> Result<responses::RawResponse> {
+        self.client
+            .send_message_raw(RequestType::GetSceneItemList { scene_name })
+            .await
+            .map(responses::RawResponse)
+    }
+
     /// Gets the scene specific properties of the specified source item. Coordinates are relative to
     /// the item's parent (the scene or group it belongs to).
     ///
-    /// - `scene_name`: Name of the scene the scene item belongs to. Defaults to the current scene.
+    /// - `scene_name`: Name of the scene or group the scene item belongs to. Defaults to the
+    ///   current scene.
     /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
     pub async fn get_scene_item_properties(
         &self,
-        scene_name: Option<&str>,
+        scene_name: Option<SceneOrGroup<'_>>,
         item: Either<&str, SceneItemSpecification<'_>>,
     ) -> Result<responses::SceneItemProperties> {
         self.client
@@ -53,13 +73,180 @@ impl<'a> SceneItems<'a> {
             .await
     }
 
-    /// Reset a scene item.
+    /// Apply a transform to a scene item. Only the values that were set on the `transform` are
+    /// changed.
+    ///
+    /// - `scene_name`: Name of the scene or group the scene item belongs to. Defaults to the
+    ///   current scene.
+    /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
+    /// - `transform`: The changes to apply.
+    pub async fn set_scene_item_transform(
+        &self,
+        scene_name: Option<SceneOrGroup<'_>>,
+        item: Either<&str, SceneItemSpecification<'_>>,
+        transform: SceneItemTransform,
+    ) -> Result<()> {
+        self.set_scene_item_properties(transform.into_properties(scene_name, item))
+            .await
+    }
+
+    /// Scale a scene item to fit into the canvas while keeping its aspect ratio, and center it.
+    ///
+    /// The canvas size is taken from [`get_video_info`](crate::client::General::get_video_info),
+    /// so this is meant for items of scenes, not of groups.
     ///
     /// - `scene_name`: Name of the scene the scene item belongs to. Defaults to the current scene.
     /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
+    pub async fn fit_to_canvas(
+        &self,
+        scene_name: Option<SceneOrGroup<'_>>,
+        item: Either<&str, SceneItemSpecification<'_>>,
+    ) -> Result<()> {
+        let canvas = self.canvas_size().await?;
+        let transform = SceneItemTransform::new()
+            .position(0.0, 0.0)
+            .alignment(Alignment::TOP | Alignment::LEFT)
+            .bounds(
+                BoundsType::ScaleInner,
+                canvas.width as f64,
+                canvas.height as f64,
+            )
+            .bounds_alignment(Alignment::empty());
+
+        self.set_scene_item_transform(scene_name, item, transform)
+            .await
+    }
+
+    /// Move a scene item to the center of the canvas, without changing its size.
+    ///
+    /// - `scene_name`: Name of the scene the scene item belongs to. Defaults to the current scene.
+    /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
+    pub async fn center(
+        &self,
+        scene_name: Option<SceneOrGroup<'_>>,
+        item: Either<&str, SceneItemSpecification<'_>>,
+    ) -> Result<()> {
+        let canvas = self.canvas_size().await?;
+        let transform = SceneItemTransform::new()
+            .position(canvas.width as f64 / 2.0, canvas.height as f64 / 2.0)
+            .alignment(Alignment::empty());
+
+        self.set_scene_item_transform(scene_name, item, transform)
+            .await
+    }
+
+    /// Move a scene item into a corner of the canvas, without changing its size.
+    ///
+    /// - `scene_name`: Name of the scene the scene item belongs to. Defaults to the current scene.
+    /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
+    /// - `corner`: The corner to place the item in.
+    /// - `margin`: Distance in pixels between the item and the edges of the canvas.
+    pub async fn anchor(
+        &self,
+        scene_name: Option<SceneOrGroup<'_>>,
+        item: Either<&str, SceneItemSpecification<'_>>,
+        corner: Corner,
+        margin: f64,
+    ) -> Result<()> {
+        let canvas = self.canvas_size().await?;
+        let (width, height) = (canvas.width as f64, canvas.height as f64);
+        let (x, y) = match corner {
+            Corner::TopLeft => (margin, margin),
+            Corner::TopRight => (width - margin, margin),
+            Corner::BottomLeft => (margin, height - margin),
+            Corner::BottomRight => (width - margin, height - margin),
+        };
+        let transform = SceneItemTransform::new()
+            .position(x, y)
+            .alignment(corner.into());
+
+        self.set_scene_item_transform(scene_name, item, transform)
+            .await
+    }
+
+    async fn canvas_size(&self) -> Result<CanvasSize> {
+        self.client
+            .general()
+            .get_video_info()
+            .await
+            .map(|info| CanvasSize::from(&info))
+    }
+
+    /// Capture all properties of the given scene items, to restore them later with
+    /// [`restore_scene_items`](Self::restore_scene_items). This allows temporary changes, like
+    /// enlarging an item while someone speaks, to be undone exactly.
+    ///
+    /// - `scene_name`: Name of the scene or group the scene items belong to.
+    /// - `items`: The scene items to capture.
+    pub async fn capture_scene_items(
+        &self,
+        scene_name: SceneOrGroup<'_>,
+        items: &[ItemRef<'_>],
+    ) -> Result<Vec<SceneItemSnapshot>> {
+        let mut snapshots = Vec::with_capacity(items.len());
+
+        for item in items {
+            let item = match *item {
+                ItemRef::ById(id) => Either::Right(SceneItemSpecification {
+                    name: None,
+                    id: Some(id),
+                }),
+                ItemRef::ByName(name) => Either::Left(name),
+            };
+            let props = self
+                .get_scene_item_properties(Some(scene_name), item)
+                .await?;
+
+            snapshots.push(SceneItemSnapshot {
+                scene_name: scene_name.name().clone(),
+                item_id: props.item_id,
+                name: props.name,
+                position: props.position,
+                rotation: props.rotation,
+                scale: props.scale,
+                crop: props.crop,
+                visible: props.visible,
+                locked: props.locked,
+                bounds: props.bounds,
+            });
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Apply previously captured properties to the scene items again.
+    ///
+    /// - `snapshots`: The captured properties of the scene items.
+    pub async fn restore_scene_items(&self, snapshots: &[SceneItemSnapshot]) -> Result<()> {
+        for snapshot in snapshots {
+            self.set_scene_item_properties(SceneItemProperties {
+                scene_name: Some((&snapshot.scene_name).into()),
+                item: Either::Right(SceneItemSpecification {
+                    name: Some(&snapshot.name),
+                    id: Some(snapshot.item_id),
+                }),
+                position: Some((&snapshot.position).into()),
+                rotation: Some(snapshot.rotation),
+                scale: Some((&snapshot.scale).into()),
+                crop: Some((&snapshot.crop).into()),
+                visible: Some(snapshot.visible),
+                locked: Some(snapshot.locked),
+                bounds: Some((&snapshot.bounds).into()),
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Reset a scene item.
+    ///
+    /// - `scene_name`: Name of the scene or group the scene item belongs to. Defaults to the
+    ///   current scene.
+    /// - `item`: Scene Item name (if this field is a string) or specification (if it is an object).
     pub async fn reset_scene_item(
         &self,
-        scene_name: Option<&str>,
+        scene_name: Option<SceneOrGroup<'_>>,
         item: Either<&str, SceneItemSpecification<'_>>,
     ) -> Result<()> {
         self.client
@@ -79,11 +266,12 @@ impl<'a> SceneItems<'a> {
 
     /// Deletes a scene item.
     ///
-    /// - `scene`: Name of the scene the scene item belongs to. Defaults to the current scene.
+    /// - `scene`: Name of the scene or group the scene item belongs to. Defaults to the current
+    ///   scene.
     /// - `item`: Scene item to delete.
     pub async fn delete_scene_item(
         &self,
-        scene: Option<&str>,
+        scene: Option<SceneOrGroup<'_>>,
         item: SceneItemSpecification<'_>,
     ) -> Result<()> {
         self.client
@@ -92,9 +280,9 @@ impl<'a> SceneItems<'a> {
     }
 
     /// Creates a scene item in a scene. In other words, this is how you add a source into a scene.
-    pub async fn add_scene_item(&self, scene_item: AddSceneItem<'_>) -> Result<i64> {
+    pub async fn add_scene_item(&self, scene_item: AddSceneItem<'_>) -> Result<SceneItemId> {
         self.client
-            .send_message::<responses::SceneItemId>(RequestType::AddSceneItem(scene_item))
+            .send_message::<responses::NewSceneItem>(RequestType::AddSceneItem(scene_item))
             .await
             .map(|sii| sii.item_id)
     }
@@ -108,4 +296,93 @@ impl<'a> SceneItems<'a> {
             .send_message(RequestType::DuplicateSceneItem(scene_item))
             .await
     }
+
+    /// Duplicates a scene item from one scene into another one, for example to copy parts of a
+    /// layout from a template scene. Returns the ID of the new scene item.
+    ///
+    /// - `from_scene`: Name of the scene to copy the item from.
+    /// - `item`: Scene item to duplicate.
+    /// - `to_scene`: Name of the scene to create the new item in. Can be the same as `from_scene`.
+    pub async fn duplicate_scene_item_to(
+        &self,
+        from_scene: impl Into<SceneName>,
+        item: SceneItemSpecification<'_>,
+        to_scene: impl Into<SceneName>,
+    ) -> Result<SceneItemId> {
+        let from_scene = from_scene.into();
+        let to_scene = to_scene.into();
+        self.duplicate_scene_item(DuplicateSceneItem {
+            from_scene: Some(&from_scene),
+            to_scene: Some(&to_scene),
+            item,
+        })
+        .await
+        .map(|duplicate| duplicate.item.id)
+    }
+
+    /// Rescale and reposition all scene items of a scene after the canvas size changed, so the
+    /// layout keeps its proportions.
+    ///
+    /// Positions are scaled on each axis individually, while the size of the items is scaled
+    /// uniformly by the smaller factor of both axis, to avoid distorting them. For example, when
+    /// switching from a 16:9 to a 9:16 canvas, all items keep their aspect ratio and relative
+    /// position but shrink to fit the narrower width.
+    ///
+    /// - `scene_name`: Name of the scene to rescale.
+    /// - `from`: Canvas size that the current layout was created for.
+    /// - `to`: New canvas size.
+    pub async fn rescale_scene_items(
+        &self,
+        scene_name: impl Into<SceneName>,
+        from: CanvasSize,
+        to: CanvasSize,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        if from == to || from.width == 0 || from.height == 0 {
+            return Ok(());
+        }
+
+        let factor_x = to.width as f64 / from.width as f64;
+        let factor_y = to.height as f64 / from.height as f64;
+        let factor = factor_x.min(factor_y);
+
+        let items = self.get_scene_item_list(Some((&scene_name).into())).await?;
+
+        for item in items.scene_items {
+            let spec = || SceneItemSpecification {
+                name: None,
+                id: Some(item.item_id),
+            };
+            let props = self
+                .get_scene_item_properties(Some((&scene_name).into()), Either::Right(spec()))
+                .await?;
+
+            self.set_scene_item_properties(SceneItemProperties {
+                scene_name: Some((&scene_name).into()),
+                item: Either::Right(spec()),
+                position: Some(Position {
+                    x: Some(props.position.x * factor_x),
+                    y: Some(props.position.y * factor_y),
+                    alignment: None,
+                }),
+                scale: Some(Scale {
+                    x: Some(props.scale.x * factor),
+                    y: Some(props.scale.y * factor),
+                }),
+                bounds: if matches!(props.bounds.ty, BoundsType::None) {
+                    None
+                } else {
+                    Some(Bounds {
+                        x: Some(props.bounds.x * factor),
+                        y: Some(props.bounds.y * factor),
+                        ..Bounds::default()
+                    })
+                },
+                ..SceneItemProperties::default()
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
 }
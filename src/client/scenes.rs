@@ -1,7 +1,17 @@
+use either::Either;
+use futures_util::future::{BoxFuture, FutureExt};
+
 use super::Client;
-use crate::requests::{RequestType, SceneItem, SceneTransitionOverride};
+use crate::common::{GroupSettings, SceneItemId, SceneName, SourceKind, SourceName};
+use crate::requests::{
+    CreateSource, ItemRef, RequestType, SceneItem, SceneItemSpecification, SceneItemTransform,
+    SceneOrGroup, SceneTransitionOverride, SourceKindSettings, SourceSettings,
+};
 use crate::responses;
-use crate::Result;
+use crate::{Error, Result};
+
+/// Source kind of groups, as reported by obs-websocket.
+const GROUP_KIND: &str = "group";
 
 /// API functions related to scenes.
 pub struct Scenes<'a> {
@@ -12,9 +22,12 @@ impl<'a> Scenes<'a> {
     /// Switch to the specified scene.
     ///
     /// - `scene_name`: Name of the scene to switch to.
-    pub async fn set_current_scene(&self, scene_name: &str) -> Result<()> {
+    pub async fn set_current_scene(&self, scene_name: impl Into<SceneName>) -> Result<()> {
+        let scene_name = scene_name.into();
         self.client
-            .send_message(RequestType::SetCurrentScene { scene_name })
+            .send_message(RequestType::SetCurrentScene {
+                scene_name: &scene_name,
+            })
             .await
     }
 
@@ -28,12 +41,238 @@ impl<'a> Scenes<'a> {
         self.client.send_message(RequestType::GetSceneList).await
     }
 
+    /// Get all scenes of the current profile as a single tree, including the properties of all
+    /// their items and the content of groups.
+    ///
+    /// This is a convenience over [`get_scene_list`](Self::get_scene_list),
+    /// [`get_scene_item_list`](crate::client::SceneItems::get_scene_item_list) and
+    /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties), that
+    /// sends several requests per scene. It's not atomic, so changes done in the meantime may
+    /// only be partially reflected.
+    pub async fn snapshot(&self) -> Result<responses::SceneGraph> {
+        let list = self.get_scene_list().await?;
+        let mut scenes = Vec::with_capacity(list.scenes.len());
+
+        for scene in list.scenes {
+            let items = self
+                .snapshot_items(SceneOrGroup::Scene(&scene.name))
+                .await?;
+            scenes.push(responses::SceneNode {
+                name: scene.name,
+                items,
+            });
+        }
+
+        Ok(responses::SceneGraph {
+            current_scene: list.current_scene,
+            scenes,
+        })
+    }
+
+    /// Collect all items of a scene or group, recursing into groups.
+    fn snapshot_items<'b>(
+        &'b self,
+        parent: SceneOrGroup<'b>,
+    ) -> BoxFuture<'b, Result<Vec<responses::SceneItemNode>>> {
+        async move {
+            let scene_items = self.client.scene_items();
+            let list = scene_items.get_scene_item_list(Some(parent)).await?;
+            let mut items = Vec::with_capacity(list.scene_items.len());
+
+            for item in list.scene_items {
+                let properties = scene_items
+                    .get_scene_item_properties(
+                        Some(parent),
+                        Either::Right(SceneItemSpecification {
+                            name: Some(&item.source_name),
+                            id: Some(item.item_id),
+                        }),
+                    )
+                    .await?;
+                let children = if item.source_type == GROUP_KIND {
+                    let group = SceneName::from(item.source_name.as_str());
+                    self.snapshot_items(SceneOrGroup::Group(&group)).await?
+                } else {
+                    Vec::new()
+                };
+
+                items.push(responses::SceneItemNode {
+                    source_kind: item.source_kind,
+                    source_type: item.source_type,
+                    properties,
+                    children,
+                });
+            }
+
+            Ok(items)
+        }
+        .boxed()
+    }
+
+    /// Create a new source and add it to a scene, optionally applying a transform right away.
+    /// Returns the ID of the new scene item.
+    ///
+    /// If the transform can't be applied, the new scene item is removed again, so the scene is
+    /// left as it was.
+    ///
+    /// - `scene_name`: Name of the scene to add the source to.
+    /// - `source_name`: Name of the new source.
+    /// - `settings`: Settings of the new source, which also define its kind.
+    /// - `transform`: Transform to apply to the new scene item.
+    pub async fn add_source<S>(
+        &self,
+        scene_name: impl Into<SceneName>,
+        source_name: impl Into<SourceName>,
+        settings: &S,
+        transform: Option<SceneItemTransform>,
+    ) -> Result<SceneItemId>
+    where
+        S: SourceKindSettings,
+    {
+        let scene_name = scene_name.into();
+        let source_name = source_name.into();
+        let source_settings = serde_json::to_value(settings).map_err(Error::SerializeCustomData)?;
+        let item_id = self
+            .client
+            .sources()
+            .create_source(CreateSource {
+                source_name: &source_name,
+                source_kind: settings.kind(),
+                scene_name: &scene_name,
+                source_settings: Some(&source_settings),
+                set_visible: None,
+            })
+            .await?;
+
+        if let Some(transform) = transform {
+            let item = SceneItemSpecification {
+                name: Some(&source_name),
+                id: Some(item_id),
+            };
+            let scene_items = self.client.scene_items();
+
+            if let Err(e) = scene_items
+                .set_scene_item_transform(
+                    Some((&scene_name).into()),
+                    Either::Right(item),
+                    transform,
+                )
+                .await
+            {
+                scene_items
+                    .delete_scene_item(Some((&scene_name).into()), item)
+                    .await
+                    .ok();
+                return Err(e);
+            }
+        }
+
+        Ok(item_id)
+    }
+
+    /// Get a list of all groups in the current scene collection.
+    ///
+    /// obs-websocket doesn't offer a dedicated request for this, so the groups are filtered out of
+    /// the [`get_sources_list`](crate::client::Sources::get_sources_list) response.
+    pub async fn get_group_list(&self) -> Result<Vec<SceneName>> {
+        self.client
+            .sources()
+            .get_sources_list()
+            .await
+            .map(|sources| {
+                sources
+                    .into_iter()
+                    .filter(|source| source.type_id == SourceKind::Group)
+                    .map(|source| String::from(source.name).into())
+                    .collect()
+            })
+    }
+
+    /// Get the items inside a group, ordered from the bottom to the top item. This is the reverse
+    /// of the order shown in the OBS UI, which lists the top item first.
+    ///
+    /// The position, scale and rotation of these items are relative to the group, not to the scene
+    /// the group is placed in. The same applies when changing them through
+    /// [`SceneItems`](crate::client::SceneItems) with [`SceneOrGroup::Group`].
+    ///
+    /// - `group_name`: Name of the group.
+    pub async fn get_group_children(
+        &self,
+        group_name: impl Into<SceneName>,
+    ) -> Result<Vec<responses::SceneItemListItem>> {
+        let group_name = group_name.into();
+        self.client
+            .scene_items()
+            .get_scene_item_list(Some(SceneOrGroup::Group(&group_name)))
+            .await
+            .map(|list| list.scene_items)
+    }
+
+    /// Get the typed settings of a group.
+    ///
+    /// - `group_name`: Name of the group.
+    pub async fn get_group_settings(
+        &self,
+        group_name: impl Into<SceneName>,
+    ) -> Result<GroupSettings> {
+        let group_name = group_name.into();
+        self.client
+            .sources()
+            .get_source_settings::<GroupSettings>(group_name.as_str(), Some(GROUP_KIND))
+            .await
+            .map(|ss| ss.source_settings)
+    }
+
+    /// Update the typed settings of a group. Other settings of the group are left untouched.
+    ///
+    /// - `group_name`: Name of the group.
+    /// - `settings`: New settings for the group.
+    pub async fn set_group_settings(
+        &self,
+        group_name: impl Into<SceneName>,
+        settings: &GroupSettings,
+    ) -> Result<()> {
+        let group_name = group_name.into();
+        self.client
+            .sources()
+            .set_source_settings::<serde_json::Value>(SourceSettings {
+                source_name: &SourceName::from(group_name.as_str()),
+                source_type: Some(GROUP_KIND),
+                source_settings: settings,
+            })
+            .await
+            .map(|_| ())
+    }
+
     /// Create a new scene scene.
     ///
     /// - `scene_name`: Name of the scene to create.
-    pub async fn create_scene(&self, scene_name: &str) -> Result<()> {
+    pub async fn create_scene(&self, scene_name: impl Into<SceneName>) -> Result<()> {
+        let scene_name = scene_name.into();
+        self.client
+            .send_message(RequestType::CreateScene {
+                scene_name: &scene_name,
+            })
+            .await
+    }
+
+    /// Rename a scene. Scenes are sources in OBS, so this is the same as
+    /// [`Sources::set_source_name`](super::Sources::set_source_name), but with typed scene names.
+    ///
+    /// - `scene_name`: Current name of the scene.
+    /// - `new_name`: New name of the scene.
+    pub async fn set_scene_name(
+        &self,
+        scene_name: impl Into<SceneName>,
+        new_name: impl Into<SceneName>,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        let new_name = new_name.into();
         self.client
-            .send_message(RequestType::CreateScene { scene_name })
+            .send_message(RequestType::SetSourceName {
+                source_name: &scene_name,
+                new_name: &new_name,
+            })
             .await
     }
 
@@ -44,7 +283,7 @@ impl<'a> Scenes<'a> {
     ///   uniqueness per scene
     pub async fn reorder_scene_items(
         &self,
-        scene: Option<&str>,
+        scene: Option<&SceneName>,
         items: &[SceneItem<'_>],
     ) -> Result<()> {
         self.client
@@ -52,6 +291,91 @@ impl<'a> Scenes<'a> {
             .await
     }
 
+    /// Change the order of the items in a scene, from the bottom to the top item.
+    ///
+    /// - `scene_name`: Name of the scene to reorder.
+    /// - `items`: All items of the scene in their new order, starting at the bottom.
+    pub async fn reorder_items(
+        &self,
+        scene_name: impl Into<SceneName>,
+        items: &[ItemRef<'_>],
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        let items = items
+            .iter()
+            .map(|&item| item.into())
+            .collect::<Vec<SceneItem<'_>>>();
+
+        self.reorder_scene_items(Some(&scene_name), &items).await
+    }
+
+    /// Move an item above all other items of the scene.
+    ///
+    /// - `scene_name`: Name of the scene the item belongs to.
+    /// - `item`: The item to move.
+    pub async fn move_to_top(
+        &self,
+        scene_name: impl Into<SceneName>,
+        item: ItemRef<'_>,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        self.move_item(&scene_name, item, Placement::Top).await
+    }
+
+    /// Move an item below all other items of the scene.
+    ///
+    /// - `scene_name`: Name of the scene the item belongs to.
+    /// - `item`: The item to move.
+    pub async fn move_to_bottom(
+        &self,
+        scene_name: impl Into<SceneName>,
+        item: ItemRef<'_>,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        self.move_item(&scene_name, item, Placement::Bottom).await
+    }
+
+    /// Move an item directly above another item of the same scene. Nothing is changed if both
+    /// refer to the same item.
+    ///
+    /// - `scene_name`: Name of the scene both items belong to.
+    /// - `item`: The item to move.
+    /// - `other`: The item that will be directly below the moved item.
+    pub async fn move_above(
+        &self,
+        scene_name: impl Into<SceneName>,
+        item: ItemRef<'_>,
+        other: ItemRef<'_>,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
+        self.move_item(&scene_name, item, Placement::Above(other))
+            .await
+    }
+
+    /// Take an item out of the current order of the scene and insert it again at the given
+    /// placement.
+    async fn move_item(
+        &self,
+        scene_name: &SceneName,
+        item: ItemRef<'_>,
+        placement: Placement<'_>,
+    ) -> Result<()> {
+        let list = self
+            .client
+            .scene_items()
+            .get_scene_item_list(Some(SceneOrGroup::Scene(scene_name)))
+            .await?
+            .scene_items;
+
+        match moved_order(&list, item, placement)? {
+            Some(order) => {
+                let order = order.into_iter().map(ItemRef::ById).collect::<Vec<_>>();
+                self.reorder_items(scene_name, &order).await
+            }
+            None => Ok(()),
+        }
+    }
+
     /// Set a scene to use a specific transition override.
     pub async fn set_scene_transition_override(
         &self,
@@ -65,9 +389,15 @@ impl<'a> Scenes<'a> {
     /// Remove any transition override on a scene.
     ///
     /// - `scene_name`: Name of the scene to remove the override from.
-    pub async fn remove_scene_transition_override(&self, scene_name: &str) -> Result<()> {
+    pub async fn remove_scene_transition_override(
+        &self,
+        scene_name: impl Into<SceneName>,
+    ) -> Result<()> {
+        let scene_name = scene_name.into();
         self.client
-            .send_message(RequestType::RemoveSceneTransitionOverride { scene_name })
+            .send_message(RequestType::RemoveSceneTransitionOverride {
+                scene_name: &scene_name,
+            })
             .await
     }
 
@@ -76,10 +406,152 @@ impl<'a> Scenes<'a> {
     /// - `scene_name`: Name of the scene to get the override for.
     pub async fn get_scene_transition_override(
         &self,
-        scene_name: &str,
+        scene_name: impl Into<SceneName>,
     ) -> Result<responses::SceneTransitionOverride> {
+        let scene_name = scene_name.into();
         self.client
-            .send_message(RequestType::GetSceneTransitionOverride { scene_name })
+            .send_message(RequestType::GetSceneTransitionOverride {
+                scene_name: &scene_name,
+            })
             .await
     }
 }
+
+/// Target position of an item that is moved within its scene.
+#[derive(Clone, Copy)]
+enum Placement<'a> {
+    /// Above all other items.
+    Top,
+    /// Below all other items.
+    Bottom,
+    /// Directly above another item.
+    Above(ItemRef<'a>),
+}
+
+/// Compute the new order (bottom to top) of the scene items, after moving the item to the given
+/// placement. Returns `None` if the order doesn't change, because the item should be moved above
+/// itself.
+fn moved_order(
+    list: &[responses::SceneItemListItem],
+    item: ItemRef<'_>,
+    placement: Placement<'_>,
+) -> Result<Option<Vec<SceneItemId>>> {
+    let id = find_item(list, item)?;
+    let other = match placement {
+        Placement::Above(other) => Some(find_item(list, other)?),
+        Placement::Top | Placement::Bottom => None,
+    };
+
+    if other == Some(id) {
+        return Ok(None);
+    }
+
+    let mut order = list
+        .iter()
+        .map(|item| item.item_id)
+        .filter(|&item_id| item_id != id)
+        .collect::<Vec<_>>();
+
+    let pos = match (placement, other) {
+        (Placement::Bottom, _) => 0,
+        (Placement::Above(_), Some(other)) => order
+            .iter()
+            .position(|&i| i == other)
+            .map_or(order.len(), |pos| pos + 1),
+        _ => order.len(),
+    };
+    order.insert(pos, id);
+
+    Ok(Some(order))
+}
+
+/// Find the ID of the referenced item in the list of scene items.
+fn find_item(list: &[responses::SceneItemListItem], item: ItemRef<'_>) -> Result<SceneItemId> {
+    list.iter()
+        .find(|i| match item {
+            ItemRef::ById(id) => i.item_id == id,
+            ItemRef::ByName(name) => i.source_name == name,
+        })
+        .map(|i| i.item_id)
+        .ok_or_else(|| {
+            Error::UnknownSceneItem(match item {
+                ItemRef::ById(id) => id.to_string(),
+                ItemRef::ByName(name) => name.to_owned(),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(names: &[&str]) -> Vec<responses::SceneItemListItem> {
+        names
+            .iter()
+            .zip(1..)
+            .map(|(name, id)| responses::SceneItemListItem {
+                item_id: SceneItemId(id),
+                source_kind: "color_source".to_owned(),
+                source_name: (*name).into(),
+                source_type: "input".to_owned(),
+            })
+            .collect()
+    }
+
+    fn ids(ids: &[i64]) -> Option<Vec<SceneItemId>> {
+        Some(ids.iter().copied().map(SceneItemId).collect())
+    }
+
+    #[test]
+    fn move_items() {
+        let list = list(&["a", "b", "c"]);
+
+        assert_eq!(
+            ids(&[2, 3, 1]),
+            moved_order(&list, ItemRef::ByName("a"), Placement::Top).unwrap()
+        );
+        assert_eq!(
+            ids(&[3, 1, 2]),
+            moved_order(&list, ItemRef::ByName("c"), Placement::Bottom).unwrap()
+        );
+        assert_eq!(
+            ids(&[2, 3, 1]),
+            moved_order(
+                &list,
+                ItemRef::ByName("a"),
+                Placement::Above(ItemRef::ByName("c"))
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            ids(&[1, 3, 2]),
+            moved_order(
+                &list,
+                ItemRef::ById(SceneItemId(3)),
+                Placement::Above(ItemRef::ByName("a"))
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn move_above_same_item() {
+        let list = list(&["a", "b"]);
+
+        assert_eq!(
+            None,
+            moved_order(
+                &list,
+                ItemRef::ByName("b"),
+                Placement::Above(ItemRef::ById(SceneItemId(2)))
+            )
+            .unwrap()
+        );
+        assert!(moved_order(
+            &list,
+            ItemRef::ByName("b"),
+            Placement::Above(ItemRef::ByName("x"))
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,30 @@
 use chrono::Duration;
-use serde::de::DeserializeOwned;
+#[cfg(feature = "events")]
+use futures_util::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::time::{self, Instant};
 
 use super::Client;
-use crate::common::MonitorType;
+use crate::common::{
+    DeviceValue, MonitorType, SceneItem, SceneItemId, SourceKind, SourceName, TrackMask, Volume,
+};
+#[cfg(feature = "events")]
+use crate::events::EventType;
 use crate::requests::{
-    AddFilter, CreateSource, MoveFilter, ReorderFilter, RequestType, SourceFilterSettings,
-    SourceFilterVisibility, SourceScreenshot, SourceSettings, TextFreetype2Properties,
-    TextGdiPlusProperties, Volume,
+    AddFilter, CreateSource, CustomSource, MoveFilter, MovementType, ReorderFilter, RequestType,
+    SourceFilterSettings, SourceFilterVisibility, SourceKindSettings, SourceScreenshot,
+    SourceSettings, SourceSettingsPatch, TextFreetype2Properties, TextGdiPlusProperties,
+    TextSource, TextSourceKind, VolumeFade,
 };
 use crate::responses;
-use crate::Result;
+use crate::settings;
+use crate::{Error, Result};
+
+/// Time between two checks whether a source became active.
+const ACTIVE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+/// Time between two checks whether a capture source started capturing. Each check requests the
+/// full scene list, so it runs less often than the check for active sources.
+const CAPTURE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
 
 /// API functions related to sources.
 pub struct Sources<'a> {
@@ -26,7 +41,7 @@ impl<'a> Sources<'a> {
     }
 
     /// Create a source and add it as a scene item to a scene.
-    pub async fn create_source(&self, source: CreateSource<'_>) -> Result<i64> {
+    pub async fn create_source(&self, source: CreateSource<'_>) -> Result<SceneItemId> {
         self.client
             .send_message::<responses::SourceItemId>(RequestType::CreateSource(source))
             .await
@@ -49,38 +64,106 @@ impl<'a> Sources<'a> {
             .map(|stl| stl.types)
     }
 
-    /// Get the volume of the specified source. Default response uses mul format, NOT SLIDER
-    /// PERCENTAGE.
+    /// Get the volume of the specified source.
     ///
     /// - `source`: Source name.
-    /// - `use_decibel`: Output volume in decibels of attenuation instead of amplitude/mul.
-    pub async fn get_volume(
-        &self,
-        source: &str,
-        use_decibel: Option<bool>,
-    ) -> Result<responses::Volume> {
+    pub async fn get_volume(&self, source: impl Into<SourceName>) -> Result<responses::Volume> {
+        let source = source.into();
+        self.client
+            .send_message(RequestType::GetVolume { source: &source })
+            .await
+    }
+
+    /// Set the volume of the specified source. The volume is sent in the unit it was created with,
+    /// through [`Volume::mul`] or [`Volume::db`].
+    ///
+    /// OBS supports amplitudes between `0.0` and `20.0` (or up to `26.0` dB) and interprets values
+    /// under -100.0 dB as silence. The OBS volume sliders only reach a maximum of 1.0 mul or
+    /// 0.0 dB.
+    ///
+    /// - `source`: Source name.
+    /// - `volume`: Desired volume.
+    pub async fn set_volume(&self, source: impl Into<SourceName>, volume: Volume) -> Result<()> {
+        let source = source.into();
+        let (volume, use_decibel) = match volume {
+            Volume::Mul(mul) => (mul, false),
+            Volume::Db(db) => (db, true),
+        };
+
         self.client
-            .send_message(RequestType::GetVolume {
-                source,
+            .send_message(RequestType::SetVolume {
+                source: &source,
+                volume,
                 use_decibel,
             })
             .await
     }
 
-    /// Set the volume of the specified source. Default request format uses mul, NOT SLIDER
-    /// PERCENTAGE.
-    pub async fn set_volume(&self, volume: Volume<'_>) -> Result<()> {
-        self.client
-            .send_message(RequestType::SetVolume(volume))
+    /// Gradually change the volume of a source from its current value to `target`, with the
+    /// default [`VolumeFade`] settings. See [`fade_volume_with`](Self::fade_volume_with) for
+    /// details.
+    ///
+    /// - `source`: Source name.
+    /// - `target`: Volume at the end of the fade.
+    /// - `duration`: Total time the fade takes.
+    pub async fn fade_volume(
+        &self,
+        source: impl Into<SourceName>,
+        target: Volume,
+        duration: Duration,
+    ) -> Result<()> {
+        self.fade_volume_with(source, target, duration, VolumeFade::default())
             .await
     }
 
+    /// Gradually change the volume of a source from its current value to `target`, by sending
+    /// intermediate volume changes every [`step`](VolumeFade::step).
+    ///
+    /// The fade is calculated in the unit of the `target`. Fading in decibels sounds more even to
+    /// the human ear, while fading the amplitude multiplier drops off quickly towards the end. The
+    /// final request always sets the exact `target` value.
+    ///
+    /// - `source`: Source name.
+    /// - `target`: Volume at the end of the fade.
+    /// - `duration`: Total time the fade takes.
+    /// - `fade`: Step interval and easing of the fade.
+    pub async fn fade_volume_with(
+        &self,
+        source: impl Into<SourceName>,
+        target: Volume,
+        duration: Duration,
+        fade: VolumeFade,
+    ) -> Result<()> {
+        let source = source.into();
+        let duration = duration.to_std().unwrap_or_default();
+        let step = fade.step.to_std().unwrap_or_default();
+        let start = self.get_volume(&source).await?.volume;
+
+        if !step.is_zero() {
+            let started = Instant::now();
+            let steps = (duration.as_secs_f64() / step.as_secs_f64()).ceil() as u32;
+
+            for i in 1..steps {
+                time::sleep_until(started + step * i).await;
+
+                let progress = (step * i).as_secs_f64() / duration.as_secs_f64();
+                let volume = fade_step(start, target, fade.easing.apply(progress));
+                self.set_volume(&source, volume).await?;
+            }
+
+            time::sleep_until(started + duration).await;
+        }
+
+        self.set_volume(source, target).await
+    }
+
     /// Get the mute status of a specified source.
     ///
     /// - `source`: Source name.
-    pub async fn get_mute(&self, source: &str) -> Result<responses::Mute> {
+    pub async fn get_mute(&self, source: impl Into<SourceName>) -> Result<responses::Mute> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::GetMute { source })
+            .send_message(RequestType::GetMute { source: &source })
             .await
     }
 
@@ -88,27 +171,178 @@ impl<'a> Sources<'a> {
     ///
     /// - `source`: Source name.
     /// - `mute`: Desired mute status.
-    pub async fn set_mute(&self, source: &str, mute: bool) -> Result<()> {
+    pub async fn set_mute(&self, source: impl Into<SourceName>, mute: bool) -> Result<()> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::SetMute { source, mute })
+            .send_message(RequestType::SetMute {
+                source: &source,
+                mute,
+            })
             .await
     }
 
     /// Inverts the mute status of a specified source.
     ///
     /// - `source`: Source name.
-    pub async fn toggle_mute(&self, source: &str) -> Result<()> {
+    pub async fn toggle_mute(&self, source: impl Into<SourceName>) -> Result<()> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::ToggleMute { source })
+            .send_message(RequestType::ToggleMute { source: &source })
+            .await
+    }
+
+    /// Get the source's active status of a specified source (if it is showing in the final mix).
+    ///
+    /// - `source_name`: Source name.
+    pub async fn get_source_active(&self, source_name: impl Into<SourceName>) -> Result<bool> {
+        let source_name = source_name.into();
+        self.client
+            .send_message::<responses::SourceActive>(RequestType::GetSourceActive {
+                source_name: &source_name,
+            })
+            .await
+            .map(|sa| sa.source_active)
+    }
+
+    /// Wait until the source is showing in the final mix, for example to start recording only
+    /// once a camera is live. Returns immediately if the source is already active.
+    ///
+    /// obs-websocket doesn't emit an event when the video of a source becomes active, so the
+    /// status is polled repeatedly.
+    ///
+    /// - `source_name`: Source name.
+    /// - `timeout`: Maximum time to wait for the source to become active.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::SourceNotActive`] if the source didn't become active in time.
+    pub async fn await_active(
+        &self,
+        source_name: impl Into<SourceName>,
+        timeout: Duration,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        let poll = async {
+            while !self.get_source_active(&source_name).await? {
+                time::sleep(ACTIVE_POLL_INTERVAL).await;
+            }
+
+            Ok(())
+        };
+
+        time::timeout(timeout.to_std().unwrap_or_default(), poll)
+            .await
+            .map_err(|_| Error::SourceNotActive(source_name.to_string()))?
+    }
+
+    /// Wait until the source's audio becomes active, signaled by the
+    /// [`SourceAudioActivated`](EventType::SourceAudioActivated) event. Returns immediately if the
+    /// audio is already active.
+    ///
+    /// - `source_name`: Source name.
+    /// - `timeout`: Maximum time to wait for the audio to become active.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::SourceNotActive`] if the audio didn't become active in time, or with
+    /// [`Error::Disconnected`] if the client is disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub async fn await_audio_active(
+        &self,
+        source_name: impl Into<SourceName>,
+        timeout: Duration,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        // Subscribe before checking the status, to not miss an event in between.
+        let events = self.client.events()?;
+        futures_util::pin_mut!(events);
+
+        if self.get_audio_active(&source_name).await? {
+            return Ok(());
+        }
+
+        let activated = async {
+            while let Some(event) = events.next().await {
+                if let EventType::SourceAudioActivated { source_name: name } = event.ty {
+                    if name == source_name {
+                        return Ok(());
+                    }
+                }
+            }
+
+            Err(Error::Disconnected)
+        };
+
+        time::timeout(timeout.to_std().unwrap_or_default(), activated)
             .await
+            .map_err(|_| Error::SourceNotActive(source_name.to_string()))?
+    }
+
+    /// Check whether a capture source currently captures anything, for example whether a
+    /// [`GameCapture`](crate::requests::GameCapture) successfully hooked into the game.
+    ///
+    /// obs-websocket doesn't report the hook status directly, so a capture is considered active
+    /// once its source has a size. The source must be part of at least one scene.
+    ///
+    /// - `source_name`: Source name.
+    pub async fn is_capture_active(&self, source_name: impl Into<SourceName>) -> Result<bool> {
+        fn has_size(items: &[SceneItem], source_name: &str) -> bool {
+            items.iter().any(|item| {
+                (item.name == source_name && item.source_cx > 0.0 && item.source_cy > 0.0)
+                    || has_size(&item.group_children, source_name)
+            })
+        }
+
+        let source_name = source_name.into();
+
+        self.client.scenes().get_scene_list().await.map(|list| {
+            list.scenes
+                .iter()
+                .any(|scene| has_size(&scene.sources, &source_name))
+        })
+    }
+
+    /// Wait until a capture source captures anything, for example to fall back to a different
+    /// scene if a game capture fails to hook into the game. Returns immediately if the capture is
+    /// already active.
+    ///
+    /// The status is polled repeatedly, as described in
+    /// [`is_capture_active`](Self::is_capture_active).
+    ///
+    /// - `source_name`: Source name.
+    /// - `timeout`: Maximum time to wait for the capture to become active.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::CaptureNotActive`] if the capture didn't become active in time.
+    pub async fn await_capture_active(
+        &self,
+        source_name: impl Into<SourceName>,
+        timeout: Duration,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        let poll = async {
+            while !self.is_capture_active(&source_name).await? {
+                time::sleep(CAPTURE_POLL_INTERVAL).await;
+            }
+
+            Ok(())
+        };
+
+        time::timeout(timeout.to_std().unwrap_or_default(), poll)
+            .await
+            .map_err(|_| Error::CaptureNotActive(source_name.to_string()))?
     }
 
     /// Get the audio's active status of a specified source.
     ///
     /// - `source_name`: Source name.
-    pub async fn get_audio_active(&self, source_name: &str) -> Result<bool> {
+    pub async fn get_audio_active(&self, source_name: impl Into<SourceName>) -> Result<bool> {
+        let source_name = source_name.into();
         self.client
-            .send_message::<responses::AudioActive>(RequestType::GetAudioActive { source_name })
+            .send_message::<responses::AudioActive>(RequestType::GetAudioActive {
+                source_name: &source_name,
+            })
             .await
             .map(|aa| aa.audio_active)
     }
@@ -119,11 +353,17 @@ impl<'a> Sources<'a> {
     ///
     /// - `source_name`: Source name.
     /// - `new_name`: New source name.
-    pub async fn set_source_name(&self, source_name: &str, new_name: &str) -> Result<()> {
+    pub async fn set_source_name(
+        &self,
+        source_name: impl Into<SourceName>,
+        new_name: impl Into<SourceName>,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        let new_name = new_name.into();
         self.client
             .send_message(RequestType::SetSourceName {
-                source_name,
-                new_name,
+                source_name: &source_name,
+                new_name: &new_name,
             })
             .await
     }
@@ -131,65 +371,216 @@ impl<'a> Sources<'a> {
     /// Set the audio sync offset of a specified source.
     ///
     /// - `source`: Source name.
-    /// - `offset`: The desired audio sync offset (in nanoseconds).
-    pub async fn set_sync_offset(&self, source: &str, offset: Duration) -> Result<()> {
+    /// - `offset`: The desired audio sync offset, transferred with nanosecond precision.
+    pub async fn set_sync_offset(
+        &self,
+        source: impl Into<SourceName>,
+        offset: Duration,
+    ) -> Result<()> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::SetSyncOffset { source, offset })
+            .send_message(RequestType::SetSyncOffset {
+                source: &source,
+                offset,
+            })
             .await
     }
 
     /// Get the audio sync offset of a specified source.
     ///
     /// - `source`: Source name.
-    pub async fn get_sync_offset(&self, source: &str) -> Result<responses::SyncOffset> {
+    pub async fn get_sync_offset(
+        &self,
+        source: impl Into<SourceName>,
+    ) -> Result<responses::SyncOffset> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::GetSyncOffset { source })
+            .send_message(RequestType::GetSyncOffset { source: &source })
             .await
     }
 
     /// Get settings of the specified source.
     ///
+    /// The settings are deserialized into `T`, which can be typed settings, a `serde_json::Value`
+    /// or a `Box<RawValue>` to keep the raw JSON without parsing it.
+    ///
     /// - `source_name`: Source name.
     /// - `source_type`: Type of the specified source. Useful for type-checking if you expect a
     ///   specific settings schema.
     pub async fn get_source_settings<T>(
         &self,
-        source_name: &str,
+        source_name: impl Into<SourceName>,
         source_type: Option<&str>,
     ) -> Result<responses::SourceSettings<T>>
     where
         T: DeserializeOwned,
     {
+        let source_name = source_name.into();
+        self.client
+            .send_message_direct(RequestType::GetSourceSettings {
+                source_name: &source_name,
+                source_type,
+            })
+            .await
+    }
+
+    /// Like [`get_source_settings`](Self::get_source_settings), but returns the response as
+    /// received. Decode it with [`RawResponse::parse`](responses::RawResponse::parse) into a
+    /// [`SourceSettingsRef`](responses::SourceSettingsRef), which borrows the names and settings
+    /// from the response instead of allocating them.
+    ///
+    /// - `source_name`: Source name.
+    /// - `source_type`: Type of the specified source.
+    pub async fn get_source_settings_raw(
+        &self,
+        source_name: impl Into<SourceName>,
+        source_type: Option<&str>,
+    ) -> Result<responses::RawResponse> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::GetSourceSettings {
-                source_name,
+            .send_message_raw(RequestType::GetSourceSettings {
+                source_name: &source_name,
                 source_type,
             })
             .await
+            .map(responses::RawResponse)
     }
 
-    /// Set settings of the specified source.
+    /// Set settings of the specified source. The settings can be any serializable value, see
+    /// [`SourceSettings`] for details.
     pub async fn set_source_settings<T>(
         &self,
-        source_settings: SourceSettings<'_>,
+        source_settings: SourceSettings<'_, impl Serialize + ?Sized>,
     ) -> Result<responses::SourceSettings<T>>
     where
         T: DeserializeOwned,
     {
+        let settings = serde_json::value::to_raw_value(source_settings.source_settings)
+            .map_err(Error::SerializeCustomData)?;
+
         self.client
-            .send_message(RequestType::SetSourceSettings(source_settings))
+            .send_message_direct(RequestType::SetSourceSettings {
+                source_name: source_settings.source_name,
+                source_type: source_settings.source_type,
+                source_settings: &settings,
+            })
             .await
     }
 
+    /// Change only some settings of the specified source. The given settings are merged into the
+    /// current ones, leaving any setting that isn't part of the serialized value unchanged.
+    pub async fn patch_source_settings<P, T>(
+        &self,
+        patch: SourceSettingsPatch<'_, P>,
+    ) -> Result<responses::SourceSettings<T>>
+    where
+        P: SourceKindSettings,
+        T: DeserializeOwned,
+    {
+        self.set_source_settings(SourceSettings {
+            source_name: patch.source_name,
+            source_type: Some(patch.source_settings.kind()),
+            source_settings: patch.source_settings,
+        })
+        .await
+    }
+
+    /// Bring the settings of the specified source in line with the `desired` ones, but only send
+    /// the fields that actually differ from the current settings, see [`settings::diff`] for
+    /// details. Nothing is sent if the settings already match, which avoids that OBS needlessly
+    /// re-initializes the source.
+    ///
+    /// Returns whether any settings were changed.
+    ///
+    /// - `source_name`: Source name.
+    /// - `desired`: The settings that the source should have.
+    pub async fn sync_source_settings<T>(
+        &self,
+        source_name: impl Into<SourceName>,
+        desired: &T,
+    ) -> Result<bool>
+    where
+        T: Serialize,
+    {
+        let source_name = source_name.into();
+        let current = self
+            .get_source_settings::<serde_json::Value>(&source_name, None)
+            .await?
+            .source_settings;
+
+        match settings::diff(&current, desired)? {
+            Some(patch) => {
+                self.set_source_settings::<serde::de::IgnoredAny>(SourceSettings {
+                    source_name: &source_name,
+                    source_type: None,
+                    source_settings: &patch,
+                })
+                .await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Set the text of a text source, leaving all other settings unchanged. Works for both the
+    /// Text GDI Plus and Text Freetype 2 sources, the kind of the source is detected automatically.
+    ///
+    /// - `source_name`: Name of the text source.
+    /// - `text`: The new text.
+    pub async fn set_text(&self, source_name: impl Into<SourceName>, text: &str) -> Result<()> {
+        self.set_text_source(
+            source_name,
+            TextSource {
+                text: Some(text),
+                ..TextSource::default()
+            },
+        )
+        .await
+    }
+
+    /// Update the settings of a text source with the platform independent [`TextSource`],
+    /// leaving all unset fields unchanged. The kind of the source is detected automatically, so
+    /// the [`kind`](TextSource::kind) of the settings is ignored.
+    ///
+    /// - `source_name`: Name of the text source.
+    /// - `settings`: The settings to change.
+    pub async fn set_text_source(
+        &self,
+        source_name: impl Into<SourceName>,
+        settings: TextSource<'_>,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        let source_type = self
+            .get_source_settings::<serde::de::IgnoredAny>(&source_name, None)
+            .await?
+            .source_type;
+
+        let kind = match TextSourceKind::from_kind(&source_type) {
+            Some(kind) => kind,
+            None => return Err(Error::NotATextSource(source_type)),
+        };
+
+        self.patch_source_settings::<_, serde::de::IgnoredAny>(SourceSettingsPatch {
+            source_name: &source_name,
+            source_settings: &CustomSource {
+                kind: &source_type,
+                settings: TextSource { kind, ..settings },
+            },
+        })
+        .await
+        .map(|_| ())
+    }
+
     /// Get the current properties of a Text GDI Plus source.
     ///
     /// - `source`: Source name.
     pub async fn get_text_gdi_plus_properties(
         &self,
-        source: &str,
+        source: impl Into<SourceName>,
     ) -> Result<responses::TextGdiPlusProperties> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::GetTextGdiPlusProperties { source })
+            .send_message(RequestType::GetTextGdiPlusProperties { source: &source })
             .await
     }
 
@@ -208,10 +599,11 @@ impl<'a> Sources<'a> {
     /// - `source`: Source name.
     pub async fn get_text_freetype2_properties(
         &self,
-        source: &str,
+        source: impl Into<SourceName>,
     ) -> Result<responses::TextFreetype2Properties> {
+        let source = source.into();
         self.client
-            .send_message(RequestType::GetTextFreetype2Properties { source })
+            .send_message(RequestType::GetTextFreetype2Properties { source: &source })
             .await
     }
 
@@ -232,15 +624,60 @@ impl<'a> Sources<'a> {
             .await
     }
 
+    /// Get the configured special sources like [`get_special_sources`](Self::get_special_sources)
+    /// does, but with the full source information instead of only their names.
+    pub async fn resolve_special_sources(&self) -> Result<responses::SpecialSourcesInfo> {
+        let special = self.get_special_sources().await?;
+        let sources = self.get_sources_list().await?;
+
+        let resolve = |name: Option<String>| {
+            let name = name?;
+            sources.iter().find(|source| source.name == name).cloned()
+        };
+
+        Ok(responses::SpecialSourcesInfo {
+            desktop_1: resolve(special.desktop_1),
+            desktop_2: resolve(special.desktop_2),
+            mic_1: resolve(special.mic_1),
+            mic_2: resolve(special.mic_2),
+            mic_3: resolve(special.mic_3),
+        })
+    }
+
     /// List filters applied to a source.
     ///
     /// - `source_name`: Source name.
     pub async fn get_source_filters(
         &self,
-        source_name: &str,
+        source_name: impl Into<SourceName>,
     ) -> Result<Vec<responses::SourceFilter>> {
+        let source_name = source_name.into();
         self.client
-            .send_message::<responses::SourceFilters>(RequestType::GetSourceFilters { source_name })
+            .send_message::<responses::SourceFilters>(RequestType::GetSourceFilters {
+                source_name: &source_name,
+            })
+            .await
+            .map(|sf| sf.filters)
+    }
+
+    /// List filters applied to a source, with their settings deserialized into `T`.
+    ///
+    /// As all filters of the source are deserialized into the same type, `T` is usually an enum
+    /// or a struct with only optional fields, when the chain contains different kinds of filters.
+    ///
+    /// - `source_name`: Source name.
+    pub async fn get_source_filters_as<T>(
+        &self,
+        source_name: impl Into<SourceName>,
+    ) -> Result<Vec<responses::SourceFilterInfo<T>>>
+    where
+        T: DeserializeOwned,
+    {
+        let source_name = source_name.into();
+        self.client
+            .send_message::<responses::TypedSourceFilters<T>>(RequestType::GetSourceFilters {
+                source_name: &source_name,
+            })
             .await
             .map(|sf| sf.filters)
     }
@@ -251,15 +688,16 @@ impl<'a> Sources<'a> {
     /// - `filter_name`: Source filter name.
     pub async fn get_source_filter_info<T>(
         &self,
-        source_name: &str,
+        source_name: impl Into<SourceName>,
         filter_name: &str,
     ) -> Result<responses::SourceFilterInfo<T>>
     where
         T: DeserializeOwned,
     {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::GetSourceFilterInfo {
-                source_name,
+                source_name: &source_name,
                 filter_name,
             })
             .await
@@ -273,18 +711,44 @@ impl<'a> Sources<'a> {
             .await
     }
 
+    /// Add a new filter to a source, with typed settings that also define the filter kind.
+    ///
+    /// - `source_name`: Name of the source on which the filter is added.
+    /// - `filter_name`: Name of the new filter.
+    /// - `settings`: Settings of the new filter, like [`VstFilter`](crate::requests::VstFilter).
+    pub async fn add_filter<S>(
+        &self,
+        source_name: impl Into<SourceName>,
+        filter_name: &str,
+        settings: &S,
+    ) -> Result<()>
+    where
+        S: SourceKindSettings,
+    {
+        let source_name = source_name.into();
+        let filter_settings = serde_json::to_value(settings).map_err(Error::SerializeCustomData)?;
+        self.add_filter_to_source(AddFilter {
+            source_name: &source_name,
+            filter_name,
+            filter_type: settings.kind(),
+            filter_settings: &filter_settings,
+        })
+        .await
+    }
+
     /// Remove a filter from a source.
     ///
     /// - `source_name`: Name of the source from which the specified filter is removed.
     /// - `filter_name`: Name of the filter to remove.
     pub async fn remove_filter_from_source(
         &self,
-        source_name: &str,
+        source_name: impl Into<SourceName>,
         filter_name: &str,
     ) -> Result<()> {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::RemoveFilterFromSource {
-                source_name,
+                source_name: &source_name,
                 filter_name,
             })
             .await
@@ -304,6 +768,26 @@ impl<'a> Sources<'a> {
             .await
     }
 
+    /// Move a filter up or down in the chain of its source, or to the very top or bottom.
+    ///
+    /// - `source_name`: Name of the source to which the filter belongs.
+    /// - `filter_name`: Name of the filter to move.
+    /// - `movement_type`: Where to move the filter.
+    pub async fn move_filter(
+        &self,
+        source_name: impl Into<SourceName>,
+        filter_name: &str,
+        movement_type: MovementType,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        self.move_source_filter(MoveFilter {
+            source_name: &source_name,
+            filter_name,
+            movement_type,
+        })
+        .await
+    }
+
     /// Update settings of a filter.
     pub async fn set_source_filter_settings(
         &self,
@@ -324,13 +808,77 @@ impl<'a> Sources<'a> {
             .await
     }
 
+    /// Enable or disable a filter.
+    ///
+    /// - `source_name`: Name of the source to which the filter belongs.
+    /// - `filter_name`: Name of the filter.
+    /// - `enabled`: New filter state.
+    pub async fn set_filter_enabled(
+        &self,
+        source_name: impl Into<SourceName>,
+        filter_name: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        self.set_source_filter_visibility(SourceFilterVisibility {
+            source_name: &source_name,
+            filter_name,
+            filter_enabled: enabled,
+        })
+        .await
+    }
+
+    /// Get the audio tracks that the specified source is routed to.
+    ///
+    /// - `source_name`: Source name.
+    pub async fn get_audio_tracks(&self, source_name: impl Into<SourceName>) -> Result<TrackMask> {
+        let source_name = source_name.into();
+        self.client
+            .send_message::<responses::AudioTracks>(RequestType::GetAudioTracks {
+                source_name: &source_name,
+            })
+            .await
+            .map(Into::into)
+    }
+
+    /// Route the specified source to exactly the given audio tracks, disabling all others.
+    ///
+    /// obs-websocket only allows to change one track at a time, so this sends a request for each
+    /// of the 6 tracks.
+    ///
+    /// - `source_name`: Source name.
+    /// - `tracks`: The audio tracks to enable.
+    pub async fn set_audio_tracks(
+        &self,
+        source_name: impl Into<SourceName>,
+        tracks: TrackMask,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        for track in 1..=6 {
+            let active = tracks.bits() & (1 << (track - 1)) != 0;
+            self.client
+                .send_message::<()>(RequestType::SetAudioTracks {
+                    source_name: &source_name,
+                    track,
+                    active,
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Get the audio monitoring type of the specified source.
     ///
     /// - `source_name`: Source name.
-    pub async fn get_audio_monitor_type(&self, source_name: &str) -> Result<MonitorType> {
+    pub async fn get_audio_monitor_type(
+        &self,
+        source_name: impl Into<SourceName>,
+    ) -> Result<MonitorType> {
+        let source_name = source_name.into();
         self.client
             .send_message::<responses::AudioMonitorType>(RequestType::GetAudioMonitorType {
-                source_name,
+                source_name: &source_name,
             })
             .await
             .map(|amt| amt.monitor_type)
@@ -339,16 +887,16 @@ impl<'a> Sources<'a> {
     /// Set the audio monitoring type of the specified source.
     ///
     /// - `source_name`: Source name.
-    /// - `monitor_type`: The monitor type to use. Options: `none`, `monitorOnly`,
-    ///   `monitorAndOutput`.
+    /// - `monitor_type`: The monitor type to use.
     pub async fn set_audio_monitor_type(
         &self,
-        source_name: &str,
+        source_name: impl Into<SourceName>,
         monitor_type: MonitorType,
     ) -> Result<()> {
+        let source_name = source_name.into();
         self.client
             .send_message(RequestType::SetAudioMonitorType {
-                source_name,
+                source_name: &source_name,
                 monitor_type,
             })
             .await
@@ -357,15 +905,103 @@ impl<'a> Sources<'a> {
     /// Get the default settings for a given source type.
     ///
     /// - `source_kind`: Source kind. Also called "source id" in libobs terminology.
-    pub async fn get_source_default_settings(
+    pub async fn get_source_default_settings<T>(
         &self,
         source_kind: &str,
-    ) -> Result<responses::SourceDefaultSettings> {
+    ) -> Result<responses::SourceDefaultSettings<T>>
+    where
+        T: DeserializeOwned,
+    {
         self.client
             .send_message(RequestType::GetSourceDefaultSettings { source_kind })
             .await
     }
 
+    /// Combine the default settings of a source kind with the given `settings`, to be used for
+    /// [`create_source`](Self::create_source). Only the fields that are set in `settings` are
+    /// changed, see [`settings::merge`] for details.
+    ///
+    /// - `source_kind`: Source kind. Also called "source id" in libobs terminology.
+    /// - `settings`: Settings that differ from the defaults.
+    pub async fn merge_default_settings<T>(
+        &self,
+        source_kind: &str,
+        settings: &T,
+    ) -> Result<serde_json::Value>
+    where
+        T: Serialize,
+    {
+        let defaults = self
+            .get_source_default_settings::<serde_json::Value>(source_kind)
+            .await?
+            .default_settings;
+
+        settings::merge(defaults, settings)
+    }
+
+    /// Collect candidate values for the device, display or window setting of a capture source
+    /// kind, to build pickers for new sources. See [`SourceKind::device_setting`] for the covered
+    /// kinds.
+    ///
+    /// obs-websocket can't list all devices that OBS offers for a source, so the candidates are
+    /// the default value of the source kind and the values of all existing sources of that kind.
+    /// Devices that no source captures yet are only found if they are the default.
+    ///
+    /// - `source_kind`: Kind of the capture source.
+    pub async fn get_device_candidates(
+        &self,
+        source_kind: &SourceKind,
+    ) -> Result<Vec<responses::DeviceCandidate>> {
+        let setting = source_kind
+            .device_setting()
+            .ok_or_else(|| Error::NoDeviceSetting(source_kind.to_string()))?;
+        let device_value = |settings: &serde_json::Value| {
+            serde_json::from_value::<DeviceValue>(settings.get(setting)?.clone()).ok()
+        };
+
+        let mut candidates = Vec::<responses::DeviceCandidate>::new();
+
+        let defaults = self
+            .get_source_default_settings::<serde_json::Value>(source_kind.as_str())
+            .await?
+            .default_settings;
+        let default_value = device_value(&defaults);
+        if let Some(value) = &default_value {
+            candidates.push(responses::DeviceCandidate {
+                value: value.clone(),
+                is_default: true,
+                sources: Vec::new(),
+            });
+        }
+
+        for source in self.get_sources_list().await? {
+            if source.type_id != *source_kind {
+                continue;
+            }
+
+            let settings = self
+                .get_source_settings::<serde_json::Value>(&source.name, None)
+                .await?
+                .source_settings;
+            // OBS only reports settings that differ from the defaults.
+            let value = match device_value(&settings).or_else(|| default_value.clone()) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match candidates.iter_mut().find(|c| c.value == value) {
+                Some(candidate) => candidate.sources.push(source.name),
+                None => candidates.push(responses::DeviceCandidate {
+                    value,
+                    is_default: false,
+                    sources: vec![source.name],
+                }),
+            }
+        }
+
+        Ok(candidates)
+    }
+
     /// At least [`embed_picture_format`](SourceScreenshot::embed_picture_format) or
     /// [`save_to_file_path`](SourceScreenshot::save_to_file_path) must be specified.
     ///
@@ -384,9 +1020,53 @@ impl<'a> Sources<'a> {
     /// Refreshes the specified browser source.
     ///
     /// - `source_name`: Source name.
-    pub async fn refresh_browser_source(&self, source_name: &str) -> Result<()> {
+    pub async fn refresh_browser_source(&self, source_name: impl Into<SourceName>) -> Result<()> {
+        let source_name = source_name.into();
         self.client
-            .send_message(RequestType::RefreshBrowserSource { source_name })
+            .send_message(RequestType::RefreshBrowserSource {
+                source_name: &source_name,
+            })
             .await
     }
 }
+
+/// Interpolate between `start` and `target` for the given `progress`, in the unit of `target`.
+fn fade_step(start: Volume, target: Volume, progress: f64) -> Volume {
+    match target {
+        Volume::Mul(to) => {
+            let from = start.as_mul();
+            Volume::Mul(from + (to - from) * progress)
+        }
+        Volume::Db(to) => {
+            let from = start.as_db().max(Volume::MIN_DB);
+            let to = to.max(Volume::MIN_DB);
+            Volume::Db(from + (to - from) * progress)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::Easing;
+
+    #[test]
+    fn fade_step_units() {
+        assert_eq!(
+            fade_step(Volume::mul(1.0), Volume::mul(0.0), 0.25),
+            Volume::Mul(0.75)
+        );
+        assert_eq!(
+            fade_step(Volume::mul(0.0), Volume::db(0.0), 0.5),
+            Volume::Db(-50.0)
+        );
+        assert_eq!(
+            fade_step(
+                Volume::db(-20.0),
+                Volume::db(0.0),
+                Easing::EaseIn.apply(0.5)
+            ),
+            Volume::Db(-15.0)
+        );
+    }
+}
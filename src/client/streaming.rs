@@ -1,5 +1,10 @@
+use chrono::Duration;
+use tokio::time::{self, Instant};
+
 use super::Client;
-use crate::requests::{RequestType, SetStreamSettings, Stream};
+use crate::requests::{
+    RequestType, SetStreamSettings, Stream, StreamSettings, StreamSettingsPatch,
+};
 use crate::responses;
 use crate::Result;
 
@@ -47,6 +52,29 @@ impl<'a> Streaming<'a> {
             .await
     }
 
+    /// Change only some attributes of the current streaming server settings. If no stream type is
+    /// given, the currently configured type is kept, so that the settings not passed remain
+    /// unchanged.
+    pub async fn patch_stream_settings(&self, patch: StreamSettingsPatch<'_>) -> Result<()> {
+        let ty = match patch.ty {
+            Some(ty) => ty,
+            None => self.get_stream_settings().await?.ty,
+        };
+
+        self.set_stream_settings(SetStreamSettings {
+            ty,
+            settings: StreamSettings {
+                server: patch.server,
+                key: patch.key,
+                use_auth: patch.use_auth,
+                username: patch.username,
+                password: patch.password,
+            },
+            save: patch.save,
+        })
+        .await
+    }
+
     /// Get the current streaming server settings.
     pub async fn get_stream_settings(&self) -> Result<responses::GetStreamSettings> {
         self.client
@@ -69,4 +97,115 @@ impl<'a> Streaming<'a> {
             .send_message(RequestType::SendCaptions { text })
             .await
     }
+
+    /// Create a new [`CaptionFeeder`] that sends captions line by line, with at least `interval`
+    /// between two submissions.
+    ///
+    /// - `interval`: Minimum time between two captions, giving viewers time to read them.
+    pub fn caption_feeder(&self, interval: Duration) -> CaptionFeeder<'a> {
+        CaptionFeeder {
+            client: self.client,
+            interval: interval.to_std().unwrap_or_default(),
+            last_sent: None,
+        }
+    }
+}
+
+/// Helper to push live captions to the stream, created by [`Streaming::caption_feeder`].
+///
+/// CEA-608 captions can only display a limited amount of characters per row. The feeder splits
+/// longer text into chunks of at most [`MAX_LINE_LENGTH`](Self::MAX_LINE_LENGTH) characters, at
+/// word boundaries where possible, and paces the submissions so each chunk stays visible for a
+/// while.
+pub struct CaptionFeeder<'a> {
+    client: &'a Client,
+    /// Minimum time between two submissions.
+    interval: std::time::Duration,
+    /// Point in time when the last caption was sent.
+    last_sent: Option<Instant>,
+}
+
+impl<'a> CaptionFeeder<'a> {
+    /// Maximum amount of characters in a single CEA-608 caption row.
+    pub const MAX_LINE_LENGTH: usize = 32;
+
+    /// Send a line of text as captions, splitting it up as needed. Waits for the pacing interval
+    /// before each chunk is sent.
+    ///
+    /// - `line`: Text to display as captions.
+    pub async fn push(&mut self, line: &str) -> Result<()> {
+        for chunk in split_captions(line, Self::MAX_LINE_LENGTH) {
+            if let Some(last_sent) = self.last_sent {
+                time::sleep_until(last_sent + self.interval).await;
+            }
+
+            self.client.streaming().send_captions(&chunk).await?;
+            self.last_sent = Some(Instant::now());
+        }
+
+        Ok(())
+    }
+}
+
+/// Split the text into chunks of at most `max_len` characters. Words are kept together if they fit
+/// into a single chunk and only split up otherwise.
+fn split_captions(text: &str, max_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+
+        if current_len > 0 && current_len + 1 + word_len <= max_len {
+            current.push(' ');
+            current.push_str(word);
+            current_len += 1 + word_len;
+            continue;
+        }
+
+        if current_len > 0 {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        let mut chars = word.chars().peekable();
+        while chars.peek().is_some() {
+            current.extend(chars.by_ref().take(max_len));
+            current_len = current.chars().count();
+            if current_len == max_len {
+                chunks.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+        }
+    }
+
+    if current_len > 0 {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_captions_on_words() {
+        assert_eq!(
+            vec!["the quick brown", "fox jumps over", "the lazy dog"],
+            split_captions("the quick brown fox jumps over the lazy dog", 15)
+        );
+        assert!(split_captions("   ", 15).is_empty());
+    }
+
+    #[test]
+    fn split_captions_long_words() {
+        assert_eq!(
+            vec!["a", "abcde", "fgh b"],
+            split_captions("a abcdefgh b", 5)
+        );
+        assert_eq!(vec!["äöü", "äö"], split_captions("äöüäö", 3));
+    }
 }
@@ -1,4 +1,5 @@
 use super::Client;
+use crate::common::SceneName;
 use crate::requests::{RequestType, Transition};
 use crate::responses;
 use crate::Result;
@@ -26,9 +27,12 @@ impl<'a> StudioMode<'a> {
     /// Set the active preview scene. Will return an `error` if Studio Mode is not enabled.
     ///
     /// - `scene_name`: The name of the scene to preview.
-    pub async fn set_preview_scene(&self, scene_name: &str) -> Result<()> {
+    pub async fn set_preview_scene(&self, scene_name: impl Into<SceneName>) -> Result<()> {
+        let scene_name = scene_name.into();
         self.client
-            .send_message(RequestType::SetPreviewScene { scene_name })
+            .send_message(RequestType::SetPreviewScene {
+                scene_name: &scene_name,
+            })
             .await
     }
 
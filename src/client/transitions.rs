@@ -13,6 +13,9 @@ pub struct Transitions<'a> {
 
 impl<'a> Transitions<'a> {
     /// List of all transitions available in the frontend's dropdown menu.
+    ///
+    /// **Note**: obs-websocket 4.9 only reports the names of the transitions, but not their kind
+    /// (like fade or stinger).
     pub async fn get_transition_list(&self) -> Result<responses::TransitionList> {
         self.client
             .send_message(RequestType::GetTransitionList)
@@ -35,9 +38,22 @@ impl<'a> Transitions<'a> {
             .await
     }
 
+    /// Set the active transition and its duration in one go.
+    ///
+    /// - `transition_name`: The name of the transition.
+    /// - `duration`: Desired duration of the transition, if supported by it.
+    pub async fn set_current_transition_with_duration(
+        &self,
+        transition_name: &str,
+        duration: Duration,
+    ) -> Result<()> {
+        self.set_current_transition(transition_name).await?;
+        self.set_transition_duration(duration).await
+    }
+
     /// Set the duration of the currently selected transition if supported.
     ///
-    /// - `duration`: Desired duration of the transition (in milliseconds).
+    /// - `duration`: Desired duration of the transition, transferred with millisecond precision.
     pub async fn set_transition_duration(&self, duration: Duration) -> Result<()> {
         self.client
             .send_message(RequestType::SetTransitionDuration { duration })
@@ -109,14 +125,89 @@ impl<'a> Transitions<'a> {
     /// and call [`release_t_bar`](Self::release_t_bar) later once the animation/interaction is
     /// over.
     ///
+    /// For scripted transitions, [`t_bar`](Self::t_bar) provides a handle that takes care of
+    /// releasing the T-Bar automatically.
+    ///
     /// - `position`: T-Bar position. This value must be between 0.0 and 1.0.
     /// - `release`: Whether or not the T-Bar gets released automatically after setting its new
     ///   position (like a user releasing their mouse button after moving the T-Bar). Call
     ///   [`release_t_bar`](Self::release_t_bar) manually if you set `release` to false. Defaults to
     ///   true.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::InvalidTBarPosition`] if the `position` is outside of `0.0..=1.0`.
     pub async fn set_t_bar_position(&self, position: f64, release: Option<bool>) -> Result<()> {
+        if !(0.0..=1.0).contains(&position) {
+            return Err(Error::InvalidTBarPosition(position));
+        }
+
         self.client
             .send_message(RequestType::SetTBarPosition { position, release })
             .await
     }
+
+    /// Take control over the T-Bar for a series of manual moves, for example to script a manual
+    /// transition. The T-Bar is released once [`TBarHandle::release`] is called or the handle is
+    /// dropped.
+    pub fn t_bar(&self) -> TBarHandle<'a> {
+        TBarHandle {
+            client: self.client,
+            held: false,
+        }
+    }
+}
+
+/// Handle to the T-Bar for a series of manual moves, created by [`Transitions::t_bar`].
+///
+/// All moves keep the T-Bar in a held state (like a user holding down the mouse button). The T-Bar
+/// is released with [`release`](Self::release) or automatically when the handle is dropped, so a
+/// manual transition can't be left in a half-finished state by accident.
+pub struct TBarHandle<'a> {
+    client: &'a Client,
+    /// Whether the T-Bar was moved since the last release.
+    held: bool,
+}
+
+impl<'a> TBarHandle<'a> {
+    /// Move the T-Bar to the given position, without releasing it.
+    ///
+    /// - `position`: T-Bar position. This value must be between 0.0 and 1.0.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::InvalidTBarPosition`] if the `position` is outside of `0.0..=1.0`.
+    pub async fn set_position(&mut self, position: f64) -> Result<()> {
+        // Mark as held first, so the T-Bar is still released if this call is cancelled after the
+        // request was sent.
+        self.held = true;
+        self.transitions()
+            .set_t_bar_position(position, Some(false))
+            .await
+    }
+
+    /// Release the T-Bar and wait for obs-websocket to confirm it. If the release fails, it's
+    /// tried again once the handle is dropped.
+    pub async fn release(mut self) -> Result<()> {
+        self.transitions().release_t_bar().await?;
+        self.held = false;
+        Ok(())
+    }
+
+    fn transitions(&self) -> Transitions<'a> {
+        Transitions {
+            client: self.client,
+        }
+    }
+}
+
+impl<'a> Drop for TBarHandle<'a> {
+    fn drop(&mut self) {
+        if self.held {
+            // We can't wait for the response here, so the release is sent in the background.
+            self.client
+                .send_message_detached(RequestType::ReleaseTBar)
+                .ok();
+        }
+    }
 }
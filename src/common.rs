@@ -1,13 +1,163 @@
 //! Common data structures shared between [`requests`](crate::requests),
 //! [`responses`](crate::responses) and [`events`](crate::events).
 
+use std::borrow::Borrow;
 use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 use bitflags::bitflags;
-use serde::{Deserialize, Serialize};
+use chrono::Duration;
+use rgb::RGBA8;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{skip_serializing_none, DeserializeAs, SerializeAs};
 
+use crate::requests::SourceKindSettings;
 use crate::Error;
 
+macro_rules! name_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// Get the name as string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self(value.to_owned())
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(value: &String) -> Self {
+                Self(value.clone())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for str {
+            fn eq(&self, other: &$name) -> bool {
+                self == other.0
+            }
+        }
+
+        impl PartialEq<$name> for &str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+    };
+}
+
+name_type! {
+    /// Name of a scene or group. A distinct type from [`SourceName`], so the compiler catches
+    /// mix-ups between both. Functions that take a scene name accept anything that converts into
+    /// it, like a `&str`, a `String` or a reference to a [`SceneName`] from a response.
+    SceneName
+}
+
+name_type! {
+    /// Name of a source. A distinct type from [`SceneName`], so the compiler catches mix-ups
+    /// between both. Functions that take a source name accept anything that converts into it, like
+    /// a `&str`, a `String` or a reference to a [`SourceName`] from a response.
+    SourceName
+}
+
+/// ID of a scene item, unique within its scene.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SceneItemId(pub i64);
+
+impl fmt::Display for SceneItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<i64> for SceneItemId {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SceneItemId> for i64 {
+    fn from(value: SceneItemId) -> Self {
+        value.0
+    }
+}
+
 /// Response value for [`get_current_scene`](crate::client::Scenes::get_current_scene) as part of
 /// [`CurrentScene`](crate::responses::CurrentScene),
 /// [`get_scene_list`](crate::client::Scenes::get_scene_list) as part of
@@ -17,36 +167,42 @@ use crate::Error;
 /// [`EventType::SwitchScenes`](crate::events::EventType::SwitchScenes),
 /// [`EventType::PreviewSceneChanged`](crate::events::EventType::PreviewSceneChanged),
 ///  and **itself**.
-#[allow(missing_docs)] // Docs missing in the obs-websocket spec.
 #[derive(Clone, Debug, Deserialize)]
 pub struct SceneItem {
+    /// Scene item height (base source height multiplied by the vertical scaling factor).
     pub cy: f64,
+    /// Scene item width (base source width multiplied by the horizontal scaling factor).
     pub cx: f64,
     /// The point on the source that the item is manipulated from. The sum of 1=Left or 2=Right, and
     /// 4=Top or 8=Bottom, or omit to center on that axis.
     #[serde(deserialize_with = "crate::de::bitflags_u8")]
     pub alignment: Alignment,
     /// The name of this Scene Item.
-    pub name: String,
+    pub name: SourceName,
     /// Scene item ID.
-    pub id: i64,
+    pub id: SceneItemId,
     /// Whether or not this Scene Item is set to "visible".
     pub render: bool,
     /// Whether or not this Scene Item is muted.
     pub muted: bool,
     /// Whether or not this Scene Item is locked and can't be moved around
     pub locked: bool,
+    /// Base width (without scaling) of the source.
     pub source_cx: f64,
+    /// Base height (without scaling) of the source.
     pub source_cy: f64,
     /// Source type.
     #[serde(rename = "type")]
     pub ty: String,
+    /// Volume of the source, in the range `0.0..=1.0`.
     pub volume: f64,
+    /// The x position of the item from the left, relative to its alignment point.
     pub x: f64,
+    /// The y position of the item from the top, relative to its alignment point.
     pub y: f64,
     /// Name of the item's parent (if this item belongs to a group).
     #[serde(rename = "parentGroupName")]
-    pub parent_group_name: Option<String>,
+    pub parent_group_name: Option<SceneName>,
     /// List of children (if this item is a group).
     #[serde(rename = "groupChildren", default)]
     pub group_children: Vec<SceneItem>,
@@ -70,6 +226,9 @@ pub struct SceneItemTransform {
     pub crop: Crop,
     /// If the scene item is visible.
     pub visible: bool,
+    /// If the scene item is muted.
+    #[serde(default)]
+    pub muted: bool,
     /// If the scene item is locked in position.
     pub locked: bool,
     /// Bounding box of the source item.
@@ -83,16 +242,24 @@ pub struct SceneItemTransform {
     /// Scene item height (base source height multiplied by the vertical scaling factor).
     pub height: f64,
     /// Name of the item's parent (if this item belongs to a group).
-    pub parent_group_name: Option<String>,
-    /// List of children (if this item is a group).
+    pub parent_group_name: Option<SceneName>,
+    /// List of children (if this item is a group), with their own children in turn if they are
+    /// groups as well.
     #[serde(default)]
     pub group_children: Vec<SceneItemTransform>,
 }
 
+impl SceneItemTransform {
+    /// Whether this item is a group, that is, whether it has any children.
+    pub fn is_group(&self) -> bool {
+        !self.group_children.is_empty()
+    }
+}
+
 /// Response value for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties) as part of
 /// [`SceneItemProperties`](crate::responses::SceneItemProperties).
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Position {
     /// The x position of the source from the left.
     pub x: f64,
@@ -107,7 +274,7 @@ pub struct Position {
 /// Response value for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties) as part of
 /// [`SceneItemProperties`](crate::responses::SceneItemProperties) and [`SceneItemTransform`].
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Scale {
     /// The x-scale factor of the source.
     pub x: f64,
@@ -118,7 +285,7 @@ pub struct Scale {
 /// Response value for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties) as part of
 /// [`SceneItemProperties`](crate::responses::SceneItemProperties) and [`SceneItemTransform`].
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Crop {
     /// The number of pixels cropped off the top of the source before scaling.
     pub top: u32,
@@ -133,7 +300,7 @@ pub struct Crop {
 /// Response value for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties) as part of
 /// [`SceneItemProperties`](crate::responses::SceneItemProperties) and [`SceneItemTransform`].
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Bounds {
     /// Type of bounding box. Can be "OBS_BOUNDS_STRETCH", "OBS_BOUNDS_SCALE_INNER",
     /// "OBS_BOUNDS_SCALE_OUTER", "OBS_BOUNDS_SCALE_TO_WIDTH", "OBS_BOUNDS_SCALE_TO_HEIGHT",
@@ -149,15 +316,191 @@ pub struct Bounds {
     pub y: f64,
 }
 
+/// All properties of a scene item, captured with
+/// [`capture_scene_items`](crate::client::SceneItems::capture_scene_items) and applied again with
+/// [`restore_scene_items`](crate::client::SceneItems::restore_scene_items).
+///
+/// It can be serialized to keep it around, for example to restore a layout after a restart.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneItemSnapshot {
+    /// Name of the scene or group the item belongs to.
+    pub scene_name: SceneName,
+    /// Scene item ID.
+    pub item_id: SceneItemId,
+    /// Scene item name.
+    pub name: SourceName,
+    /// Position of the item.
+    pub position: Position,
+    /// The clockwise rotation of the item in degrees around the point of alignment.
+    pub rotation: f64,
+    /// Scaling factor of the item.
+    pub scale: Scale,
+    /// Pixel cropping of the item before scaling.
+    pub crop: Crop,
+    /// If the item is visible.
+    pub visible: bool,
+    /// If the item's transform is locked.
+    pub locked: bool,
+    /// Bounding box of the item.
+    pub bounds: Bounds,
+}
+
+/// Realm used for presence announcements that are distributed as custom broadcast messages.
+///
+/// See [`announce_presence`](crate::client::General::announce_presence) and
+/// [`withdraw_presence`](crate::client::General::withdraw_presence) for sending and
+/// [`EventType::presence`](crate::events::EventType::presence) for receiving them.
+pub const PRESENCE_REALM: &str = "obws-presence";
+
+/// Presence announcement that is sent by
+/// [`announce_presence`](crate::client::General::announce_presence) and
+/// [`withdraw_presence`](crate::client::General::withdraw_presence) and received as part of
+/// [`EventType::BroadcastCustomMessage`](crate::events::EventType::BroadcastCustomMessage).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Presence {
+    /// Application-provided label of the session. It should stay the same across restarts and
+    /// reconnects, so cooperating clients can recognize each other.
+    pub session: String,
+    /// Whether the session came online or is going away.
+    pub state: PresenceState,
+}
+
+/// State of a session as part of a [`Presence`] announcement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresenceState {
+    /// The session is connected and ready to cooperate.
+    Online,
+    /// The session is about to disconnect.
+    Offline,
+}
+
+/// Settings of a group source, as used by
+/// [`get_group_settings`](crate::client::Scenes::get_group_settings) and
+/// [`set_group_settings`](crate::client::Scenes::set_group_settings).
+///
+/// Groups (as well as scenes) use these settings to define their size. Any other settings of the
+/// group, like the contained items, are not covered by this type and left untouched when updating
+/// the settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupSettings {
+    /// Whether the group uses a custom size instead of the base canvas size.
+    #[serde(default)]
+    pub custom_size: bool,
+    /// Custom width of the group. Only used if [`custom_size`](Self::custom_size) is `true`.
+    #[serde(default)]
+    pub cx: u32,
+    /// Custom height of the group. Only used if [`custom_size`](Self::custom_size) is `true`.
+    #[serde(default)]
+    pub cy: u32,
+}
+
+/// Settings of a FreeType 2 text source (`text_ft2_source_v2`), as used by
+/// [`get_source_settings`](crate::client::Sources::get_source_settings),
+/// [`set_source_settings`](crate::client::Sources::set_source_settings) and
+/// [`add_source`](crate::client::Scenes::add_source).
+///
+/// Fields that are `None` are not sent and keep their current value, or are not set in OBS when
+/// reading the settings. OBS only reports settings that differ from its defaults, so the
+/// [`Default`] value with all fields unset equals the defaults of a new source, and
+/// [`with_obs_defaults`](Self::with_obs_defaults) fills in the actual values.
+///
+/// Unlike the GDI+ text source, the FreeType 2 source has no separate gradient direction or
+/// opacity settings. The gradient always runs from top to bottom, and the opacity of the text is
+/// the alpha channel of [`color1`](Self::color1) and [`color2`](Self::color2).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextFt2SourceV2 {
+    /// Text content to be displayed.
+    pub text: Option<String>,
+    /// Font settings.
+    pub font: Option<TextFont>,
+    /// Gradient top color, including the opacity as alpha channel. Set both colors to the same
+    /// value for a solid color.
+    pub color1: Option<Color>,
+    /// Gradient bottom color, including the opacity as alpha channel.
+    pub color2: Option<Color>,
+    /// Outline.
+    pub outline: Option<bool>,
+    /// Drop shadow.
+    pub drop_shadow: Option<bool>,
+    /// Custom width (0 to disable).
+    pub custom_width: Option<u32>,
+    /// Word wrap.
+    pub word_wrap: Option<bool>,
+    /// Read text from the specified file.
+    pub from_file: Option<bool>,
+    /// File path.
+    pub text_file: Option<PathBuf>,
+    /// Chat log.
+    pub log_mode: Option<bool>,
+    /// Number of lines to show in chat log mode.
+    pub log_lines: Option<u32>,
+    /// Smooth the edges of the text.
+    pub antialiasing: Option<bool>,
+}
+
+impl TextFt2SourceV2 {
+    /// Internal kind of the FreeType 2 text source.
+    pub const KIND: &'static str = "text_ft2_source_v2";
+
+    /// Fill all unset fields with the default values of OBS, for example after reading the
+    /// settings with [`get_source_settings`](crate::client::Sources::get_source_settings).
+    ///
+    /// The font face and the text are left untouched, as the default face depends on the platform
+    /// OBS runs on and the text has no default.
+    pub fn with_obs_defaults(mut self) -> Self {
+        let font = self.font.get_or_insert_with(TextFont::default);
+        font.flags.get_or_insert(FontFlags::empty());
+        font.size.get_or_insert(256);
+        font.style.get_or_insert_with(String::new);
+
+        self.color1.get_or_insert(Color::rgb(255, 255, 255));
+        self.color2.get_or_insert(Color::rgb(255, 255, 255));
+        self.outline.get_or_insert(false);
+        self.drop_shadow.get_or_insert(false);
+        self.custom_width.get_or_insert(0);
+        self.word_wrap.get_or_insert(false);
+        self.from_file.get_or_insert(false);
+        self.log_mode.get_or_insert(false);
+        self.log_lines.get_or_insert(6);
+        self.antialiasing.get_or_insert(true);
+        self
+    }
+}
+
+impl SourceKindSettings for TextFt2SourceV2 {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Font of a [`TextFt2SourceV2`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextFont {
+    /// Font face.
+    pub face: Option<String>,
+    /// Font text styling flag.
+    pub flags: Option<FontFlags>,
+    /// Font text size.
+    pub size: Option<u32>,
+    /// Font Style (unknown function).
+    pub style: Option<String>,
+}
+
 /// Monitoring type for audio outputs.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum MonitorType {
     /// No monitoring.
     None,
     /// Only monitor but don't output any sounds.
     MonitorOnly,
-    /// Mintor the audio and output it at the same time.
+    /// Monitor the audio and output it at the same time.
     MonitorAndOutput,
 }
 
@@ -223,6 +566,24 @@ impl From<FontFlags> for u8 {
     }
 }
 
+impl Serialize for FontFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.bits)
+    }
+}
+
+impl<'de> Deserialize<'de> for FontFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::try_from(u8::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
 bitflags! {
     /// Alignment for different items on the scene that is described in two axis. The default is
     /// center for both axis.
@@ -256,9 +617,314 @@ impl From<Alignment> for u8 {
     }
 }
 
+impl Serialize for Alignment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.bits)
+    }
+}
+
+bitflags! {
+    /// The audio tracks of the advanced audio properties that a source is routed to. OBS
+    /// supports up to 6 tracks, and a source can be routed to any combination of them.
+    pub struct TrackMask: u8 {
+        /// First audio track.
+        const TRACK_1 = 1;
+        /// Second audio track.
+        const TRACK_2 = 2;
+        /// Third audio track.
+        const TRACK_3 = 4;
+        /// Fourth audio track.
+        const TRACK_4 = 8;
+        /// Fifth audio track.
+        const TRACK_5 = 16;
+        /// Sixth audio track.
+        const TRACK_6 = 32;
+    }
+}
+
+impl TrackMask {
+    /// Get the flag for a single track by its number, from `1` to `6`.
+    pub fn track(number: u8) -> Option<Self> {
+        match number {
+            1..=6 => Self::from_bits(1 << (number - 1)),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for TrackMask {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_bits(value).ok_or(Error::UnknownFlags(value))
+    }
+}
+
+impl From<TrackMask> for u8 {
+    fn from(value: TrackMask) -> Self {
+        value.bits
+    }
+}
+
+/// Kind of a source, also called "source id" in libobs terminology. Covers the sources that are
+/// built into OBS, while all other kinds like filters, transitions or sources from plugins are
+/// kept as [`Other`](Self::Other).
+///
+/// Some kinds carry a version suffix, like `text_ft2_source_v2` or `color_source_v3`. These are
+/// kept as [`Versioned`](Self::Versioned), so the exact kind is preserved when sending it back to
+/// OBS. Use [`base`](Self::base) to match the kind regardless of its version.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SourceKind {
+    /// A scene.
+    Scene,
+    /// A group of scene items.
+    Group,
+    /// Single image file.
+    ImageSource,
+    /// Area filled with a single color.
+    ColorSource,
+    /// Slideshow of multiple images.
+    Slideshow,
+    /// Web page rendered by the embedded browser.
+    BrowserSource,
+    /// Media file or stream played through FFmpeg.
+    FfmpegSource,
+    /// Playlist of media files played through VLC.
+    VlcSource,
+    /// Text rendered with GDI+ (Windows).
+    TextGdiplus,
+    /// Text rendered with FreeType 2 (Linux and macOS).
+    TextFt2Source,
+    /// Capture of a whole display (Windows).
+    MonitorCapture,
+    /// Capture of a single window (Windows and macOS).
+    WindowCapture,
+    /// Capture of a fullscreen game (Windows).
+    GameCapture,
+    /// Capture of a whole display (macOS).
+    DisplayCapture,
+    /// Video capture device through DirectShow (Windows).
+    DshowInput,
+    /// Audio input capture through WASAPI (Windows).
+    WasapiInputCapture,
+    /// Audio output capture through WASAPI (Windows).
+    WasapiOutputCapture,
+    /// Audio input capture through CoreAudio (macOS).
+    CoreaudioInputCapture,
+    /// Audio output capture through CoreAudio (macOS).
+    CoreaudioOutputCapture,
+    /// Video capture device through AVFoundation (macOS).
+    AvCaptureInput,
+    /// Audio input capture through PulseAudio (Linux).
+    PulseInputCapture,
+    /// Audio output capture through PulseAudio (Linux).
+    PulseOutputCapture,
+    /// Audio input capture through ALSA (Linux).
+    AlsaInputCapture,
+    /// Capture of a whole screen through XSHM (Linux).
+    XshmInput,
+    /// Capture of a single window through XComposite (Linux).
+    XcompositeInput,
+    /// Video capture device through Video4Linux2 (Linux).
+    V4l2Input,
+    /// A known kind with a version suffix, like `color_source_v3`.
+    Versioned {
+        /// The known kind without version suffix.
+        base: Box<SourceKind>,
+        /// The full kind as reported by OBS, including the version suffix.
+        kind: String,
+    },
+    /// Any other kind of source, with the kind as reported by OBS.
+    Other(String),
+}
+
+impl SourceKind {
+    /// The source kind as used by OBS, including any version suffix.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Scene => "scene",
+            Self::Group => "group",
+            Self::ImageSource => "image_source",
+            Self::ColorSource => "color_source",
+            Self::Slideshow => "slideshow",
+            Self::BrowserSource => "browser_source",
+            Self::FfmpegSource => "ffmpeg_source",
+            Self::VlcSource => "vlc_source",
+            Self::TextGdiplus => "text_gdiplus",
+            Self::TextFt2Source => "text_ft2_source",
+            Self::MonitorCapture => "monitor_capture",
+            Self::WindowCapture => "window_capture",
+            Self::GameCapture => "game_capture",
+            Self::DisplayCapture => "display_capture",
+            Self::DshowInput => "dshow_input",
+            Self::WasapiInputCapture => "wasapi_input_capture",
+            Self::WasapiOutputCapture => "wasapi_output_capture",
+            Self::CoreaudioInputCapture => "coreaudio_input_capture",
+            Self::CoreaudioOutputCapture => "coreaudio_output_capture",
+            Self::AvCaptureInput => "av_capture_input",
+            Self::PulseInputCapture => "pulse_input_capture",
+            Self::PulseOutputCapture => "pulse_output_capture",
+            Self::AlsaInputCapture => "alsa_input_capture",
+            Self::XshmInput => "xshm_input",
+            Self::XcompositeInput => "xcomposite_input",
+            Self::V4l2Input => "v4l2_input",
+            Self::Versioned { kind, .. } | Self::Other(kind) => kind,
+        }
+    }
+
+    /// The known kind without version suffix, which is the kind itself for all kinds that aren't
+    /// [`Versioned`](Self::Versioned).
+    pub fn base(&self) -> &Self {
+        match self {
+            Self::Versioned { base, .. } => base,
+            _ => self,
+        }
+    }
+
+    /// The version suffix of a [`Versioned`](Self::Versioned) kind, like `3` for
+    /// `color_source_v3`.
+    pub fn version(&self) -> Option<u32> {
+        match self {
+            Self::Versioned { kind, .. } => split_version(kind).and_then(|(_, v)| v.parse().ok()),
+            _ => None,
+        }
+    }
+
+    /// Name of the setting that selects the captured device, display or window, for the capture
+    /// source kinds of OBS. Returns `None` for all other kinds.
+    ///
+    /// These names are a convention of the OBS plugins and not reported by obs-websocket, so they
+    /// may change with future OBS versions.
+    pub fn device_setting(&self) -> Option<&'static str> {
+        Some(match self.base() {
+            Self::MonitorCapture => "monitor",
+            Self::WindowCapture | Self::GameCapture => "window",
+            Self::DisplayCapture => "display",
+            Self::DshowInput => "video_device_id",
+            Self::WasapiInputCapture
+            | Self::WasapiOutputCapture
+            | Self::CoreaudioInputCapture
+            | Self::CoreaudioOutputCapture
+            | Self::PulseInputCapture
+            | Self::PulseOutputCapture
+            | Self::AlsaInputCapture
+            | Self::V4l2Input => "device_id",
+            Self::AvCaptureInput => "device",
+            Self::XshmInput => "screen",
+            Self::XcompositeInput => "capture_window",
+            _ => return None,
+        })
+    }
+}
+
+/// Value of the setting that selects the captured device, display or window of a capture source,
+/// see [`SourceKind::device_setting`]. Depending on the source kind, OBS identifies them by an
+/// ID or by an index.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeviceValue {
+    /// Identifier of a device or window, like the device ID of an audio input.
+    Id(String),
+    /// Index of a display or screen.
+    Index(i64),
+}
+
+impl fmt::Display for DeviceValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => f.write_str(id),
+            Self::Index(index) => index.fmt(f),
+        }
+    }
+}
+
+/// Split a source kind into the base kind and its version suffix, if it has one.
+fn split_version(kind: &str) -> Option<(&str, &str)> {
+    kind.rsplit_once("_v")
+        .filter(|(_, version)| !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit()))
+}
+
+impl From<&str> for SourceKind {
+    fn from(kind: &str) -> Self {
+        if let Some((base, _)) = split_version(kind) {
+            return match SourceKind::from(base) {
+                Self::Other(_) => Self::Other(kind.to_owned()),
+                base => Self::Versioned {
+                    base: Box::new(base),
+                    kind: kind.to_owned(),
+                },
+            };
+        }
+
+        match kind {
+            "scene" => Self::Scene,
+            "group" => Self::Group,
+            "image_source" => Self::ImageSource,
+            "color_source" => Self::ColorSource,
+            "slideshow" => Self::Slideshow,
+            "browser_source" => Self::BrowserSource,
+            "ffmpeg_source" => Self::FfmpegSource,
+            "vlc_source" => Self::VlcSource,
+            "text_gdiplus" => Self::TextGdiplus,
+            "text_ft2_source" => Self::TextFt2Source,
+            "monitor_capture" => Self::MonitorCapture,
+            "window_capture" => Self::WindowCapture,
+            "game_capture" => Self::GameCapture,
+            "display_capture" => Self::DisplayCapture,
+            "dshow_input" => Self::DshowInput,
+            "wasapi_input_capture" => Self::WasapiInputCapture,
+            "wasapi_output_capture" => Self::WasapiOutputCapture,
+            "coreaudio_input_capture" => Self::CoreaudioInputCapture,
+            "coreaudio_output_capture" => Self::CoreaudioOutputCapture,
+            "av_capture_input" => Self::AvCaptureInput,
+            "pulse_input_capture" => Self::PulseInputCapture,
+            "pulse_output_capture" => Self::PulseOutputCapture,
+            "alsa_input_capture" => Self::AlsaInputCapture,
+            "xshm_input" => Self::XshmInput,
+            "xcomposite_input" => Self::XcompositeInput,
+            "v4l2_input" => Self::V4l2Input,
+            _ => Self::Other(kind.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceKind {
+    fn from(kind: String) -> Self {
+        kind.as_str().into()
+    }
+}
+
+impl fmt::Display for SourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SourceKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Into::into)
+    }
+}
+
 /// Different kinds of bounds that can be applied to different items on the scene as part of the
 /// [`Bounds`] type.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BoundsType {
     /// Stretch to bounds.
     #[serde(rename = "OBS_BOUNDS_STRETCH")]
@@ -282,3 +948,781 @@ pub enum BoundsType {
     #[serde(rename = "OBS_BOUNDS_NONE")]
     None,
 }
+
+/// A [`Duration`] that is transferred as whole milliseconds, the unit that obs-websocket uses for
+/// most durations, like transition durations or media timestamps.
+///
+/// It can be used as field type directly, or as adapter for plain [`Duration`] fields with
+/// [`serde_with::serde_as`]. This allows to use it in custom source settings as well:
+///
+/// ```
+/// use chrono::Duration;
+/// use obws::common::DurationMillis;
+/// use serde::Serialize;
+///
+/// #[serde_with::serde_as]
+/// #[derive(Serialize)]
+/// struct SlideshowSettings {
+///     #[serde_as(as = "DurationMillis")]
+///     slide_time: Duration,
+///     transition_speed: DurationMillis,
+/// }
+///
+/// let settings = SlideshowSettings {
+///     slide_time: Duration::seconds(8),
+///     transition_speed: Duration::milliseconds(700).into(),
+/// };
+///
+/// assert_eq!(
+///     serde_json::json!({"slide_time": 8000, "transition_speed": 700}),
+///     serde_json::to_value(&settings).unwrap(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationMillis(pub Duration);
+
+impl From<Duration> for DurationMillis {
+    fn from(value: Duration) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DurationMillis> for Duration {
+    fn from(value: DurationMillis) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for DurationMillis {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.0.num_milliseconds())
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationMillis {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(|millis| Self(Duration::milliseconds(millis)))
+    }
+}
+
+impl SerializeAs<Duration> for DurationMillis {
+    fn serialize_as<S>(source: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self(*source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Duration> for DurationMillis {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer).map(|value| value.0)
+    }
+}
+
+/// A [`Duration`] that is transferred as whole nanoseconds, which obs-websocket uses for the sync
+/// offset of sources.
+///
+/// Like [`DurationMillis`], it can be used as field type or as [`serde_with::serde_as`] adapter.
+/// Serializing fails for durations that are too big to be represented as nanoseconds (roughly 292
+/// years).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationNanos(pub Duration);
+
+impl From<Duration> for DurationNanos {
+    fn from(value: Duration) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DurationNanos> for Duration {
+    fn from(value: DurationNanos) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DurationError {
+    #[error("duration of {} days is too big to be serialized as nanoseconds", .0.num_days())]
+    TooBig(Duration),
+}
+
+impl Serialize for DurationNanos {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0.num_nanoseconds() {
+            Some(nanos) => serializer.serialize_i64(nanos),
+            None => Err(ser::Error::custom(DurationError::TooBig(self.0))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationNanos {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(|nanos| Self(Duration::nanoseconds(nanos)))
+    }
+}
+
+impl SerializeAs<Duration> for DurationNanos {
+    fn serialize_as<S>(source: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self(*source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Duration> for DurationNanos {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer).map(|value| value.0)
+    }
+}
+
+/// A [`Duration`] that is at least `MS` milliseconds long, transferred as whole milliseconds like
+/// [`DurationMillis`].
+///
+/// Some settings silently break in OBS if their duration is too short, like the slide time of a
+/// slideshow (at least 50ms) or the network caching of a VLC source (at least 100ms). Using this
+/// type in custom source settings rejects such values before they are sent to OBS.
+///
+/// ```
+/// use chrono::Duration;
+/// use obws::common::MinDuration;
+///
+/// assert!(MinDuration::<50>::new(Duration::seconds(8)).is_ok());
+/// assert!(MinDuration::<50>::new(Duration::milliseconds(10)).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MinDuration<const MS: u64>(Duration);
+
+impl<const MS: u64> MinDuration<MS> {
+    /// Create a new duration, failing with [`Error::DurationTooShort`] if it's shorter than `MS`
+    /// milliseconds.
+    pub fn new(value: Duration) -> Result<Self, Error> {
+        let min = Duration::milliseconds(MS as i64);
+        if value < min {
+            return Err(Error::DurationTooShort(value, min));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Get the wrapped duration.
+    pub fn get(self) -> Duration {
+        self.0
+    }
+}
+
+impl<const MS: u64> TryFrom<Duration> for MinDuration<MS> {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const MS: u64> From<MinDuration<MS>> for Duration {
+    fn from(value: MinDuration<MS>) -> Self {
+        value.0
+    }
+}
+
+impl<const MS: u64> Serialize for MinDuration<MS> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DurationMillis(self.0).serialize(serializer)
+    }
+}
+
+impl<'de, const MS: u64> Deserialize<'de> for MinDuration<MS> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = DurationMillis::deserialize(deserializer)?;
+        Self::new(value.0).map_err(de::Error::custom)
+    }
+}
+
+/// A percentage in the range `0.0..=100.0`, for settings like the opacity of a source.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Percent(f64);
+
+impl Percent {
+    /// Create a new percentage, failing with [`Error::InvalidPercent`] if it's outside of
+    /// `0.0..=100.0`.
+    pub fn new(value: f64) -> Result<Self, Error> {
+        if !(0.0..=100.0).contains(&value) {
+            return Err(Error::InvalidPercent(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Get the wrapped percentage.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for Percent {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Percent> for f64 {
+    fn from(value: Percent) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for Percent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Percent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::new(f64::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Number of an audio track, in the range `1..=6`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Track(u8);
+
+impl Track {
+    /// Create a new track number, failing with [`Error::InvalidTrack`] if it's outside of `1..=6`.
+    pub fn new(value: u8) -> Result<Self, Error> {
+        if !(1..=6).contains(&value) {
+            return Err(Error::InvalidTrack(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Get the wrapped track number.
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Track {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Track> for u8 {
+    fn from(value: Track) -> Self {
+        value.0
+    }
+}
+
+impl From<Track> for TrackMask {
+    fn from(value: Track) -> Self {
+        Self::from_bits_truncate(1 << (value.0 - 1))
+    }
+}
+
+impl Serialize for Track {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Track {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::new(u8::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Audio volume of a source, either as amplitude multiplier (`mul`) or in decibels (`dB`).
+///
+/// obs-websocket accepts both units, so the volume is sent in whatever unit it was created with.
+/// Conversion between the two happens with [`as_mul`](Self::as_mul) and [`as_db`](Self::as_db).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Volume {
+    /// Amplitude multiplier, where `1.0` is the original volume and `0.0` is silence.
+    Mul(f64),
+    /// Decibels, where `0.0` is the original volume. OBS treats everything under -100.0 dB as
+    /// silence.
+    Db(f64),
+}
+
+impl Volume {
+    /// Lowest decibel value that OBS doesn't consider as silence.
+    pub const MIN_DB: f64 = -100.0;
+
+    /// Create a new volume from an amplitude multiplier.
+    pub fn mul(value: f64) -> Self {
+        Self::Mul(value)
+    }
+
+    /// Create a new volume in decibels.
+    pub fn db(value: f64) -> Self {
+        Self::Db(value)
+    }
+
+    /// Get the volume as amplitude multiplier.
+    pub fn as_mul(self) -> f64 {
+        match self {
+            Self::Mul(mul) => mul,
+            Self::Db(db) if db <= Self::MIN_DB => 0.0,
+            Self::Db(db) => 10_f64.powf(db / 20.0),
+        }
+    }
+
+    /// Get the volume in decibels. Silence is represented as negative infinity.
+    pub fn as_db(self) -> f64 {
+        match self {
+            Self::Mul(mul) if mul <= 0.0 => f64::NEG_INFINITY,
+            Self::Mul(mul) => 20.0 * mul.log10(),
+            Self::Db(db) => db,
+        }
+    }
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self::Mul(1.0)
+    }
+}
+
+/// An RGBA color, as used by the color fields of text sources, color sources and color filters.
+///
+/// OBS stores colors as integer with the channels in inverse order (ABGR), which is handled when
+/// serializing and deserializing. Colors can be created from hex strings like `#RRGGBB` or
+/// `#RRGGBBAA` through [`FromStr`], and converted from and to [`RGBA8`].
+///
+/// ```
+/// use obws::common::Color;
+///
+/// let color = "#ff8000".parse::<Color>()?;
+/// assert_eq!(Color::new(255, 128, 0, 255), color);
+/// assert_eq!("#ff8000", color.to_string());
+/// # Ok::<(), obws::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel, where `0` is fully transparent and `255` fully opaque.
+    pub a: u8,
+}
+
+impl Color {
+    /// Create a new color from all four channels.
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Create a new, fully opaque color.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new(r, g, b, 255)
+    }
+
+    /// Decode the color from the integer representation of OBS (ABGR).
+    fn from_abgr(value: u32) -> Self {
+        Self::new(
+            (value & 0xff) as u8,
+            (value >> 8 & 0xff) as u8,
+            (value >> 16 & 0xff) as u8,
+            (value >> 24 & 0xff) as u8,
+        )
+    }
+
+    /// Encode the color into the integer representation of OBS (ABGR).
+    fn to_abgr(self) -> u32 {
+        (self.a as u32) << 24 | (self.b as u32) << 16 | (self.g as u32) << 8 | (self.r as u32)
+    }
+}
+
+impl From<RGBA8> for Color {
+    fn from(value: RGBA8) -> Self {
+        Self::new(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl From<Color> for RGBA8 {
+    fn from(value: Color) -> Self {
+        Self::new(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| Error::InvalidColor(s.to_owned()))
+        };
+
+        match hex.len() {
+            6 => Ok(Self::rgb(channel(0)?, channel(2)?, channel(4)?)),
+            8 => Ok(Self::new(
+                channel(0)?,
+                channel(2)?,
+                channel(4)?,
+                channel(6)?,
+            )),
+            _ => Err(Error::InvalidColor(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)?;
+        if self.a != 255 {
+            write!(f, "{:02x}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.to_abgr())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u32(ColorVisitor)
+    }
+}
+
+struct ColorVisitor;
+
+impl<'de> de::Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a RGBA color value encoded as integer in inverse order (ABGR)")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u32::try_from(v)
+            .map(Color::from_abgr)
+            .map_err(de::Error::custom)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u32::try_from(v)
+            .map(Color::from_abgr)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_ser_tokens_error,
+        assert_tokens, Token,
+    };
+    use serde_with::serde_as;
+
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert_tokens(&SceneName::from("Scene"), &[Token::Str("Scene")]);
+        assert_tokens(&SourceName::from("Text"), &[Token::Str("Text")]);
+        assert_tokens(&SceneItemId(5), &[Token::I64(5)]);
+
+        let name = SourceName::from("Text");
+        assert_eq!(name, "Text");
+        assert_eq!("Text", name.to_string());
+    }
+
+    #[test]
+    fn device_value() {
+        assert_tokens(
+            &DeviceValue::Id("default".to_owned()),
+            &[Token::Str("default")],
+        );
+        assert_tokens(&DeviceValue::Index(1), &[Token::I64(1)]);
+        assert_eq!(
+            Some("device_id"),
+            SourceKind::from("coreaudio_input_capture").device_setting()
+        );
+        assert_eq!(None, SourceKind::ImageSource.device_setting());
+    }
+
+    #[test]
+    fn alignment() {
+        assert_ser_tokens(&(Alignment::BOTTOM | Alignment::RIGHT), &[Token::U8(10)]);
+    }
+
+    #[test]
+    fn text_ft2_source_v2() {
+        let settings = serde_json::from_value::<TextFt2SourceV2>(serde_json::json!({
+            "text": "Hello",
+            "font": { "face": "Arial", "flags": 1, "size": 48, "style": "Regular" },
+            "color1": 0xff00_00ff_u32,
+            "unknown": true,
+        }))
+        .unwrap();
+
+        assert_eq!(Some("Hello"), settings.text.as_deref());
+        assert_eq!(
+            Some(FontFlags::BOLD),
+            settings.font.as_ref().and_then(|f| f.flags)
+        );
+        assert_eq!(Some(Color::rgb(255, 0, 0)), settings.color1);
+        assert_eq!(None, settings.color2);
+
+        assert_eq!(
+            serde_json::json!({
+                "text": "Hello",
+                "font": { "face": "Arial", "flags": 1, "size": 48, "style": "Regular" },
+                "color1": 0xff00_00ff_u32,
+            }),
+            serde_json::to_value(&settings).unwrap(),
+        );
+    }
+
+    #[test]
+    fn text_ft2_source_v2_defaults() {
+        let settings = TextFt2SourceV2 {
+            color1: Some(Color::new(255, 0, 0, 128)),
+            ..TextFt2SourceV2::default()
+        }
+        .with_obs_defaults();
+
+        assert_eq!(Some(Color::new(255, 0, 0, 128)), settings.color1);
+        assert_eq!(Some(Color::rgb(255, 255, 255)), settings.color2);
+        assert_eq!(Some(6), settings.log_lines);
+        assert_eq!(Some(true), settings.antialiasing);
+        assert_eq!(None, settings.text);
+        assert_eq!(None, settings.font.as_ref().and_then(|f| f.face.as_ref()));
+        assert_eq!(Some(256), settings.font.as_ref().and_then(|f| f.size));
+    }
+
+    #[test]
+    fn color() {
+        assert_tokens(&Color::new(1, 2, 3, 4), &[Token::U32(0x0403_0201)]);
+        assert_de_tokens(&Color::new(1, 2, 3, 4), &[Token::I64(0x0403_0201)]);
+        assert_de_tokens(&Color::new(1, 2, 3, 4), &[Token::U64(0x0403_0201)]);
+        assert_de_tokens_error::<Color>(
+            &[Token::I64(-1)],
+            "out of range integral type conversion attempted",
+        );
+    }
+
+    #[test]
+    fn color_hex() {
+        assert_eq!(Color::rgb(255, 128, 0), "#FF8000".parse::<Color>().unwrap());
+        assert_eq!(Color::new(1, 2, 3, 4), "01020304".parse::<Color>().unwrap());
+        assert!("#ff80".parse::<Color>().is_err());
+        assert!("#gg8000".parse::<Color>().is_err());
+        assert!("#ff800€".parse::<Color>().is_err());
+
+        assert_eq!("#ff8000", Color::rgb(255, 128, 0).to_string());
+        assert_eq!("#01020304", Color::new(1, 2, 3, 4).to_string());
+    }
+
+    #[test]
+    fn duration_millis() {
+        assert_tokens(
+            &DurationMillis(Duration::milliseconds(150)),
+            &[Token::I64(150)],
+        );
+
+        assert_de_tokens_error::<DurationMillis>(
+            &[Token::U64(u64::MAX)],
+            "invalid value: integer `18446744073709551615`, expected i64",
+        );
+    }
+
+    #[test]
+    fn duration_millis_as() {
+        #[serde_as]
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct SimpleDuration {
+            #[serde_as(as = "Option<DurationMillis>")]
+            value: Option<Duration>,
+        }
+
+        assert_tokens(
+            &SimpleDuration {
+                value: Some(Duration::milliseconds(150)),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleDuration",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::Some,
+                Token::I64(150),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &SimpleDuration { value: None },
+            &[
+                Token::Struct {
+                    name: "SimpleDuration",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn duration_nanos() {
+        assert_tokens(
+            &DurationNanos(Duration::nanoseconds(150)),
+            &[Token::I64(150)],
+        );
+
+        assert_ser_tokens_error(
+            &DurationNanos(Duration::days(365_000_000)),
+            &[],
+            "duration of 365000000 days is too big to be serialized as nanoseconds",
+        );
+    }
+
+    #[test]
+    fn min_duration() {
+        assert_tokens(
+            &MinDuration::<50>::new(Duration::milliseconds(50)).unwrap(),
+            &[Token::I64(50)],
+        );
+        assert_de_tokens_error::<MinDuration<50>>(
+            &[Token::I64(49)],
+            "duration of 49ms is shorter than the minimum of 50ms",
+        );
+    }
+
+    #[test]
+    fn percent() {
+        assert_tokens(&Percent::new(50.0).unwrap(), &[Token::F64(50.0)]);
+        assert!(Percent::new(-1.0).is_err());
+        assert!(Percent::new(100.5).is_err());
+        assert!(Percent::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn track() {
+        assert_tokens(&Track::new(6).unwrap(), &[Token::U8(6)]);
+        assert_de_tokens_error::<Track>(
+            &[Token::U8(0)],
+            "audio track 0 is outside of the valid range 1 to 6",
+        );
+        assert_eq!(TrackMask::TRACK_3, Track::new(3).unwrap().into());
+    }
+
+    #[test]
+    fn source_kind() {
+        assert_de_tokens(&SourceKind::ImageSource, &[Token::Str("image_source")]);
+        assert_tokens(
+            &SourceKind::Other("obs_vst_v2".to_owned()),
+            &[Token::Str("obs_vst_v2")],
+        );
+        assert_ser_tokens(&SourceKind::TextGdiplus, &[Token::Str("text_gdiplus")]);
+    }
+
+    #[test]
+    fn versioned_source_kind() {
+        for (kind, base, version) in &[
+            ("text_ft2_source_v2", SourceKind::TextFt2Source, 2),
+            ("color_source_v3", SourceKind::ColorSource, 3),
+        ] {
+            let parsed = SourceKind::from(*kind);
+            assert_ne!(base, &parsed);
+            assert_eq!(base, parsed.base());
+            assert_eq!(Some(*version), parsed.version());
+            assert_eq!(*kind, parsed.as_str());
+            assert_tokens(&parsed, &[Token::Str(kind)]);
+        }
+
+        assert_eq!(
+            Some("device_id"),
+            SourceKind::from("pulse_input_capture_v2").device_setting()
+        );
+        assert_eq!(None, SourceKind::ColorSource.version());
+        assert_eq!(None, SourceKind::from("obs_vst_v2").version());
+    }
+
+    #[test]
+    fn track_mask() {
+        assert_eq!(TrackMask::track(1), Some(TrackMask::TRACK_1));
+        assert_eq!(TrackMask::track(6), Some(TrackMask::TRACK_6));
+        assert_eq!(TrackMask::track(0), None);
+        assert_eq!(TrackMask::track(7), None);
+    }
+
+    #[test]
+    fn volume_conversion() {
+        assert_eq!(Volume::mul(1.0).as_db(), 0.0);
+        assert_eq!(Volume::mul(0.0).as_db(), f64::NEG_INFINITY);
+        assert!((Volume::mul(0.5).as_db() + 6.0206).abs() < 1e-4);
+
+        assert_eq!(Volume::db(0.0).as_mul(), 1.0);
+        assert_eq!(Volume::db(-100.0).as_mul(), 0.0);
+        assert!((Volume::db(-6.0).as_mul() - 0.5012).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,222 @@
+//! A high-level facade over the [`Client`], for applications like chat bots that only need a few
+//! common operations and don't want to deal with the details of the obs-websocket protocol.
+
+use crate::client::{ConnectConfig, SceneHandle, SourceHandle};
+use crate::common::{SceneName, SourceName, Volume};
+use crate::requests::SceneItemRender;
+#[cfg(feature = "events")]
+use crate::state::ObsState;
+use crate::{Client, Result};
+
+/// Opinionated controller for OBS that bundles the most common operations behind a small set of
+/// methods. It connects and logs in at once, can reconnect with the same settings, gives access to
+/// scene and source handles and, with the `events` feature, keeps a [state cache](Self::state)
+/// that is loaded again on every reconnect.
+///
+/// The underlying [`Client`] is always available through [`client`](Self::client) for anything
+/// not covered here.
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use obws::controller::ObsController;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut obs = ObsController::connect("localhost", 4444, Some("password")).await?;
+///
+///     obs.switch_scene("Just Chatting").await?;
+///     obs.set_text("Title", "Today: building a bot").await?;
+///     obs.show_overlay(None, "Follower Alert").await?;
+///     obs.source(&"Mic/Aux".into()).mute().await?;
+///
+///     if obs.start_stream_checked().await? {
+///         println!("stream started");
+///     }
+///
+///     // After the connection was lost, for example because OBS was restarted.
+///     obs.reconnect().await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct ObsController {
+    client: Client,
+    config: ConnectConfig<String>,
+    password: Option<String>,
+    #[cfg(feature = "events")]
+    state: ObsState,
+}
+
+impl ObsController {
+    /// Connect to obs-websocket on the given host and port, and log in with the password if
+    /// authentication is enabled.
+    pub async fn connect(
+        host: impl Into<String>,
+        port: u16,
+        password: Option<impl Into<String>>,
+    ) -> Result<Self> {
+        Self::connect_with_config(
+            ConnectConfig {
+                host: host.into(),
+                port,
+                #[cfg(feature = "tls")]
+                tls: false,
+                broadcast_capacity: None,
+                ping_interval: None,
+                ping_timeout: None,
+            },
+            password,
+        )
+        .await
+    }
+
+    /// Connect to obs-websocket with the given configuration, and log in with the password if
+    /// authentication is enabled. The configuration is kept as is for
+    /// [`reconnect`](Self::reconnect).
+    pub async fn connect_with_config(
+        config: ConnectConfig<String>,
+        password: Option<impl Into<String>>,
+    ) -> Result<Self> {
+        let password = password.map(Into::into);
+        let client = Self::connect_client(&config, password.as_deref()).await?;
+        #[cfg(feature = "events")]
+        let state = ObsState::new(&client).await?;
+
+        Ok(Self {
+            client,
+            config,
+            password,
+            #[cfg(feature = "events")]
+            state,
+        })
+    }
+
+    async fn connect_client(
+        config: &ConnectConfig<String>,
+        password: Option<&str>,
+    ) -> Result<Client> {
+        let client = Client::connect_with_config(ConnectConfig {
+            host: config.host.as_str(),
+            port: config.port,
+            #[cfg(feature = "tls")]
+            tls: config.tls,
+            broadcast_capacity: config.broadcast_capacity,
+            ping_interval: config.ping_interval,
+            ping_timeout: config.ping_timeout,
+        })
+        .await?;
+        client.login(password).await?;
+
+        Ok(client)
+    }
+
+    /// Access the underlying low-level client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Cached state of OBS, that is kept in sync through events and can be read without sending
+    /// any requests.
+    #[cfg(feature = "events")]
+    pub fn state(&self) -> &ObsState {
+        &self.state
+    }
+
+    /// Get a handle to the scene with the given name.
+    pub fn scene<'a>(&'a self, name: &'a SceneName) -> SceneHandle<'a> {
+        self.client.scene(name)
+    }
+
+    /// Get a handle to the source with the given name.
+    pub fn source<'a>(&'a self, name: &'a SourceName) -> SourceHandle<'a> {
+        self.client.source(name)
+    }
+
+    /// Drop the current connection and connect again with the same settings as before, for
+    /// example after OBS was restarted. The [state cache](Self::state) is loaded again from the
+    /// new connection.
+    ///
+    /// Event streams of the previous connection end and have to be requested again from the
+    /// [`client`](Self::client).
+    pub async fn reconnect(&mut self) -> Result<()> {
+        let client = Self::connect_client(&self.config, self.password.as_deref()).await?;
+        #[cfg(feature = "events")]
+        {
+            self.state = ObsState::new(&client).await?;
+        }
+
+        let mut old = std::mem::replace(&mut self.client, client);
+        old.disconnect().await;
+
+        Ok(())
+    }
+
+    /// Switch the program output to the given scene.
+    pub async fn switch_scene(&self, scene_name: impl Into<SceneName>) -> Result<()> {
+        self.client.scenes().set_current_scene(scene_name).await
+    }
+
+    /// Set the text of a text source, leaving its other settings unchanged.
+    pub async fn set_text(&self, source_name: impl Into<SourceName>, text: &str) -> Result<()> {
+        self.client.sources().set_text(source_name, text).await
+    }
+
+    /// Make an item visible in the given scene, or the current scene if `None`.
+    pub async fn show_overlay(
+        &self,
+        scene_name: Option<&SceneName>,
+        item: impl Into<SourceName>,
+    ) -> Result<()> {
+        self.set_overlay_visible(scene_name, item.into(), true)
+            .await
+    }
+
+    /// Hide an item in the given scene, or the current scene if `None`.
+    pub async fn hide_overlay(
+        &self,
+        scene_name: Option<&SceneName>,
+        item: impl Into<SourceName>,
+    ) -> Result<()> {
+        self.set_overlay_visible(scene_name, item.into(), false)
+            .await
+    }
+
+    async fn set_overlay_visible(
+        &self,
+        scene_name: Option<&SceneName>,
+        item: SourceName,
+        render: bool,
+    ) -> Result<()> {
+        self.client
+            .scene_items()
+            .set_scene_item_render(SceneItemRender {
+                scene_name: scene_name.map(Into::into),
+                source: &item,
+                item: None,
+                render,
+            })
+            .await
+    }
+
+    /// Mute or unmute an audio source.
+    pub async fn set_mute(&self, source: impl Into<SourceName>, mute: bool) -> Result<()> {
+        self.client.sources().set_mute(source, mute).await
+    }
+
+    /// Set the volume of an audio source.
+    pub async fn set_volume(&self, source: impl Into<SourceName>, volume: Volume) -> Result<()> {
+        self.client.sources().set_volume(source, volume).await
+    }
+
+    /// Start streaming, unless a stream is already running. Returns whether a new stream was
+    /// started.
+    pub async fn start_stream_checked(&self) -> Result<bool> {
+        let streaming = self.client.streaming();
+        if streaming.get_streaming_status().await?.streaming {
+            return Ok(false);
+        }
+
+        streaming.start_streaming(None).await?;
+        Ok(true)
+    }
+}
@@ -6,7 +6,9 @@ use std::fmt::{self, Display};
 use std::marker::PhantomData;
 
 use chrono::Duration;
-use serde::de::{self, Deserializer, Visitor};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+
+use crate::common::Volume;
 
 #[derive(Debug, thiserror::Error)]
 enum Error {
@@ -82,6 +84,13 @@ impl<'de> Visitor<'de> for DurationOptVisitor {
     }
 }
 
+pub fn volume_mul<'de, D>(deserializer: D) -> Result<Volume, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    f64::deserialize(deserializer).map(Volume::Mul)
+}
+
 pub fn duration_millis_opt<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
 where
     D: Deserializer<'de>,
@@ -134,72 +143,42 @@ impl<'de> Visitor<'de> for DurationMillisOptVisitor {
     }
 }
 
-pub fn duration_millis<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+#[cfg(feature = "events")]
+pub fn duration_secs_opt<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserializer.deserialize_i64(DurationMillisVisitor)
+    Option::<i64>::deserialize(deserializer).map(|secs| secs.map(Duration::seconds))
 }
 
-struct DurationMillisVisitor;
-
-impl<'de> Visitor<'de> for DurationMillisVisitor {
-    type Value = Duration;
-
-    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str("a duration in milliseconds")
-    }
-
-    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        Ok(Duration::milliseconds(v))
-    }
-
-    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match i64::try_from(v) {
-            Ok(value) => self.visit_i64(value),
-            Err(e) => Err(de::Error::custom(Error::ValueTooLargeI64(e, v))),
-        }
-    }
+#[cfg(feature = "events")]
+pub fn duration_secs<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i64::deserialize(deserializer).map(Duration::seconds)
 }
 
-pub fn duration_nanos<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+#[cfg(feature = "events")]
+pub fn duration_millis_f64<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserializer.deserialize_i64(DurationNanosVisitor)
+    f64::deserialize(deserializer).map(|millis| Duration::microseconds((millis * 1000.0) as i64))
 }
 
-struct DurationNanosVisitor;
-
-impl<'de> Visitor<'de> for DurationNanosVisitor {
-    type Value = Duration;
-
-    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str("a duration in nanoseconds")
-    }
-
-    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        Ok(Duration::nanoseconds(v))
-    }
+#[cfg(feature = "events")]
+pub fn bitflags_u8_hex<'de, D, T, TE>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u8, Error = TE>,
+    TE: Display,
+{
+    let value = String::deserialize(deserializer)?;
+    let value = u8::from_str_radix(value.trim_start_matches("0x"), 16)
+        .map_err(|e| de::Error::custom(Error::InvalidInteger(e)))?;
 
-    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        match i64::try_from(v) {
-            Ok(value) => self.visit_i64(value),
-            Err(e) => Err(de::Error::custom(Error::ValueTooLargeI64(e, v))),
-        }
-    }
+    T::try_from(value).map_err(|e| de::Error::custom(Error::ConversionFailed(e.to_string())))
 }
 
 pub fn bitflags_u8<'de, D, T, TE>(deserializer: D) -> Result<T, D::Error>
@@ -419,70 +398,18 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "events")]
     #[test]
-    fn deser_duration_millis() {
+    fn deser_duration_secs_opt() {
         #[derive(Debug, PartialEq, Eq, Deserialize)]
         struct SimpleDuration {
-            #[serde(deserialize_with = "duration_millis")]
-            value: Duration,
-        }
-
-        assert_de_tokens(
-            &SimpleDuration {
-                value: Duration::milliseconds(150),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::I64(150),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_de_tokens(
-            &SimpleDuration {
-                value: Duration::milliseconds(150),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::U64(150),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_de_tokens_error::<SimpleDuration>(
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::U64(u64::MAX),
-                Token::StructEnd,
-            ],
-            "value 18446744073709551615 is too large for an i64: \
-            out of range integral type conversion attempted",
-        );
-    }
-
-    #[test]
-    fn deser_duration_nanos() {
-        #[derive(Debug, PartialEq, Eq, Deserialize)]
-        struct SimpleDuration {
-            #[serde(deserialize_with = "duration_nanos")]
-            value: Duration,
+            #[serde(default, deserialize_with = "duration_secs_opt")]
+            value: Option<Duration>,
         }
 
         assert_de_tokens(
             &SimpleDuration {
-                value: Duration::nanoseconds(150),
+                value: Some(Duration::seconds(95)),
             },
             &[
                 Token::Struct {
@@ -490,38 +417,21 @@ mod tests {
                     len: 1,
                 },
                 Token::Str("value"),
-                Token::I64(150),
+                Token::Some,
+                Token::I64(95),
                 Token::StructEnd,
             ],
         );
 
         assert_de_tokens(
-            &SimpleDuration {
-                value: Duration::nanoseconds(150),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::U64(150),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_de_tokens_error::<SimpleDuration>(
+            &SimpleDuration { value: None },
             &[
                 Token::Struct {
                     name: "SimpleDuration",
-                    len: 1,
+                    len: 0,
                 },
-                Token::Str("value"),
-                Token::U64(u64::MAX),
                 Token::StructEnd,
             ],
-            "value 18446744073709551615 is too large for an i64: \
-            out of range integral type conversion attempted",
         );
     }
 
@@ -632,4 +542,28 @@ mod tests {
             "value doesn't fit into an u8 integer: out of range integral type conversion attempted",
         );
     }
+
+    #[test]
+    fn deser_volume_mul() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct SimpleVolume {
+            #[serde(deserialize_with = "volume_mul")]
+            value: Volume,
+        }
+
+        assert_de_tokens(
+            &SimpleVolume {
+                value: Volume::Mul(0.5),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleVolume",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(0.5),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,4 @@
+//! Derive macros for typed settings of sources from third-party plugins, that can be used like the
+//! built-in settings in [`requests`](crate::requests).
+
+pub use obws_derive::SourceSettings;
@@ -2,8 +2,13 @@
 
 use chrono::Duration;
 use serde::Deserialize;
+use serde_with::serde_as;
 
-use crate::common::{SceneItem, SceneItemTransform};
+use crate::common::{
+    DurationMillis, DurationNanos, Presence, SceneItem, SceneItemId, SceneItemTransform, SceneName,
+    SourceKind, SourceName, Track, TrackMask, Volume, PRESENCE_REALM,
+};
+use crate::responses::ObsStats;
 
 /// Events are sent when a recognized action occurs within OBS.
 #[derive(Clone, Debug, Deserialize)]
@@ -21,6 +26,7 @@ pub struct Event {
 }
 
 /// All possible event types that can occur while the user interacts with OBS.
+#[serde_as]
 #[derive(Clone, Debug, Deserialize)]
 #[serde(tag = "update-type")]
 pub enum EventType {
@@ -31,7 +37,7 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     SwitchScenes {
         /// The new scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// List of scene items in the new scene.
         sources: Vec<SceneItem>,
     },
@@ -73,7 +79,7 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     TransitionDurationChanged {
         /// New transition duration.
-        #[serde(deserialize_with = "crate::de::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         new_duration: Duration,
     },
     /// A transition (other than "cut") has begun.
@@ -86,12 +92,12 @@ pub enum EventType {
         ty: String,
         /// Transition duration (in milliseconds). Will be -1 for any transition with a fixed
         /// duration, such as a Stinger, due to limitations of the OBS API.
-        #[serde(deserialize_with = "crate::de::duration_millis_opt")]
+        #[serde_as(as = "Option<DurationMillis>")]
         duration: Option<Duration>,
         /// Source scene of the transition.
-        from_scene: Option<String>,
+        from_scene: Option<SceneName>,
         /// Destination scene of the transition.
-        to_scene: String,
+        to_scene: SceneName,
     },
     /// A transition (other than "cut") has ended.
     ///
@@ -104,10 +110,10 @@ pub enum EventType {
         #[serde(rename = "type")]
         ty: String,
         /// Transition duration (in milliseconds).
-        #[serde(deserialize_with = "crate::de::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         duration: Duration,
         /// Destination scene of the transition.
-        to_scene: String,
+        to_scene: SceneName,
     },
     /// A stinger transition has finished playing its video.
     #[serde(rename_all = "kebab-case")]
@@ -118,12 +124,12 @@ pub enum EventType {
         #[serde(rename = "type")]
         ty: String,
         /// Transition duration (in milliseconds).
-        #[serde(deserialize_with = "crate::de::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         duration: Duration,
         /// Source scene of the transition.
-        from_scene: Option<String>,
+        from_scene: Option<SceneName>,
         /// Destination scene of the transition.
-        to_scene: String,
+        to_scene: SceneName,
     },
     // --------------------------------
     // Profiles
@@ -174,8 +180,9 @@ pub enum EventType {
         kbits_per_sec: u64,
         /// Percentage of dropped frames.
         strain: f64,
-        /// Total time (in seconds) since the stream started.
-        total_stream_time: u64,
+        /// Total time since the stream started.
+        #[serde(deserialize_with = "crate::de::duration_secs")]
+        total_stream_time: Duration,
         /// Total number of frames transmitted since the stream started.
         num_total_frames: u64,
         /// Number of frames dropped by the encoder since the stream started.
@@ -190,8 +197,9 @@ pub enum EventType {
         output_total_frames: u64,
         /// Number of frames skipped due to encoding lag.
         output_skipped_frames: u64,
-        /// Average frame time (in milliseconds).
-        average_frame_time: f64,
+        /// Average time to render a frame.
+        #[serde(deserialize_with = "crate::de::duration_millis_f64")]
+        average_frame_time: Duration,
         /// Current CPU usage (percentage).
         cpu_usage: f64,
         /// Current RAM usage (in megabytes).
@@ -251,6 +259,9 @@ pub enum EventType {
     // --------------------------------
     // General
     // --------------------------------
+    /// Emitted every 2 seconds after enabling it by calling
+    /// [`set_heartbeat`](crate::client::General::set_heartbeat).
+    Heartbeat(Heartbeat),
     /// A custom broadcast message, sent by the server, requested by one of the websocket clients.
     BroadcastCustomMessage {
         /// Identifier provided by the sender.
@@ -265,11 +276,11 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceCreated {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Source type. Can be "input", "scene", "transition" or "filter".
         source_type: SourceType,
         /// Source kind.
-        source_kind: String,
+        source_kind: SourceKind,
         /// Source settings.
         source_settings: serde_json::Value,
     },
@@ -277,25 +288,26 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceDestroyed {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Source type. Can be "input", "scene", "transition" or "filter".
         source_type: SourceType,
         /// Source kind.
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// The volume of a source has changed.
     #[serde(rename_all = "camelCase")]
     SourceVolumeChanged {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Source volume.
-        volume: f32,
+        #[serde(deserialize_with = "crate::de::volume_mul")]
+        volume: Volume,
     },
     /// A source has been muted or unmuted.
     #[serde(rename_all = "camelCase")]
     SourceMuteStateChanged {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Mute status of the source.
         muted: bool,
     },
@@ -303,32 +315,37 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceAudioDeactivated {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
     },
     /// A source has added audio.
     #[serde(rename_all = "camelCase")]
     SourceAudioActivated {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
     },
     /// The audio sync offset of a source has changed.
     #[serde(rename_all = "camelCase")]
     SourceAudioSyncOffsetChanged {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Audio sync offset of the source (in nanoseconds).
-        #[serde(deserialize_with = "crate::de::duration_nanos")]
+        #[serde_as(as = "DurationNanos")]
         sync_offset: Duration,
     },
     /// Audio mixer routing changed on a source.
     #[serde(rename_all = "camelCase")]
     SourceAudioMixersChanged {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Routing status of the source for each audio mixer (array of 6 values).
         mixers: [AudioMixer; 6],
-        /// Raw mixer flags (little-endian, one bit per mixer) as an hexadecimal value.
-        hex_mixers_value: String,
+        /// All audio mixers the source is routed to, the same information as
+        /// [`mixers`](Self::SourceAudioMixersChanged::mixers) combined into flags.
+        #[serde(
+            rename = "hexMixersValue",
+            deserialize_with = "crate::de::bitflags_u8_hex"
+        )]
+        tracks: TrackMask,
     },
     /// A source has been renamed.
     #[serde(rename_all = "camelCase")]
@@ -344,7 +361,7 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceFilterAdded {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Filter name.
         filter_name: String,
         /// Filter type.
@@ -356,7 +373,7 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceFilterRemoved {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Filter name.
         filter_name: String,
         /// Filter type.
@@ -366,7 +383,7 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceFilterVisibilityChanged {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Filter name.
         filter_name: String,
         /// New filter state.
@@ -376,7 +393,7 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     SourceFiltersReordered {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// Ordered Filters list.
         filters: Vec<SourceFilter>,
     },
@@ -390,9 +407,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaPlaying {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Media playback paused.
     ///
@@ -401,9 +418,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaPaused {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Media playback restarted.
     ///
@@ -412,9 +429,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaRestarted {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Media playback stopped.
     ///
@@ -423,9 +440,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaStopped {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Next media started.
     ///
@@ -434,9 +451,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaNext {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Previous media started.
     ///
@@ -445,9 +462,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaPrevious {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Media playback started.
     ///
@@ -456,9 +473,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaStarted {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     /// Media playback ended.
     ///
@@ -467,9 +484,9 @@ pub enum EventType {
     #[serde(rename_all = "camelCase")]
     MediaEnded {
         /// Source name.
-        source_name: String,
+        source_name: SourceName,
         /// The ID type of the source (Eg. `vlc_source` or `ffmpeg_source`).
-        source_kind: String,
+        source_kind: SourceKind,
     },
     // --------------------------------
     // Scene Items
@@ -478,39 +495,39 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     SourceOrderChanged {
         /// Name of the scene where items have been reordered.
-        scene_name: String,
-        /// Ordered list of scene items.
+        scene_name: SceneName,
+        /// Ordered list of scene items, from the bottom to the top item.
         scene_items: Vec<SourceOrderSceneItem>,
     },
     /// A scene item has been added to a scene.
     #[serde(rename_all = "kebab-case")]
     SceneItemAdded {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item added to the scene.
-        item_name: String,
+        item_name: SourceName,
         /// Scene item ID.
-        item_id: i64,
+        item_id: SceneItemId,
     },
     /// A scene item has been removed from a scene.
     #[serde(rename_all = "kebab-case")]
     SceneItemRemoved {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item removed from the scene.
-        item_name: String,
+        item_name: SourceName,
         /// Scene item ID.
-        item_id: i64,
+        item_id: SceneItemId,
     },
     /// A scene item's visibility has been toggled.
     #[serde(rename_all = "kebab-case")]
     SceneItemVisibilityChanged {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item in the scene.
-        item_name: String,
+        item_name: SourceName,
         /// Scene item ID.
-        item_id: i64,
+        item_id: SceneItemId,
         /// New visibility state of the item.
         item_visible: bool,
     },
@@ -518,11 +535,11 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     SceneItemLockChanged {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item in the scene.
-        item_name: String,
+        item_name: SourceName,
         /// Scene item ID.
-        item_id: i64,
+        item_id: SceneItemId,
         /// New locked state of the item.
         item_locked: bool,
     },
@@ -530,11 +547,11 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     SceneItemTransformChanged {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item in the scene.
-        item_name: String,
+        item_name: SourceName,
         /// Scene item ID.
-        item_id: i64,
+        item_id: SceneItemId,
         /// Scene item transform properties.
         transform: SceneItemTransform,
     },
@@ -542,21 +559,21 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     SceneItemSelected {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item in the scene.
-        item_name: String,
+        item_name: SourceName,
         /// ID of the item in the scene.
-        item_id: i64,
+        item_id: SceneItemId,
     },
     /// A scene item is deselected.
     #[serde(rename_all = "kebab-case")]
     SceneItemDeselected {
         /// Name of the scene.
-        scene_name: String,
+        scene_name: SceneName,
         /// Name of the item in the scene.
-        item_name: String,
+        item_name: SourceName,
         /// ID of the item in the scene.
-        item_id: i64,
+        item_id: SceneItemId,
     },
     // --------------------------------
     // Studio Mode
@@ -565,7 +582,7 @@ pub enum EventType {
     #[serde(rename_all = "kebab-case")]
     PreviewSceneChanged {
         /// Name of the scene being previewed.
-        scene_name: String,
+        scene_name: SceneName,
         /// List of sources composing the scene.
         sources: Vec<SceneItem>,
     },
@@ -584,11 +601,57 @@ pub enum EventType {
     Unknown,
 }
 
+impl EventType {
+    /// Extract a presence announcement, if this event is a
+    /// [`BroadcastCustomMessage`](Self::BroadcastCustomMessage) within the [`PRESENCE_REALM`] that
+    /// carries a valid [`Presence`] payload.
+    pub fn presence(&self) -> Option<Presence> {
+        match self {
+            Self::BroadcastCustomMessage { realm, data } if realm == PRESENCE_REALM => {
+                serde_json::from_value(serde_json::Value::Object(data.clone())).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Part of [`EventType::Heartbeat`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Heartbeat {
+    /// Toggles between every JSON message as an "I am alive" indicator.
+    pub pulse: bool,
+    /// Current active profile.
+    pub current_profile: Option<String>,
+    /// Current active scene.
+    pub current_scene: Option<SceneName>,
+    /// Current streaming state.
+    pub streaming: Option<bool>,
+    /// Total time since the stream started.
+    #[serde(default, deserialize_with = "crate::de::duration_secs_opt")]
+    pub total_stream_time: Option<Duration>,
+    /// Total bytes sent since the stream started.
+    pub total_stream_bytes: Option<u64>,
+    /// Total frames streamed since the stream started.
+    pub total_stream_frames: Option<u64>,
+    /// Current recording state.
+    pub recording: Option<bool>,
+    /// Total time since recording started.
+    #[serde(default, deserialize_with = "crate::de::duration_secs_opt")]
+    pub total_record_time: Option<Duration>,
+    /// Total bytes recorded since the recording started.
+    pub total_record_bytes: Option<u64>,
+    /// Total frames recorded since the recording started.
+    pub total_record_frames: Option<u64>,
+    /// OBS stats.
+    pub stats: ObsStats,
+}
+
 /// Part of [`EventType::ScenesChanged`].
 #[derive(Clone, Debug, Deserialize)]
 pub struct Scene {
     /// Name of the currently active scene.
-    pub name: String,
+    pub name: SceneName,
     /// Ordered list of the current scene's source items.
     pub sources: Vec<SceneItem>,
 }
@@ -618,7 +681,7 @@ pub struct Profile {
 #[derive(Clone, Debug, Deserialize)]
 pub struct AudioMixer {
     /// Mixer number.
-    pub id: i64,
+    pub id: Track,
     /// Routing status.
     pub enabled: bool,
 }
@@ -640,9 +703,9 @@ pub struct SourceFilter {
 #[serde(rename_all = "kebab-case")]
 pub struct SourceOrderSceneItem {
     /// Item source name.
-    pub source_name: String,
+    pub source_name: SourceName,
     /// Scene item unique ID.
-    pub item_id: i64,
+    pub item_id: SceneItemId,
 }
 
 /// Part of [`EventType::SourceCreated`], [`EventType::SourceDestroyed`] and
@@ -659,3 +722,69 @@ pub enum SourceType {
     /// Filter for scene items.
     Filter,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn source_audio_mixers_changed() {
+        let mixers = (1..=6)
+            .map(|id| json!({ "id": id, "enabled": id <= 2 }))
+            .collect::<Vec<_>>();
+        let event = serde_json::from_value::<EventType>(json!({
+            "update-type": "SourceAudioMixersChanged",
+            "sourceName": "Mic",
+            "mixers": mixers,
+            "hexMixersValue": "3",
+        }))
+        .unwrap();
+
+        match event {
+            EventType::SourceAudioMixersChanged { mixers, tracks, .. } => {
+                assert_eq!(TrackMask::TRACK_1 | TrackMask::TRACK_2, tracks);
+                assert_eq!(6, mixers[5].id.get());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn media_event_source_kind() {
+        let event = serde_json::from_value::<EventType>(json!({
+            "update-type": "MediaEnded",
+            "sourceName": "Clip",
+            "sourceKind": "ffmpeg_source",
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            event,
+            EventType::MediaEnded {
+                source_kind: SourceKind::FfmpegSource,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn transition_begin_duration() {
+        let event = serde_json::from_value::<EventType>(json!({
+            "update-type": "TransitionBegin",
+            "name": "Fade",
+            "type": "fade_transition",
+            "duration": 300,
+            "to-scene": "Scene",
+        }))
+        .unwrap();
+
+        match event {
+            EventType::TransitionBegin { duration, .. } => {
+                assert_eq!(Some(Duration::milliseconds(300)), duration);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+}
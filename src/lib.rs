@@ -38,11 +38,29 @@ pub use self::client::Client;
 
 pub mod client;
 pub mod common;
+pub mod controller;
+#[cfg(feature = "derive")]
+pub mod derive;
 #[cfg(feature = "events")]
 pub mod events;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod pool;
 pub mod requests;
 pub mod responses;
+pub mod settings;
+pub mod setup;
+#[cfg(feature = "events")]
+pub mod state;
+#[cfg(feature = "events")]
+pub mod switcher;
+#[cfg(feature = "move-transition")]
+pub mod vendors;
 
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
 mod de;
 
 /// Result type used throughout the crate that uses [`Error`] as default error.
@@ -53,13 +71,13 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub enum Error {
     /// An error occured while trying to connect to the websocket.
     #[error("failed to connect to the obs-websocket plugin")]
-    Connect(#[source] tokio_tungstenite::tungstenite::Error),
+    Connect(#[source] Box<tokio_tungstenite::tungstenite::Error>),
     /// Failed to serialize the message to be send to the websocket.
     #[error("failed to serialize message")]
     SerializeMessage(#[source] serde_json::Error),
     /// A message could not be send through the websocket.
     #[error("failed to send message to the obs-websocket plugin")]
-    Send(#[source] tokio_tungstenite::tungstenite::Error),
+    Send(#[source] Box<tokio_tungstenite::tungstenite::Error>),
     /// Tried to receive data while the send side was already closed.
     #[error("send side is closed")]
     ReceiveMessage(#[source] tokio::sync::oneshot::error::RecvError),
@@ -75,6 +93,9 @@ pub enum Error {
     /// The obs-websocket API requires authentication but no password was given.
     #[error("authentication required but no password provided")]
     NoPassword,
+    /// The credentials for the authentication couldn't be loaded.
+    #[error("failed to load the credentials")]
+    LoadCredentials(#[source] client::AuthError),
     /// Unknown flags were found while trying to parse bitflags.
     #[error("value {0} contains unknown flags")]
     UnknownFlags(u8),
@@ -89,4 +110,67 @@ pub enum Error {
     /// The obs-websocket plugin version doesn't match the required version for this crate.
     #[error("obs-websocket version {0} doesn't match required {1}")]
     ObsWebsocketVersion(Version, Comparator),
+    /// The T-Bar position is outside of the valid range `0.0..=1.0`.
+    #[error("T-Bar position {0} is outside of the valid range 0.0 to 1.0")]
+    InvalidTBarPosition(f64),
+    /// The playback speed is outside of the valid range `1..=200` percent.
+    #[error("playback speed {0}% is outside of the valid range 1 to 200")]
+    InvalidPlaybackSpeed(u32),
+    /// The source is of the given kind, which has no playback speed setting.
+    #[error("source kind {0} has no playback speed setting")]
+    NoPlaybackSpeed(String),
+    /// The source is of the given kind, which isn't a text source.
+    #[error("source kind {0} is not a text source")]
+    NotATextSource(String),
+    /// The source kind has no setting to select a device, display or window.
+    #[error("source kind {0} has no device setting")]
+    NoDeviceSetting(String),
+    /// The referenced scene item doesn't exist in the scene.
+    #[error("scene item {0} not found")]
+    UnknownSceneItem(String),
+    /// A source of a [`Setup`](setup::Setup) doesn't exist yet and can't be created, because its
+    /// kind is missing.
+    #[error("source {0} doesn't exist and has no kind to create it")]
+    MissingSourceKind(String),
+    /// No heartbeat was received from OBS within the expected interval.
+    #[error("no heartbeat received within {0}")]
+    HeartbeatTimeout(chrono::Duration),
+    /// The source didn't become active within the given time.
+    #[error("source {0} didn't become active in time")]
+    SourceNotActive(String),
+    /// The capture source didn't start capturing anything within the given time, for example
+    /// because a game capture couldn't hook into the game.
+    #[error("capture source {0} didn't start capturing in time")]
+    CaptureNotActive(String),
+    /// OBS didn't finish switching to the scene collection within the given time.
+    #[error("scene collection {0} wasn't loaded in time")]
+    SceneCollectionNotLoaded(String),
+    /// OBS didn't switch to the profile and apply a different canvas size within the given time.
+    #[error("canvas size didn't change after switching to profile {0}")]
+    CanvasNotChanged(String),
+    /// The string is not a valid hex color like `#RRGGBB` or `#RRGGBBAA`.
+    #[error("{0} is not a valid hex color")]
+    InvalidColor(String),
+    /// The duration is shorter than the minimum that OBS accepts for the setting.
+    #[error(
+        "duration of {}ms is shorter than the minimum of {}ms",
+        .0.num_milliseconds(),
+        .1.num_milliseconds()
+    )]
+    DurationTooShort(chrono::Duration, chrono::Duration),
+    /// The percentage is outside of the valid range `0.0..=100.0`.
+    #[error("percentage {0} is outside of the valid range 0 to 100")]
+    InvalidPercent(f64),
+    /// The audio track number is outside of the valid range `1..=6`.
+    #[error("audio track {0} is outside of the valid range 1 to 6")]
+    InvalidTrack(u8),
+    /// The request is not supported by the connected obs-websocket version, as it was added in a
+    /// later version.
+    #[error("request requires obs-websocket {required}, but the connected version is {actual}")]
+    UnsupportedRequest {
+        /// Minimum version that supports the request.
+        required: Version,
+        /// Version of the connected obs-websocket plugin.
+        actual: Version,
+    },
 }
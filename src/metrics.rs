@@ -0,0 +1,164 @@
+//! Exporter that feeds the health of an OBS instance into the [`metrics`] facade, so it
+//! can be scraped by whatever recorder the application installed (for example a Prometheus
+//! exporter).
+//!
+//! All metrics are prefixed with `obs_`:
+//!
+//! | Name                                | Type    | Description                                |
+//! | ----------------------------------- | ------- | ------------------------------------------ |
+//! | `obs_up`                            | gauge   | `1` if the last poll succeeded, else `0`.  |
+//! | `obs_poll_errors_total`             | counter | Failed polls.                              |
+//! | `obs_fps`                           | gauge   | Current framerate.                         |
+//! | `obs_average_frame_time_ms`         | gauge   | Average frame render time.                 |
+//! | `obs_cpu_usage_percent`             | gauge   | CPU usage of OBS.                          |
+//! | `obs_memory_usage_mb`               | gauge   | RAM usage of OBS.                          |
+//! | `obs_free_disk_space_mb`            | gauge   | Free space on the recording disk.          |
+//! | `obs_render_frames_total`           | counter | Rendered frames.                           |
+//! | `obs_render_missed_frames_total`    | counter | Frames missed due to rendering lag.        |
+//! | `obs_output_frames_total`           | counter | Output frames.                             |
+//! | `obs_output_skipped_frames_total`   | counter | Frames skipped due to encoding lag.        |
+//! | `obs_render_missed_ratio`           | gauge   | Missed render frames since the last poll.  |
+//! | `obs_output_skipped_ratio`          | gauge   | Skipped output frames since the last poll. |
+//! | `obs_streaming`                     | gauge   | `1` while streaming, else `0`.             |
+//! | `obs_recording`                     | gauge   | `1` while recording, else `0`.             |
+//! | `obs_recording_paused`              | gauge   | `1` while the recording is paused.         |
+//! | `obs_stream_uptime_seconds`         | gauge   | Time since the stream started.             |
+//! | `obs_recording_uptime_seconds`      | gauge   | Time since the recording started.          |
+
+use chrono::Duration;
+use futures_util::StreamExt;
+use log::debug;
+use metrics::{counter, gauge};
+
+use crate::client::{Client, StatsSample};
+use crate::responses::{ObsStats, StreamingStatus};
+use crate::Result;
+
+/// Periodically polls an OBS instance and records its stats, streaming status and connection
+/// health as metrics.
+///
+/// ```no_run
+/// # async fn run(client: &obws::Client) {
+/// use chrono::Duration;
+/// use obws::metrics::MetricsExporter;
+///
+/// MetricsExporter::new(client)
+///     .label("instance", "main")
+///     .run(Duration::seconds(5))
+///     .await;
+/// # }
+/// ```
+pub struct MetricsExporter<'a> {
+    client: &'a Client,
+    labels: Vec<(String, String)>,
+}
+
+impl<'a> MetricsExporter<'a> {
+    /// Create a new exporter for the given client.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Add a label that is attached to all recorded metrics, for example to distinguish multiple
+    /// OBS instances.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.push((key.into(), value.into()));
+        self
+    }
+
+    /// Poll OBS in the given interval and record the metrics, until the returned future is
+    /// dropped. Failed polls are recorded as well and don't stop the exporter.
+    ///
+    /// - `interval`: Time between two polls.
+    pub async fn run(&self, interval: Duration) {
+        let samples = self.client.general().stats_stream(interval);
+        futures_util::pin_mut!(samples);
+
+        while let Some(sample) = samples.next().await {
+            let status = match sample {
+                Ok(sample) => {
+                    self.record_stats(&sample.stats);
+                    self.record_ratios(&sample);
+                    self.client.streaming().get_streaming_status().await
+                }
+                Err(e) => Err(e),
+            };
+
+            self.record_result(status.map(|status| self.record_status(&status)));
+        }
+    }
+
+    /// Poll OBS once and record the metrics. This allows to drive the exporter from an existing
+    /// scheduler instead of [`run`](Self::run). The derived ratios are only recorded by
+    /// [`run`](Self::run), as they need the previous poll.
+    pub async fn record(&self) -> Result<()> {
+        let result = self.poll().await;
+        self.record_result(result.as_ref().map(|_| ()));
+        result
+    }
+
+    async fn poll(&self) -> Result<()> {
+        let stats = self.client.general().get_stats().await?;
+        self.record_stats(&stats);
+
+        let status = self.client.streaming().get_streaming_status().await?;
+        self.record_status(&status);
+
+        Ok(())
+    }
+
+    fn record_result<E: std::fmt::Debug>(&self, result: std::result::Result<(), E>) {
+        let labels = &self.labels;
+
+        match result {
+            Ok(()) => gauge!("obs_up", labels).set(1.0),
+            Err(e) => {
+                debug!("failed polling OBS for metrics: {:?}", e);
+                gauge!("obs_up", labels).set(0.0);
+                counter!("obs_poll_errors_total", labels).increment(1);
+            }
+        }
+    }
+
+    fn record_stats(&self, stats: &ObsStats) {
+        let labels = &self.labels;
+
+        gauge!("obs_fps", labels).set(stats.fps);
+        gauge!("obs_average_frame_time_ms", labels).set(stats.average_frame_time);
+        gauge!("obs_cpu_usage_percent", labels).set(stats.cpu_usage);
+        gauge!("obs_memory_usage_mb", labels).set(stats.memory_usage);
+        gauge!("obs_free_disk_space_mb", labels).set(stats.free_disk_space);
+
+        counter!("obs_render_frames_total", labels).absolute(stats.render_total_frames);
+        counter!("obs_render_missed_frames_total", labels).absolute(stats.render_missed_frames);
+        counter!("obs_output_frames_total", labels).absolute(stats.output_total_frames);
+        counter!("obs_output_skipped_frames_total", labels).absolute(stats.output_skipped_frames);
+    }
+
+    fn record_ratios(&self, sample: &StatsSample) {
+        let labels = &self.labels;
+
+        if let Some(ratio) = sample.render_missed_ratio {
+            gauge!("obs_render_missed_ratio", labels).set(ratio);
+        }
+        if let Some(ratio) = sample.output_skipped_ratio {
+            gauge!("obs_output_skipped_ratio", labels).set(ratio);
+        }
+    }
+
+    fn record_status(&self, status: &StreamingStatus) {
+        let labels = &self.labels;
+        let flag = |value: bool| if value { 1.0 } else { 0.0 };
+        let seconds =
+            |value: Option<Duration>| value.map_or(0.0, |d| d.num_milliseconds() as f64 / 1000.0);
+
+        gauge!("obs_streaming", labels).set(flag(status.streaming));
+        gauge!("obs_recording", labels).set(flag(status.recording));
+        gauge!("obs_recording_paused", labels).set(flag(status.recording_paused));
+        gauge!("obs_stream_uptime_seconds", labels).set(seconds(status.stream_timecode));
+        gauge!("obs_recording_uptime_seconds", labels).set(seconds(status.rec_timecode));
+    }
+}
@@ -0,0 +1,186 @@
+//! Management of connections to several OBS instances at once, for productions that run more
+//! than one instance, like separate instances for streaming and recording or a redundant backup.
+
+use std::future::Future;
+
+use futures_util::future;
+#[cfg(feature = "events")]
+use futures_util::stream::{self, Stream, StreamExt};
+
+#[cfg(feature = "events")]
+use crate::events::Event;
+use crate::{Client, Result};
+
+/// Collection of clients, each connected to a different OBS instance and identified by a label.
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use obws::{pool::ObsPool, Client};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut pool = ObsPool::new();
+///     pool.insert("main", Client::connect("10.0.0.10", 4444).await?);
+///     pool.insert("backup", Client::connect("10.0.0.11", 4444).await?);
+///
+///     for (label, result) in pool.all().start_streaming().await {
+///         if let Err(e) = result {
+///             eprintln!("{} failed to start streaming: {}", label, e);
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ObsPool {
+    clients: Vec<(String, Client)>,
+}
+
+impl ObsPool {
+    /// Create a new, empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a client under the given label. A client that already exists under the same label is
+    /// replaced and returned.
+    pub fn insert(&mut self, label: impl Into<String>, client: Client) -> Option<Client> {
+        let label = label.into();
+
+        match self.clients.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, existing)) => Some(std::mem::replace(existing, client)),
+            None => {
+                self.clients.push((label, client));
+                None
+            }
+        }
+    }
+
+    /// Remove the client with the given label from the pool.
+    pub fn remove(&mut self, label: &str) -> Option<Client> {
+        let index = self.clients.iter().position(|(l, _)| l == label)?;
+        Some(self.clients.remove(index).1)
+    }
+
+    /// Get the client with the given label.
+    pub fn get(&self, label: &str) -> Option<&Client> {
+        self.clients
+            .iter()
+            .find_map(|(l, client)| (l == label).then_some(client))
+    }
+
+    /// Labels of all clients, in the order they were added.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.clients.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Number of clients in the pool.
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Whether the pool contains no clients.
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Access functions that send the same request to all clients.
+    pub fn all(&self) -> PoolAll<'_> {
+        PoolAll { pool: self }
+    }
+
+    /// Get a single stream of the events of all clients, each tagged with the label of the client
+    /// it came from. Clients that are already disconnected are skipped.
+    ///
+    /// **Note**: To be able to iterate over the stream you have to pin it with
+    /// [`futures_util::pin_mut`] for example.
+    #[cfg(feature = "events")]
+    pub fn events(&self) -> impl Stream<Item = (String, Event)> {
+        let streams = self
+            .clients
+            .iter()
+            .filter_map(|(label, client)| {
+                let label = label.clone();
+                client
+                    .events()
+                    .ok()
+                    .map(|events| events.map(move |event| (label.clone(), event)).boxed())
+            })
+            .collect::<Vec<_>>();
+
+        stream::select_all(streams)
+    }
+}
+
+/// Functions that send the same request to all clients of an [`ObsPool`] concurrently.
+///
+/// The results are returned together with the label of each client, in the order the clients
+/// were added. A failure of one client doesn't stop the request for the others.
+pub struct PoolAll<'a> {
+    pool: &'a ObsPool,
+}
+
+impl<'a> PoolAll<'a> {
+    /// Run any operation for all clients, for requests that aren't covered by the other functions.
+    ///
+    /// ```no_run
+    /// # async fn run(pool: &obws::pool::ObsPool) {
+    /// let results = pool
+    ///     .all()
+    ///     .run(|client| async move { client.scenes().set_current_scene("Intro").await })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn run<F, Fut, T>(&self, f: F) -> Vec<(String, Result<T>)>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let results = future::join_all(
+            self.pool
+                .clients
+                .iter()
+                .map(|(_, client)| f(client.clone())),
+        )
+        .await;
+
+        self.pool
+            .labels()
+            .map(ToOwned::to_owned)
+            .zip(results)
+            .collect()
+    }
+
+    /// Start streaming on all instances.
+    pub async fn start_streaming(&self) -> Vec<(String, Result<()>)> {
+        self.run(|client| async move { client.streaming().start_streaming(None).await })
+            .await
+    }
+
+    /// Stop streaming on all instances.
+    pub async fn stop_streaming(&self) -> Vec<(String, Result<()>)> {
+        self.run(|client| async move { client.streaming().stop_streaming().await })
+            .await
+    }
+
+    /// Start recording on all instances.
+    pub async fn start_recording(&self) -> Vec<(String, Result<()>)> {
+        self.run(|client| async move { client.recording().start_recording().await })
+            .await
+    }
+
+    /// Stop recording on all instances.
+    pub async fn stop_recording(&self) -> Vec<(String, Result<()>)> {
+        self.run(|client| async move { client.recording().stop_recording().await })
+            .await
+    }
+
+    /// Switch to the scene with the given name on all instances.
+    ///
+    /// - `scene_name`: Name of the scene to switch to.
+    pub async fn set_current_scene(&self, scene_name: &str) -> Vec<(String, Result<()>)> {
+        self.run(|client| async move { client.scenes().set_current_scene(scene_name).await })
+            .await
+    }
+}
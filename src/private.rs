@@ -0,0 +1,65 @@
+//! Support types for the code generated by the derive macros. Not part of the public API.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use rgb::RGBA8;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::common::Color;
+
+pub use serde;
+
+pub struct RgbaInverseSer<'a>(pub &'a RGBA8);
+
+impl Serialize for RgbaInverseSer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Color::from(*self.0).serialize(serializer)
+    }
+}
+
+pub struct RgbaInverseDe(pub RGBA8);
+
+impl<'de> Deserialize<'de> for RgbaInverseDe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Color::deserialize(deserializer).map(|color| Self(color.into()))
+    }
+}
+
+pub struct BitflagsSer<'a, T>(pub &'a T);
+
+impl<T> Serialize for BitflagsSer<'_, T>
+where
+    T: Into<u8> + Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8((*self.0).into())
+    }
+}
+
+pub struct BitflagsDe<T>(pub T);
+
+impl<'de, T, TE> Deserialize<'de> for BitflagsDe<T>
+where
+    T: TryFrom<u8, Error = TE>,
+    TE: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u8::deserialize(deserializer)?;
+        T::try_from(value).map(Self).map_err(de::Error::custom)
+    }
+}
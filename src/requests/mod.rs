@@ -1,17 +1,30 @@
 //! All requests that can be send to the API.
 
-use std::path::Path;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
 use bitflags::bitflags;
 use chrono::Duration;
 use either::Either;
+use semver::Version;
 use serde::Serialize;
-use serde_with::skip_serializing_none;
+use serde_json::value::RawValue;
+use serde_with::{serde_as, skip_serializing_none};
 
 pub use rgb::RGBA8;
 
-use crate::common::{Align, Alignment, BoundsType, FontFlags, MonitorType, StreamType, Valign};
+#[cfg(feature = "ndi")]
+pub use self::ndi::{
+    NdiBandwidth, NdiLatency, NdiSource, NdiSyncMode, NDI_MAIN_OUTPUT, NDI_PREVIEW_OUTPUT,
+};
 
+use crate::common::{
+    Align, Alignment, BoundsType, Color, DurationMillis, DurationNanos, FontFlags, MinDuration,
+    MonitorType, SceneItemId, SceneName, SourceName, StreamType, TextFont, Valign,
+};
+
+#[cfg(feature = "ndi")]
+mod ndi;
 mod ser;
 
 #[derive(Serialize)]
@@ -19,9 +32,10 @@ mod ser;
 pub(crate) struct Request<'a> {
     pub message_id: &'a str,
     #[serde(flatten)]
-    pub ty: RequestType<'a>,
+    pub ty: &'a RequestType<'a>,
 }
 
+#[serde_as]
 #[derive(Serialize)]
 #[serde(tag = "request-type")]
 pub(crate) enum RequestType<'a> {
@@ -41,6 +55,10 @@ pub(crate) enum RequestType<'a> {
     },
     GetFilenameFormatting,
     GetStats,
+    SetHeartbeat {
+        /// Starts/Stops emitting heartbeat messages.
+        enable: bool,
+    },
     BroadcastCustomMessage {
         /// Identifier to be choosen by the client.
         realm: &'a str,
@@ -59,9 +77,11 @@ pub(crate) enum RequestType<'a> {
     TriggerHotkeyBySequence {
         /// Main key identifier (e.g. `OBS_KEY_A` for key "A"). Available identifiers
         /// [here](https://github.com/obsproject/obs-studio/blob/master/libobs/obs-hotkeys.h).
-        key_id: &'a str,
+        #[serde(with = "either::serde_untagged")]
+        key_id: Either<&'a str, Key>,
         /// Optional key modifiers object. False entries can be omitted.
-        key_modifiers: &'a [KeyModifier],
+        #[serde(serialize_with = "ser::key_modifiers")]
+        key_modifiers: KeyModifiers,
     },
     // --------------------------------
     // Media Control
@@ -108,7 +128,7 @@ pub(crate) enum RequestType<'a> {
         /// Source name.
         source_name: &'a str,
         /// Milliseconds to set the timestamp to.
-        #[serde(serialize_with = "ser::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         timestamp: Duration,
     },
     #[serde(rename_all = "camelCase")]
@@ -116,7 +136,7 @@ pub(crate) enum RequestType<'a> {
         /// Source name.
         source_name: &'a str,
         /// Millisecond offset (positive or negative) to offset the current media position.
-        #[serde(serialize_with = "ser::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         time_offset: Duration,
     },
     #[serde(rename_all = "camelCase")]
@@ -131,14 +151,19 @@ pub(crate) enum RequestType<'a> {
     CreateSource(CreateSource<'a>),
     GetSourcesList,
     GetSourceTypesList,
-    #[serde(rename_all = "camelCase")]
     GetVolume {
         /// Source name.
         source: &'a str,
-        /// Output volume in decibels of attenuation instead of amplitude/mul.
-        use_decibel: Option<bool>,
     },
-    SetVolume(Volume<'a>),
+    #[serde(rename_all = "camelCase")]
+    SetVolume {
+        /// Source name.
+        source: &'a str,
+        /// Desired volume, either as amplitude/mul or in decibels.
+        volume: f64,
+        /// Interpret `volume` data as decibels instead of amplitude/mul.
+        use_decibel: bool,
+    },
     GetMute {
         /// Source name.
         source: &'a str,
@@ -154,6 +179,11 @@ pub(crate) enum RequestType<'a> {
         source: &'a str,
     },
     #[serde(rename_all = "camelCase")]
+    GetSourceActive {
+        /// Source name.
+        source_name: &'a str,
+    },
+    #[serde(rename_all = "camelCase")]
     GetAudioActive {
         /// Source name.
         source_name: &'a str,
@@ -169,7 +199,7 @@ pub(crate) enum RequestType<'a> {
         /// Source name.
         source: &'a str,
         /// The desired audio sync offset (in nanoseconds).
-        #[serde(serialize_with = "ser::duration_nanos")]
+        #[serde_as(as = "DurationNanos")]
         offset: Duration,
     },
     GetSyncOffset {
@@ -184,7 +214,15 @@ pub(crate) enum RequestType<'a> {
         /// schema.
         source_type: Option<&'a str>,
     },
-    SetSourceSettings(SourceSettings<'a>),
+    #[serde(rename_all = "camelCase")]
+    SetSourceSettings {
+        /// Source name.
+        source_name: &'a str,
+        /// Type of the specified source.
+        source_type: Option<&'a str>,
+        /// Source settings, already serialized to JSON.
+        source_settings: &'a RawValue,
+    },
     #[serde(rename = "GetTextGDIPlusProperties")]
     GetTextGdiPlusProperties {
         /// Source name.
@@ -223,6 +261,20 @@ pub(crate) enum RequestType<'a> {
     SetSourceFilterSettings(SourceFilterSettings<'a>),
     SetSourceFilterVisibility(SourceFilterVisibility<'a>),
     #[serde(rename_all = "camelCase")]
+    GetAudioTracks {
+        /// Source name.
+        source_name: &'a str,
+    },
+    #[serde(rename_all = "camelCase")]
+    SetAudioTracks {
+        /// Source name.
+        source_name: &'a str,
+        /// Audio track number, from `1` to `6`.
+        track: u8,
+        /// Whether audio track is active or not.
+        active: bool,
+    },
+    #[serde(rename_all = "camelCase")]
     GetAudioMonitorType {
         /// Source name.
         source_name: &'a str,
@@ -231,7 +283,7 @@ pub(crate) enum RequestType<'a> {
     SetAudioMonitorType {
         /// Source name.
         source_name: &'a str,
-        /// The monitor type to use. Options: `none`, `monitorOnly`, `monitorAndOutput`.
+        /// The monitor type to use.
         monitor_type: MonitorType,
     },
     #[serde(rename_all = "camelCase")]
@@ -314,14 +366,14 @@ pub(crate) enum RequestType<'a> {
     // --------------------------------
     #[serde(rename_all = "camelCase")]
     GetSceneItemList {
-        /// Name of the scene to get the list of scene items from. Defaults to the current scene if
-        /// not specified.
-        scene_name: Option<&'a str>,
+        /// Name of the scene or group to get the list of scene items from. Defaults to the current
+        /// scene if not specified.
+        scene_name: Option<SceneOrGroup<'a>>,
     },
     #[serde(rename_all = "kebab-case")]
     GetSceneItemProperties {
-        /// Name of the scene the scene item belongs to. Defaults to the current scene.
-        scene_name: Option<&'a str>,
+        /// Name of the scene or group the scene item belongs to. Defaults to the current scene.
+        scene_name: Option<SceneOrGroup<'a>>,
         /// Scene Item name (if this field is a string) or specification (if it is an object).
         #[serde(with = "either::serde_untagged")]
         item: Either<&'a str, SceneItemSpecification<'a>>,
@@ -329,16 +381,16 @@ pub(crate) enum RequestType<'a> {
     SetSceneItemProperties(SceneItemProperties<'a>),
     #[serde(rename_all = "kebab-case")]
     ResetSceneItem {
-        /// Name of the scene the scene item belongs to. Defaults to the current scene.
-        scene_name: Option<&'a str>,
+        /// Name of the scene or group the scene item belongs to. Defaults to the current scene.
+        scene_name: Option<SceneOrGroup<'a>>,
         /// Scene Item name (if this field is a string) or specification (if it is an object).
         #[serde(with = "either::serde_untagged")]
         item: Either<&'a str, SceneItemSpecification<'a>>,
     },
     SetSceneItemRender(SceneItemRender<'a>),
     DeleteSceneItem {
-        /// Name of the scene the scene item belongs to. Defaults to the current scene.
-        scene: Option<&'a str>,
+        /// Name of the scene or group the scene item belongs to. Defaults to the current scene.
+        scene: Option<SceneOrGroup<'a>>,
         /// Scene item to delete.
         item: SceneItemSpecification<'a>, // TODO: fields are actually not optional
     },
@@ -361,7 +413,7 @@ pub(crate) enum RequestType<'a> {
     },
     ReorderSceneItems {
         /// Name of the scene to reorder (defaults to current).
-        scene: Option<&'a str>,
+        scene: Option<&'a SceneName>,
         /// Ordered list of objects with name and/or id specified. Id preferred due to uniqueness
         /// per scene.
         items: &'a [SceneItem<'a>],
@@ -423,7 +475,7 @@ pub(crate) enum RequestType<'a> {
     },
     SetTransitionDuration {
         /// Desired duration of the transition (in milliseconds).
-        #[serde(serialize_with = "ser::duration_millis")]
+        #[serde_as(as = "DurationMillis")]
         duration: Duration,
     },
     GetTransitionDuration,
@@ -452,6 +504,20 @@ pub(crate) enum RequestType<'a> {
     },
 }
 
+impl<'a> RequestType<'a> {
+    /// Minimum obs-websocket version that supports the request, if it was added after the oldest
+    /// version this crate supports.
+    pub(crate) fn min_version(&self) -> Option<Version> {
+        match self {
+            Self::GetSourceActive { .. }
+            | Self::GetAudioActive { .. }
+            | Self::GetAudioTracks { .. }
+            | Self::SetAudioTracks { .. } => Some(Version::new(4, 9, 1)),
+            _ => None,
+        }
+    }
+}
+
 /// Request information for [`open_projector`](crate::client::General::open_projector).
 #[derive(Debug, Default)]
 pub struct Projector<'a> {
@@ -470,7 +536,7 @@ pub struct Projector<'a> {
 
 /// Request information for [`open_projector`](crate::client::General::open_projector) as part of
 /// [`Projector`].
-#[derive(Clone, Copy, Debug, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub enum ProjectorType {
     /// Open a projector of the preview area.
     Preview,
@@ -518,59 +584,1309 @@ pub struct KeyModifier {
     pub command: bool,
 }
 
+impl From<&KeyModifier> for KeyModifiers {
+    fn from(m: &KeyModifier) -> Self {
+        let mut modifiers = Self::empty();
+        modifiers.set(Self::SHIFT, m.shift);
+        modifiers.set(Self::ALT, m.alt);
+        modifiers.set(Self::CONTROL, m.control);
+        modifiers.set(Self::COMMAND, m.command);
+        modifiers
+    }
+}
+
+bitflags! {
+    /// Request information for
+    /// [`trigger_hotkey_sequence`](crate::client::General::trigger_hotkey_sequence). The modifiers
+    /// can be combined, for example `KeyModifiers::CONTROL | KeyModifiers::SHIFT`.
+    #[derive(Default)]
+    pub struct KeyModifiers: u8 {
+        /// Trigger Shift key.
+        const SHIFT = 1;
+        /// Trigger Alt key.
+        const ALT = 2;
+        /// Trigger Control (Ctrl) key.
+        const CONTROL = 4;
+        /// Trigger Command key (Mac).
+        const COMMAND = 8;
+    }
+}
+
+/// Key identifiers of OBS, for usage in
+/// [`trigger_hotkey_sequence`](crate::client::General::trigger_hotkey_sequence).
+///
+/// This covers the commonly used keys. The full list of identifiers can be found in the
+/// [OBS source code](https://github.com/obsproject/obs-studio/blob/master/libobs/obs-hotkeys.h) and
+/// any of them can still be triggered by name through
+/// [`trigger_hotkey_by_sequence`](crate::client::General::trigger_hotkey_by_sequence).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum Key {
+    /// The `A` key.
+    #[serde(rename = "OBS_KEY_A")]
+    A,
+    /// The `B` key.
+    #[serde(rename = "OBS_KEY_B")]
+    B,
+    /// The `C` key.
+    #[serde(rename = "OBS_KEY_C")]
+    C,
+    /// The `D` key.
+    #[serde(rename = "OBS_KEY_D")]
+    D,
+    /// The `E` key.
+    #[serde(rename = "OBS_KEY_E")]
+    E,
+    /// The `F` key.
+    #[serde(rename = "OBS_KEY_F")]
+    F,
+    /// The `G` key.
+    #[serde(rename = "OBS_KEY_G")]
+    G,
+    /// The `H` key.
+    #[serde(rename = "OBS_KEY_H")]
+    H,
+    /// The `I` key.
+    #[serde(rename = "OBS_KEY_I")]
+    I,
+    /// The `J` key.
+    #[serde(rename = "OBS_KEY_J")]
+    J,
+    /// The `K` key.
+    #[serde(rename = "OBS_KEY_K")]
+    K,
+    /// The `L` key.
+    #[serde(rename = "OBS_KEY_L")]
+    L,
+    /// The `M` key.
+    #[serde(rename = "OBS_KEY_M")]
+    M,
+    /// The `N` key.
+    #[serde(rename = "OBS_KEY_N")]
+    N,
+    /// The `O` key.
+    #[serde(rename = "OBS_KEY_O")]
+    O,
+    /// The `P` key.
+    #[serde(rename = "OBS_KEY_P")]
+    P,
+    /// The `Q` key.
+    #[serde(rename = "OBS_KEY_Q")]
+    Q,
+    /// The `R` key.
+    #[serde(rename = "OBS_KEY_R")]
+    R,
+    /// The `S` key.
+    #[serde(rename = "OBS_KEY_S")]
+    S,
+    /// The `T` key.
+    #[serde(rename = "OBS_KEY_T")]
+    T,
+    /// The `U` key.
+    #[serde(rename = "OBS_KEY_U")]
+    U,
+    /// The `V` key.
+    #[serde(rename = "OBS_KEY_V")]
+    V,
+    /// The `W` key.
+    #[serde(rename = "OBS_KEY_W")]
+    W,
+    /// The `X` key.
+    #[serde(rename = "OBS_KEY_X")]
+    X,
+    /// The `Y` key.
+    #[serde(rename = "OBS_KEY_Y")]
+    Y,
+    /// The `Z` key.
+    #[serde(rename = "OBS_KEY_Z")]
+    Z,
+    /// The `0` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_0")]
+    Digit0,
+    /// The `1` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_1")]
+    Digit1,
+    /// The `2` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_2")]
+    Digit2,
+    /// The `3` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_3")]
+    Digit3,
+    /// The `4` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_4")]
+    Digit4,
+    /// The `5` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_5")]
+    Digit5,
+    /// The `6` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_6")]
+    Digit6,
+    /// The `7` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_7")]
+    Digit7,
+    /// The `8` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_8")]
+    Digit8,
+    /// The `9` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_9")]
+    Digit9,
+    /// Function key `F1`.
+    #[serde(rename = "OBS_KEY_F1")]
+    F1,
+    /// Function key `F2`.
+    #[serde(rename = "OBS_KEY_F2")]
+    F2,
+    /// Function key `F3`.
+    #[serde(rename = "OBS_KEY_F3")]
+    F3,
+    /// Function key `F4`.
+    #[serde(rename = "OBS_KEY_F4")]
+    F4,
+    /// Function key `F5`.
+    #[serde(rename = "OBS_KEY_F5")]
+    F5,
+    /// Function key `F6`.
+    #[serde(rename = "OBS_KEY_F6")]
+    F6,
+    /// Function key `F7`.
+    #[serde(rename = "OBS_KEY_F7")]
+    F7,
+    /// Function key `F8`.
+    #[serde(rename = "OBS_KEY_F8")]
+    F8,
+    /// Function key `F9`.
+    #[serde(rename = "OBS_KEY_F9")]
+    F9,
+    /// Function key `F10`.
+    #[serde(rename = "OBS_KEY_F10")]
+    F10,
+    /// Function key `F11`.
+    #[serde(rename = "OBS_KEY_F11")]
+    F11,
+    /// Function key `F12`.
+    #[serde(rename = "OBS_KEY_F12")]
+    F12,
+    /// Function key `F13`.
+    #[serde(rename = "OBS_KEY_F13")]
+    F13,
+    /// Function key `F14`.
+    #[serde(rename = "OBS_KEY_F14")]
+    F14,
+    /// Function key `F15`.
+    #[serde(rename = "OBS_KEY_F15")]
+    F15,
+    /// Function key `F16`.
+    #[serde(rename = "OBS_KEY_F16")]
+    F16,
+    /// Function key `F17`.
+    #[serde(rename = "OBS_KEY_F17")]
+    F17,
+    /// Function key `F18`.
+    #[serde(rename = "OBS_KEY_F18")]
+    F18,
+    /// Function key `F19`.
+    #[serde(rename = "OBS_KEY_F19")]
+    F19,
+    /// Function key `F20`.
+    #[serde(rename = "OBS_KEY_F20")]
+    F20,
+    /// Function key `F21`.
+    #[serde(rename = "OBS_KEY_F21")]
+    F21,
+    /// Function key `F22`.
+    #[serde(rename = "OBS_KEY_F22")]
+    F22,
+    /// Function key `F23`.
+    #[serde(rename = "OBS_KEY_F23")]
+    F23,
+    /// Function key `F24`.
+    #[serde(rename = "OBS_KEY_F24")]
+    F24,
+    /// The `0` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM0")]
+    Num0,
+    /// The `1` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM1")]
+    Num1,
+    /// The `2` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM2")]
+    Num2,
+    /// The `3` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM3")]
+    Num3,
+    /// The `4` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM4")]
+    Num4,
+    /// The `5` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM5")]
+    Num5,
+    /// The `6` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM6")]
+    Num6,
+    /// The `7` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM7")]
+    Num7,
+    /// The `8` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM8")]
+    Num8,
+    /// The `9` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUM9")]
+    Num9,
+    /// The `*` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUMASTERISK")]
+    NumAsterisk,
+    /// The `+` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUMPLUS")]
+    NumPlus,
+    /// The `-` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUMMINUS")]
+    NumMinus,
+    /// The `.` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUMPERIOD")]
+    NumPeriod,
+    /// The `/` key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_NUMSLASH")]
+    NumSlash,
+    /// The escape key.
+    #[serde(rename = "OBS_KEY_ESCAPE")]
+    Escape,
+    /// The tab key.
+    #[serde(rename = "OBS_KEY_TAB")]
+    Tab,
+    /// The backspace key.
+    #[serde(rename = "OBS_KEY_BACKSPACE")]
+    Backspace,
+    /// The return key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_RETURN")]
+    Return,
+    /// The enter key on the numeric keypad.
+    #[serde(rename = "OBS_KEY_ENTER")]
+    Enter,
+    /// The insert key.
+    #[serde(rename = "OBS_KEY_INSERT")]
+    Insert,
+    /// The delete key.
+    #[serde(rename = "OBS_KEY_DELETE")]
+    Delete,
+    /// The pause key.
+    #[serde(rename = "OBS_KEY_PAUSE")]
+    Pause,
+    /// The print screen key.
+    #[serde(rename = "OBS_KEY_PRINT")]
+    Print,
+    /// The home key.
+    #[serde(rename = "OBS_KEY_HOME")]
+    Home,
+    /// The end key.
+    #[serde(rename = "OBS_KEY_END")]
+    End,
+    /// The left arrow key.
+    #[serde(rename = "OBS_KEY_LEFT")]
+    Left,
+    /// The up arrow key.
+    #[serde(rename = "OBS_KEY_UP")]
+    Up,
+    /// The right arrow key.
+    #[serde(rename = "OBS_KEY_RIGHT")]
+    Right,
+    /// The down arrow key.
+    #[serde(rename = "OBS_KEY_DOWN")]
+    Down,
+    /// The page up key.
+    #[serde(rename = "OBS_KEY_PAGEUP")]
+    PageUp,
+    /// The page down key.
+    #[serde(rename = "OBS_KEY_PAGEDOWN")]
+    PageDown,
+    /// The caps lock key.
+    #[serde(rename = "OBS_KEY_CAPSLOCK")]
+    CapsLock,
+    /// The num lock key.
+    #[serde(rename = "OBS_KEY_NUMLOCK")]
+    NumLock,
+    /// The scroll lock key.
+    #[serde(rename = "OBS_KEY_SCROLLLOCK")]
+    ScrollLock,
+    /// The space bar.
+    #[serde(rename = "OBS_KEY_SPACE")]
+    Space,
+    /// The `-` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_MINUS")]
+    Minus,
+    /// The `+` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_PLUS")]
+    Plus,
+    /// The `,` key.
+    #[serde(rename = "OBS_KEY_COMMA")]
+    Comma,
+    /// The `.` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_PERIOD")]
+    Period,
+    /// The `/` key in the main section of the keyboard.
+    #[serde(rename = "OBS_KEY_SLASH")]
+    Slash,
+    /// The `;` key.
+    #[serde(rename = "OBS_KEY_SEMICOLON")]
+    Semicolon,
+    /// The `'` key.
+    #[serde(rename = "OBS_KEY_APOSTROPHE")]
+    Apostrophe,
+    /// The `[` key.
+    #[serde(rename = "OBS_KEY_BRACKETLEFT")]
+    BracketLeft,
+    /// The `]` key.
+    #[serde(rename = "OBS_KEY_BRACKETRIGHT")]
+    BracketRight,
+    /// The `\` key.
+    #[serde(rename = "OBS_KEY_BACKSLASH")]
+    Backslash,
+    /// The `~` key.
+    #[serde(rename = "OBS_KEY_ASCIITILDE")]
+    AsciiTilde,
+    /// The context menu key.
+    #[serde(rename = "OBS_KEY_MENU")]
+    Menu,
+    /// The media key to mute the volume.
+    #[serde(rename = "OBS_KEY_VOLUMEMUTE")]
+    VolumeMute,
+    /// The media key to lower the volume.
+    #[serde(rename = "OBS_KEY_VOLUMEDOWN")]
+    VolumeDown,
+    /// The media key to raise the volume.
+    #[serde(rename = "OBS_KEY_VOLUMEUP")]
+    VolumeUp,
+    /// The media key to play or pause.
+    #[serde(rename = "OBS_KEY_MEDIAPLAY")]
+    MediaPlay,
+    /// The media key to stop playback.
+    #[serde(rename = "OBS_KEY_MEDIASTOP")]
+    MediaStop,
+    /// The media key to skip to the previous track.
+    #[serde(rename = "OBS_KEY_MEDIAPREVIOUS")]
+    MediaPrevious,
+    /// The media key to skip to the next track.
+    #[serde(rename = "OBS_KEY_MEDIANEXT")]
+    MediaNext,
+}
+
 /// Request information for [`create_source`](crate::client::Sources::create_source).
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateSource<'a> {
     /// Source name.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Source kind, Eg. `vlc_source`.
     pub source_kind: &'a str,
     /// Scene to add the new source to.
-    pub scene_name: &'a str,
+    pub scene_name: &'a SceneName,
     /// Source settings data.
     pub source_settings: Option<&'a serde_json::Value>,
     /// Set the created SceneItem as visible or not. Defaults to true.
     pub set_visible: Option<bool>,
 }
 
-/// Request information for [`set_volume`](crate::client::Sources::set_volume).
+/// Settings of a source that also know which kind of source they belong to, used to create new
+/// sources with [`add_source`](crate::client::Scenes::add_source). As filters are sources in OBS,
+/// filter settings use this trait as well, to add filters with
+/// [`add_filter`](crate::client::Sources::add_filter).
+///
+/// [`CustomSource`] covers any source kind with arbitrary settings. With the `derive` feature, the
+/// trait can be derived for typed settings with `#[derive(SourceSettings)]`.
+pub trait SourceKindSettings: Serialize {
+    /// Internal kind of the source, for example `text_ft2_source_v2` or `image_source`.
+    fn kind(&self) -> &str;
+}
+
+/// Source settings for any source kind, as part of [`SourceKindSettings`].
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct CustomSource<'a, T> {
+    /// Internal kind of the source.
+    #[serde(skip)]
+    pub kind: &'a str,
+    /// Settings of the source.
+    pub settings: T,
+}
+
+impl<'a, T> SourceKindSettings for CustomSource<'a, T>
+where
+    T: Serialize,
+{
+    fn kind(&self) -> &str {
+        self.kind
+    }
+}
+
+/// Settings of a browser source, as part of [`SourceKindSettings`]. Fields that are `None` keep
+/// the default value of OBS.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Volume<'a> {
-    /// Source name.
-    pub source: &'a str,
-    /// Desired volume. Must be between `0.0` and `20.0` for mul, and under 26.0 for dB. OBS will
-    /// interpret dB values under -100.0 as Inf. Note: The OBS volume sliders only reach a maximum
-    /// of 1.0mul/0.0dB, however OBS actually supports larger values.
-    pub volume: f64,
-    /// Interpret `volume` data as decibels instead of amplitude/mul.
-    pub use_decibel: Option<bool>,
+pub struct BrowserSource<'a> {
+    /// Load a local file instead of the [`url`](Self::url).
+    pub is_local_file: Option<bool>,
+    /// Path of the local file to load.
+    pub local_file: Option<&'a Path>,
+    /// URL of the web page to load.
+    pub url: Option<&'a str>,
+    /// Width of the page.
+    pub width: Option<u32>,
+    /// Height of the page.
+    pub height: Option<u32>,
+    /// Use the custom [`fps`](Self::fps) instead of the frame rate of OBS.
+    pub fps_custom: Option<bool>,
+    /// Custom frame rate.
+    pub fps: Option<u16>,
+    /// Control audio via OBS. Routes the audio of the page through the audio mixer of OBS, instead
+    /// of playing it on the desktop directly.
+    pub reroute_audio: Option<bool>,
+    /// Custom CSS that is applied to the page.
+    pub css: Option<&'a str>,
+    /// Shutdown the source when not visible.
+    pub shutdown: Option<bool>,
+    /// Refresh the page when the scene becomes active.
+    pub restart_when_active: Option<bool>,
+    /// Permissions of the page to access OBS through the `window.obsstudio` JavaScript API.
+    pub webpage_control_level: Option<WebpageControlLevel>,
 }
 
-/// Request information for [`set_source_settings`](crate::client::Sources::set_source_settings).
+impl<'a> BrowserSource<'a> {
+    /// Internal kind of the browser source.
+    pub const KIND: &'static str = "browser_source";
+}
+
+impl<'a> SourceKindSettings for BrowserSource<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Permissions of a web page in a [`BrowserSource`] to access OBS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebpageControlLevel {
+    /// No access to OBS.
+    NoAccess,
+    /// Read access to the status of OBS, like whether it's streaming or recording.
+    ReadObs,
+    /// Read access to user information, like the current scene collection and scenes.
+    ReadUser,
+    /// Basic access, like saving the replay buffer.
+    Basic,
+    /// Advanced access, like switching scenes and starting the replay buffer.
+    Advanced,
+    /// Full access, like starting and stopping the stream or recording.
+    All,
+}
+
+impl Serialize for WebpageControlLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(match self {
+            Self::NoAccess => 0,
+            Self::ReadObs => 1,
+            Self::ReadUser => 2,
+            Self::Basic => 3,
+            Self::Advanced => 4,
+            Self::All => 5,
+        })
+    }
+}
+
+/// Settings of a macOS screen capture, as part of [`SourceKindSettings`]. This is the
+/// ScreenCaptureKit based source of OBS 28 and later, which replaces the older display capture on
+/// macOS. Fields that are `None` keep the default value of OBS.
 #[skip_serializing_none]
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SourceSettings<'a> {
+#[derive(Debug, Default, Serialize)]
+pub struct ScreenCapture<'a> {
+    /// What to capture. Defines which of [`display_uuid`](Self::display_uuid),
+    /// [`window`](Self::window) or [`application`](Self::application) is used.
+    #[serde(rename = "type")]
+    pub capture_type: Option<ScreenCaptureType>,
+    /// UUID of the display to capture.
+    pub display_uuid: Option<&'a str>,
+    /// ID of the window to capture.
+    pub window: Option<u32>,
+    /// Bundle identifier of the application to capture, like `com.apple.Safari`.
+    pub application: Option<&'a str>,
+    /// Whether to include the mouse cursor in the capture.
+    pub show_cursor: Option<bool>,
+    /// Whether to exclude the windows of OBS itself from the capture.
+    pub hide_obs: Option<bool>,
+}
+
+impl<'a> ScreenCapture<'a> {
+    /// Internal kind of the macOS screen capture.
+    pub const KIND: &'static str = "screen_capture";
+}
+
+impl<'a> SourceKindSettings for ScreenCapture<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Target of a [`ScreenCapture`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScreenCaptureType {
+    /// Capture a whole display.
+    Display,
+    /// Capture a single window.
+    Window,
+    /// Capture all windows of an application.
+    Application,
+}
+
+impl Serialize for ScreenCaptureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(match self {
+            Self::Display => 0,
+            Self::Window => 1,
+            Self::Application => 2,
+        })
+    }
+}
+
+/// Settings of a game capture source on Windows, as part of [`SourceKindSettings`]. Fields that
+/// are `None` keep the default value of OBS.
+///
+/// Whether the capture actually hooked into the game can be checked with
+/// [`is_capture_active`](crate::client::Sources::is_capture_active) and
+/// [`await_capture_active`](crate::client::Sources::await_capture_active).
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct GameCapture<'a> {
+    /// What to capture.
+    pub capture_mode: Option<GameCaptureMode>,
+    /// Window to capture in [`GameCaptureMode::Window`], in the form `title:class:executable`.
+    pub window: Option<&'a str>,
+    /// How the [`window`](Self::window) is matched if it can't be found exactly.
+    pub priority: Option<WindowPriority>,
+    /// SLI/Crossfire capture mode, which is slow.
+    pub sli_compatibility: Option<bool>,
+    /// Capture the mouse cursor.
+    pub capture_cursor: Option<bool>,
+    /// Keep the transparency of the captured image.
+    pub allow_transparency: Option<bool>,
+    /// Limit the capture frame rate to the frame rate of OBS.
+    pub limit_framerate: Option<bool>,
+    /// Capture third-party overlays, like the ones of Steam.
+    pub capture_overlays: Option<bool>,
+    /// Use a hook that is compatible with anti-cheat software.
+    pub anti_cheat_hook: Option<bool>,
+    /// How often OBS tries to hook into the game.
+    pub hook_rate: Option<HookRate>,
+}
+
+impl<'a> GameCapture<'a> {
+    /// Internal kind of the game capture.
+    pub const KIND: &'static str = "game_capture";
+}
+
+impl<'a> SourceKindSettings for GameCapture<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Capture mode of a [`GameCapture`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameCaptureMode {
+    /// Capture any application that runs in fullscreen.
+    AnyFullscreen,
+    /// Capture a specific window.
+    Window,
+    /// Capture the foreground window when pressing the capture hotkey.
+    Hotkey,
+}
+
+/// How a window is matched if no window with the exact title, class and executable exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WindowPriority {
+    /// Match the window title, otherwise find a window of the same type.
+    Title = 0,
+    /// Match the window class, otherwise find a window of the same type.
+    Class = 1,
+    /// Match the window class, otherwise find a window of the same executable.
+    Executable = 2,
+}
+
+impl Serialize for WindowPriority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Rate at which a [`GameCapture`] tries to hook into the game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum HookRate {
+    /// Slow, with the lowest CPU usage.
+    Slow = 0,
+    /// Normal, the default.
+    Normal = 1,
+    /// Fast.
+    Fast = 2,
+    /// Fastest, with the highest CPU usage.
+    Fastest = 3,
+}
+
+impl Serialize for HookRate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Settings of a Blackmagic Decklink capture source, as part of [`SourceKindSettings`]. Fields
+/// that are `None` keep the default value of OBS.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct DecklinkInput<'a> {
+    /// Hash that identifies the capture device.
+    pub device_hash: Option<&'a str>,
+    /// Display name of the capture device.
+    pub device_name: Option<&'a str>,
+    /// Video mode of the device as Decklink `BMDDisplayMode` value, or `-1` to detect the mode
+    /// automatically.
+    pub mode_id: Option<i64>,
+    /// Display name of the video mode.
+    pub mode_name: Option<&'a str>,
+    /// Pixel format of the captured video.
+    pub pixel_format: Option<DecklinkPixelFormat>,
+    /// Color space of the captured video.
+    pub color_space: Option<VideoColorSpace>,
+    /// Color range of the captured video.
+    pub color_range: Option<VideoColorRange>,
+    /// Layout of the captured audio channels.
+    pub channel_format: Option<SpeakerLayout>,
+    /// Swap the front center and LFE channels, for devices that order them differently.
+    #[serde(rename = "swap")]
+    pub swap_center_lfe: Option<bool>,
+    /// Buffer the captured frames, to smooth out irregular timing at the cost of latency.
+    pub buffering: Option<bool>,
+    /// Stop the capture while the source isn't visible.
+    pub deactivate_when_not_showing: Option<bool>,
+    /// Allow 10-bit pixel formats.
+    #[serde(rename = "allow_10_bit")]
+    pub allow_10bit: Option<bool>,
+}
+
+impl<'a> DecklinkInput<'a> {
+    /// Internal kind of the Decklink capture source.
+    pub const KIND: &'static str = "decklink-input";
+}
+
+impl<'a> SourceKindSettings for DecklinkInput<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Pixel format of a [`DecklinkInput`], as Decklink `BMDPixelFormat` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecklinkPixelFormat {
+    /// 8-bit YUV 4:2:2 (`2vuy`).
+    Yuv8Bit = 0x3276_7579,
+    /// 10-bit YUV 4:2:2 (`v210`).
+    Yuv10Bit = 0x7632_3130,
+    /// 8-bit BGRA (`BGRA`).
+    Bgra8Bit = 0x4247_5241,
+}
+
+impl Serialize for DecklinkPixelFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u32(*self as u32)
+    }
+}
+
+/// Settings of an AJA capture source, as part of [`SourceKindSettings`]. The video and pixel
+/// formats are the raw `NTV2VideoFormat` and `NTV2PixelFormat` values of the AJA SDK. Fields that
+/// are `None` keep the default value of OBS.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct AjaInput<'a> {
+    /// Identifier of the capture device.
+    #[serde(rename = "ui_prop_device")]
+    pub device: Option<&'a str>,
+    /// Input connector of the device to capture.
+    #[serde(rename = "ui_prop_input")]
+    pub input: Option<i64>,
+    /// Video format as `NTV2VideoFormat` value, or `-1` to detect the format automatically.
+    #[serde(rename = "ui_prop_vid_fmt")]
+    pub video_format: Option<i64>,
+    /// Pixel format as `NTV2PixelFormat` value, or `-1` to detect the format automatically.
+    #[serde(rename = "ui_prop_pix_fmt")]
+    pub pixel_format: Option<i64>,
+    /// SDI transport type as `SDITransport` value of the plugin.
+    #[serde(rename = "ui_prop_sdi_transport")]
+    pub sdi_transport: Option<i64>,
+    /// 4K SDI transport type as `SDITransport4K` value of the plugin.
+    #[serde(rename = "ui_prop_sdi_4k")]
+    pub sdi_transport_4k: Option<i64>,
+    /// Layout of the captured audio channels.
+    #[serde(rename = "ui_prop_channel_format")]
+    pub channel_format: Option<SpeakerLayout>,
+    /// Buffer the captured frames, to smooth out irregular timing at the cost of latency.
+    #[serde(rename = "ui_prop_buffering")]
+    pub buffering: Option<bool>,
+    /// Stop the capture while the source isn't visible.
+    #[serde(rename = "ui_prop_deactivate_when_not_showing")]
+    pub deactivate_when_not_showing: Option<bool>,
+}
+
+impl<'a> AjaInput<'a> {
+    /// Internal kind of the AJA capture source.
+    pub const KIND: &'static str = "aja_source";
+}
+
+impl<'a> SourceKindSettings for AjaInput<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Color space of a video capture source, like the [`DecklinkInput`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoColorSpace {
+    /// Detect the color space automatically.
+    Default = 0,
+    /// 601 color space.
+    Cs601 = 1,
+    /// 709 color space.
+    Cs709 = 2,
+}
+
+impl Serialize for VideoColorSpace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Color range of a video capture source, like the [`DecklinkInput`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoColorRange {
+    /// Detect the color range automatically.
+    Default = 0,
+    /// Partial color range.
+    Partial = 1,
+    /// Full color range.
+    Full = 2,
+}
+
+impl Serialize for VideoColorRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Layout of audio channels, used as channel mapping of capture devices like the
+/// [`DecklinkInput`] and [`AjaInput`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpeakerLayout {
+    /// No audio is captured.
+    None = 0,
+    /// Single channel.
+    Mono = 1,
+    /// Two channels.
+    Stereo = 2,
+    /// 2.1 surround (three channels).
+    TwoPointOne = 3,
+    /// 4.0 surround (four channels).
+    FourPointZero = 4,
+    /// 4.1 surround (five channels).
+    FourPointOne = 5,
+    /// 5.1 surround (six channels).
+    FivePointOne = 6,
+    /// 7.1 surround (eight channels).
+    SevenPointOne = 8,
+}
+
+impl Serialize for SpeakerLayout {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Settings of an image slideshow, as part of [`SourceKindSettings`]. Fields that are `None` keep
+/// the default value of OBS.
+#[skip_serializing_none]
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+pub struct Slideshow<'a> {
+    /// Behavior when the source becomes visible or hidden.
+    pub playback_behavior: Option<PlaybackBehavior>,
+    /// Whether slides advance automatically or only through hotkeys.
+    pub slide_mode: Option<SlideMode>,
+    /// Transition between two slides.
+    pub transition: Option<SlideTransition>,
+    /// Time each slide is shown. OBS requires at least 50 milliseconds.
+    pub slide_time: Option<MinDuration<50>>,
+    /// Duration of the transition between two slides.
+    #[serde_as(as = "Option<DurationMillis>")]
+    pub transition_speed: Option<Duration>,
+    /// Start over after the last slide.
+    #[serde(rename = "loop")]
+    pub loop_: Option<bool>,
+    /// Hide the source once the last slide was shown.
+    pub hide: Option<bool>,
+    /// Show the slides in random order.
+    pub randomize: Option<bool>,
+    /// Size of the slideshow, by default the size of the first image.
+    #[serde(rename = "use_custom_size")]
+    pub custom_size: Option<SlideshowSize>,
+    /// Images to show, either borrowed or owned, for example when collected from a directory
+    /// listing.
+    pub files: Option<Cow<'a, [SlideshowFile]>>,
+}
+
+impl<'a> Slideshow<'a> {
+    /// Internal kind of the slideshow.
+    pub const KIND: &'static str = "slideshow";
+}
+
+impl<'a> SourceKindSettings for Slideshow<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Settings of a VLC video source, as part of [`SourceKindSettings`]. Fields that are `None` keep
+/// the default value of OBS.
+#[skip_serializing_none]
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+pub struct VlcSource<'a> {
+    /// Start over after the last entry of the playlist.
+    #[serde(rename = "loop")]
+    pub loop_: Option<bool>,
+    /// Play the playlist in random order.
+    pub shuffle: Option<bool>,
+    /// Behavior when the source becomes visible or hidden.
+    pub playback_behavior: Option<PlaybackBehavior>,
+    /// Buffer size for network streams. OBS requires at least 100 milliseconds.
+    pub network_caching: Option<MinDuration<100>>,
+    /// Audio track of the media to play, starting at 1.
+    pub track: Option<u32>,
+    /// Show subtitles.
+    pub subtitle_enable: Option<bool>,
+    /// Subtitle track of the media to show, starting at 1.
+    pub subtitle: Option<u32>,
+    /// Files, directories or URLs to play, either borrowed or owned, for example when collected
+    /// from a directory listing.
+    pub playlist: Option<Cow<'a, [SlideshowFile]>>,
+}
+
+impl<'a> VlcSource<'a> {
+    /// Internal kind of the VLC video source.
+    pub const KIND: &'static str = "vlc_source";
+}
+
+impl<'a> SourceKindSettings for VlcSource<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Settings of a media source, that plays files or streams through FFmpeg, as part of
+/// [`SourceKindSettings`]. Fields that are `None` keep the default value of OBS.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct FfmpegSource<'a> {
+    /// Play the [`local_file`](Self::local_file) instead of the [`input`](Self::input).
+    pub is_local_file: Option<bool>,
+    /// Path of the local file to play.
+    pub local_file: Option<&'a Path>,
+    /// URL of the stream to play.
+    pub input: Option<&'a str>,
+    /// Format of the stream, empty to detect it automatically.
+    pub input_format: Option<&'a str>,
+    /// Start over once the end of the file is reached.
+    pub looping: Option<bool>,
+    /// Restart playback when the source becomes active.
+    pub restart_on_activate: Option<bool>,
+    /// Close the file while the source isn't active.
+    pub close_when_inactive: Option<bool>,
+    /// Clear the output once playback ended.
+    pub clear_on_media_end: Option<bool>,
+    /// Use hardware decoding if available.
+    pub hw_decode: Option<bool>,
+    /// Playback speed in percent, within `1..=200`.
+    pub speed_percent: Option<u32>,
+    /// Size of the network buffer in megabytes.
+    pub buffering_mb: Option<u32>,
+    /// Delay in seconds before reconnecting to a stream.
+    pub reconnect_delay_sec: Option<u32>,
+}
+
+impl<'a> FfmpegSource<'a> {
+    /// Internal kind of the media source.
+    pub const KIND: &'static str = "ffmpeg_source";
+}
+
+impl<'a> SourceKindSettings for FfmpegSource<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Size of a [`Slideshow`]. Images of a different size are scaled to fit, keeping their aspect
+/// ratio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlideshowSize {
+    /// Use the size of the first image.
+    Automatic,
+    /// Use a fixed size.
+    Custom {
+        /// Width in pixels.
+        width: u32,
+        /// Height in pixels.
+        height: u32,
+    },
+}
+
+impl Serialize for SlideshowSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Automatic => serializer.serialize_str("Automatic"),
+            Self::Custom { width, height } => {
+                serializer.collect_str(&format_args!("{}x{}", width, height))
+            }
+        }
+    }
+}
+
+/// Settings of an image source, as part of [`SourceKindSettings`]. Fields that are `None` keep
+/// the default value of OBS.
+///
+/// Animated GIFs are shown through the same source, with [`unload`](Self::unload) also
+/// restarting the animation each time the source becomes visible. For a sequence of images use a
+/// [`Slideshow`] instead.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct ImageSource<'a> {
+    /// Path of the image file.
+    pub file: Option<&'a Path>,
+    /// Unload the image from memory while the source is not showing.
+    pub unload: Option<bool>,
+    /// Apply the alpha channel in linear space, which gives more accurate blending of
+    /// semi-transparent images. Available since OBS 27.
+    pub linear_alpha: Option<bool>,
+}
+
+impl<'a> ImageSource<'a> {
+    /// Internal kind of the image source.
+    pub const KIND: &'static str = "image_source";
+}
+
+impl<'a> SourceKindSettings for ImageSource<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Settings of a text source that work on all platforms, as part of [`SourceKindSettings`]. They
+/// cover the common subset of the FreeType 2 source of Linux and macOS and the GDI+ source of
+/// Windows, and are sent in the format of the selected [`kind`](Self::kind). Fields that are
+/// `None` keep the default value of OBS, or their current value when updating a source with
+/// [`set_text_source`](crate::client::Sources::set_text_source).
+///
+/// ```
+/// use obws::common::Color;
+/// use obws::requests::TextSource;
+///
+/// let settings = TextSource {
+///     text: Some("Hello"),
+///     color: Some(Color::rgb(255, 0, 0)),
+///     ..TextSource::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct TextSource<'a> {
+    /// Kind of text source to create. Defaults to the native kind of the platform obws is
+    /// compiled for, see [`TextSourceKind::native`].
+    pub kind: TextSourceKind,
+    /// Text content to be displayed.
+    pub text: Option<&'a str>,
+    /// Font settings.
+    pub font: Option<&'a TextFont>,
+    /// Color of the text, including the opacity as alpha channel.
+    pub color: Option<Color>,
+    /// Draw an outline around the text.
+    pub outline: Option<bool>,
+    /// Wrap the text into multiple lines. The wrapping width is the custom width of the FreeType 2
+    /// source or the custom text extents of the GDI+ source, which must be set in OBS.
+    pub word_wrap: Option<bool>,
+}
+
+impl<'a> SourceKindSettings for TextSource<'a> {
+    fn kind(&self) -> &str {
+        self.kind.kind()
+    }
+}
+
+impl<'a> Serialize for TextSource<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct FreeType2<'a> {
+            text: Option<&'a str>,
+            font: Option<&'a TextFont>,
+            color1: Option<Color>,
+            color2: Option<Color>,
+            outline: Option<bool>,
+            word_wrap: Option<bool>,
+        }
+
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct GdiPlus<'a> {
+            text: Option<&'a str>,
+            font: Option<&'a TextFont>,
+            color: Option<Color>,
+            opacity: Option<u8>,
+            outline: Option<bool>,
+            extents_wrap: Option<bool>,
+        }
+
+        match self.kind {
+            TextSourceKind::FreeType2 => FreeType2 {
+                text: self.text,
+                font: self.font,
+                color1: self.color,
+                color2: self.color,
+                outline: self.outline,
+                word_wrap: self.word_wrap,
+            }
+            .serialize(serializer),
+            TextSourceKind::GdiPlus => GdiPlus {
+                text: self.text,
+                font: self.font,
+                color: self.color,
+                // GDI+ ignores the alpha channel and has a separate opacity in percent.
+                opacity: self
+                    .color
+                    .map(|color| (u16::from(color.a) * 100 / 255) as u8),
+                outline: self.outline,
+                extents_wrap: self.word_wrap,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+/// Kind of a [`TextSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSourceKind {
+    /// The FreeType 2 text source (`text_ft2_source_v2`), available on all platforms and the only
+    /// text source on Linux and macOS.
+    FreeType2,
+    /// The GDI+ text source (`text_gdiplus_v2`), only available on Windows.
+    GdiPlus,
+}
+
+impl TextSourceKind {
+    /// The text source OBS offers by default on the platform obws is compiled for, which is
+    /// [`GdiPlus`](Self::GdiPlus) on Windows and [`FreeType2`](Self::FreeType2) everywhere else.
+    /// If OBS runs on a different machine, the kind must be chosen explicitly.
+    pub const fn native() -> Self {
+        if cfg!(windows) {
+            Self::GdiPlus
+        } else {
+            Self::FreeType2
+        }
+    }
+
+    /// Internal kind of the source.
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::FreeType2 => "text_ft2_source_v2",
+            Self::GdiPlus => "text_gdiplus_v2",
+        }
+    }
+
+    /// Detect the text source kind from the internal kind of a source as reported by OBS, in any
+    /// version. Returns `None` if it's not a text source.
+    pub fn from_kind(kind: &str) -> Option<Self> {
+        if kind.starts_with("text_ft2_source") {
+            Some(Self::FreeType2)
+        } else if kind.starts_with("text_gdiplus") {
+            Some(Self::GdiPlus)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for TextSourceKind {
+    fn default() -> Self {
+        Self::native()
+    }
+}
+
+/// Single entry of the file list in a [`Slideshow`] or the playlist in a [`VlcSource`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SlideshowFile {
+    /// Path of the file or directory, or URL of a network stream.
+    pub value: PathBuf,
+    /// Whether the entry is skipped.
+    pub hidden: bool,
+    /// Whether the entry is selected in the UI.
+    pub selected: bool,
+}
+
+impl SlideshowFile {
+    /// Create a visible entry for the given path.
+    ///
+    /// ```
+    /// use obws::requests::SlideshowFile;
+    ///
+    /// let files = ["intro.png", "outro.png"]
+    ///     .iter()
+    ///     .map(SlideshowFile::from_path)
+    ///     .collect::<Vec<_>>();
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        Self {
+            value: path.as_ref().to_owned(),
+            hidden: false,
+            selected: false,
+        }
+    }
+}
+
+/// Behavior of a [`Slideshow`] or [`VlcSource`] when it becomes visible or hidden.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackBehavior {
+    /// Keep playing, even while hidden.
+    AlwaysPlay,
+    /// Stop when hidden and restart from the beginning when visible again.
+    StopRestart,
+    /// Pause when hidden and continue when visible again.
+    PauseUnpause,
+}
+
+/// Mode of a [`Slideshow`] to advance slides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SlideMode {
+    /// Advance automatically after the slide time.
+    #[serde(rename = "mode_auto")]
+    Automatic,
+    /// Advance only through hotkeys.
+    #[serde(rename = "mode_manual")]
+    Manual,
+}
+
+/// Transition between two slides of a [`Slideshow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlideTransition {
+    /// Switch immediately.
+    Cut,
+    /// Fade into the next slide.
+    Fade,
+    /// Swipe the next slide in.
+    Swipe,
+    /// Slide the next slide in.
+    Slide,
+}
+
+/// Settings of a VST 2.x audio filter, as part of [`SourceKindSettings`]. Fields that are `None`
+/// keep the default value of OBS.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct VstFilter<'a> {
+    /// Path to the VST plugin library.
+    pub plugin_path: Option<&'a Path>,
+    /// Base64 encoded state of the plugin, as saved by OBS. Can be copied from an existing filter
+    /// to provision the same plugin configuration again.
+    pub chunk_data: Option<&'a str>,
+    /// Hash of the [`chunk_data`](Self::chunk_data), which OBS uses to detect changes of the
+    /// plugin state. Should be copied together with the data.
+    pub chunk_hash: Option<&'a str>,
+    /// Open the interface of the plugin when the filter becomes active.
+    #[serde(rename = "open_when_active_vst_settings")]
+    pub open_interface_when_active: Option<bool>,
+}
+
+impl<'a> VstFilter<'a> {
+    /// Internal kind of the VST filter.
+    pub const KIND: &'static str = "vst_filter";
+}
+
+impl<'a> SourceKindSettings for VstFilter<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Request information for [`set_source_settings`](crate::client::Sources::set_source_settings).
+///
+/// The settings are any serializable value, like a `serde_json::Value`, typed settings or a
+/// [`RawValue`] that is passed through as-is, without parsing it first.
+#[derive(Debug)]
+pub struct SourceSettings<'a, T: ?Sized = serde_json::Value> {
     /// Source name.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Type of the specified source. Useful for type-checking to avoid settings a set of settings
     /// incompatible with the actual source's type.
     pub source_type: Option<&'a str>,
     /// Source settings (varies between source types, may require some probing around).
-    pub source_settings: &'a serde_json::Value,
+    pub source_settings: &'a T,
+}
+
+/// Request information for
+/// [`patch_source_settings`](crate::client::Sources::patch_source_settings).
+///
+/// The settings are typed settings of a source kind, like [`BrowserSource`] or [`FfmpegSource`],
+/// whose fields that are `None` aren't sent. obs-websocket merges the remaining ones into the
+/// current settings of the source, so everything else stays unchanged. The
+/// [`kind`](SourceKindSettings::kind) of the settings is sent along, so obs-websocket rejects
+/// settings for a different kind of source. [`CustomSource`] covers all other kinds.
+#[derive(Debug)]
+pub struct SourceSettingsPatch<'a, T> {
+    /// Source name.
+    pub source_name: &'a SourceName,
+    /// The settings to change.
+    pub source_settings: &'a T,
 }
 
 /// Request information for
 /// [`set_text_gdi_plus_properties`](crate::client::Sources::set_text_gdi_plus_properties).
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Serialize)]
 pub struct TextGdiPlusProperties<'a> {
     /// Name of the source.
-    pub source: &'a str,
+    pub source: &'a SourceName,
     /// Text Alignment ("left", "center", "right").
     pub align: Option<Align>,
     /// Background color.
@@ -625,16 +1941,14 @@ pub struct TextGdiPlusProperties<'a> {
 /// Request information for
 /// [`set_text_freetype2_properties`](crate::client::Sources::set_text_freetype2_properties).
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Serialize)]
 pub struct TextFreetype2Properties<'a> {
     /// Source name.
-    pub source: &'a str,
+    pub source: &'a SourceName,
     /// Gradient top color.
-    #[serde(serialize_with = "ser::rgba8_inverse_opt")]
-    pub color1: Option<RGBA8>,
+    pub color1: Option<Color>,
     /// Gradient bottom color.
-    #[serde(serialize_with = "ser::rgba8_inverse_opt")]
-    pub color2: Option<RGBA8>,
+    pub color2: Option<Color>,
     /// Custom width (0 to disable).
     pub custom_width: Option<u32>,
     /// Drop shadow.
@@ -680,7 +1994,7 @@ impl<'a> From<&'a crate::responses::TextFreetype2Properties> for TextFreetype2Pr
 #[serde(rename_all = "camelCase")]
 pub struct AddFilter<'a> {
     /// Name of the source on which the filter is added.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Name of the new filter.
     pub filter_name: &'a str,
     /// Filter type.
@@ -695,7 +2009,7 @@ pub struct AddFilter<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct ReorderFilter<'a> {
     /// Name of the source to which the filter belongs.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Name of the filter to reorder.
     pub filter_name: &'a str,
     /// Desired position of the filter in the chain.
@@ -707,7 +2021,7 @@ pub struct ReorderFilter<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct MoveFilter<'a> {
     /// Name of the source to which the filter belongs.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Name of the filter to reorder.
     pub filter_name: &'a str,
     /// How to move the filter around in the source's filter chain. Either "up", "down", "top" or
@@ -736,7 +2050,7 @@ pub enum MovementType {
 #[serde(rename_all = "camelCase")]
 pub struct SourceFilterSettings<'a> {
     /// Name of the source to which the filter belongs.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Name of the filter to reconfigure.
     pub filter_name: &'a str,
     /// New settings. These will be merged to the current filter settings.
@@ -749,7 +2063,7 @@ pub struct SourceFilterSettings<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct SourceFilterVisibility<'a> {
     /// Source name.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Source filter name.
     pub filter_name: &'a str,
     /// New filter state.
@@ -766,7 +2080,7 @@ pub struct SourceScreenshot<'a> {
     ///
     /// Note: Since scenes are also sources, you can also provide a scene name. If not provided, the
     /// currently active scene is used.
-    pub source_name: Option<&'a str>,
+    pub source_name: Option<&'a SourceName>,
     /// Format of the Data URI encoded picture. Can be "png", "jpg", "jpeg" or "bmp" (or any other
     /// value supported by Qt's Image module).
     pub embed_picture_format: Option<&'a str>,
@@ -794,8 +2108,8 @@ pub struct SourceScreenshot<'a> {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct SceneItemProperties<'a> {
-    /// Name of the scene the source item belongs to. Defaults to the current scene.
-    pub scene_name: Option<&'a str>,
+    /// Name of the scene or group the source item belongs to. Defaults to the current scene.
+    pub scene_name: Option<SceneOrGroup<'a>>,
     /// Scene Item name (if this field is a string) or specification (if it is an object).
     #[serde(with = "either::serde_untagged")]
     pub item: Either<&'a str, SceneItemSpecification<'a>>,
@@ -832,30 +2146,133 @@ impl<'a> Default for SceneItemProperties<'a> {
     }
 }
 
+/// Request information for
+/// [`set_scene_item_transform`](crate::client::SceneItems::set_scene_item_transform).
+///
+/// Builder for the transform of a scene item, where only the values that were explicitly set are
+/// sent to obs-websocket. Everything else stays unchanged.
+///
+/// ```
+/// use obws::common::{Alignment, BoundsType};
+/// use obws::requests::SceneItemTransform;
+///
+/// let transform = SceneItemTransform::new()
+///     .position(100.0, 50.0)
+///     .alignment(Alignment::TOP | Alignment::LEFT)
+///     .rotation(90.0)
+///     .bounds(BoundsType::ScaleInner, 640.0, 360.0);
+/// ```
+#[derive(Debug, Default)]
+pub struct SceneItemTransform {
+    position: Option<Position>,
+    rotation: Option<f64>,
+    scale: Option<Scale>,
+    crop: Option<Crop>,
+    bounds: Option<Bounds>,
+}
+
+impl SceneItemTransform {
+    /// Create a new, empty transform that doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the position of the scene item.
+    pub fn position(mut self, x: f64, y: f64) -> Self {
+        let position = self.position.get_or_insert_with(Position::default);
+        position.x = Some(x);
+        position.y = Some(y);
+        self
+    }
+
+    /// Set the point on the scene item that it is positioned and rotated from.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.position
+            .get_or_insert_with(Position::default)
+            .alignment = Some(alignment);
+        self
+    }
+
+    /// Set the clockwise rotation of the scene item in degrees.
+    pub fn rotation(mut self, rotation: f64) -> Self {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    /// Set the scaling factor of the scene item.
+    pub fn scale(mut self, x: f64, y: f64) -> Self {
+        self.scale = Some(Scale {
+            x: Some(x),
+            y: Some(y),
+        });
+        self
+    }
+
+    /// Set the pixel cropping of the scene item before scaling.
+    pub fn crop(mut self, crop: Crop) -> Self {
+        self.crop = Some(crop);
+        self
+    }
+
+    /// Set the bounding box of the scene item.
+    pub fn bounds(mut self, ty: BoundsType, width: f64, height: f64) -> Self {
+        let bounds = self.bounds.get_or_insert_with(Bounds::default);
+        bounds.ty = Some(ty);
+        bounds.x = Some(width);
+        bounds.y = Some(height);
+        self
+    }
+
+    /// Set the alignment of the scene item within its bounding box.
+    pub fn bounds_alignment(mut self, alignment: Alignment) -> Self {
+        self.bounds.get_or_insert_with(Bounds::default).alignment = Some(alignment);
+        self
+    }
+
+    /// Convert the transform into the full [`SceneItemProperties`] for the given scene item.
+    pub fn into_properties<'a>(
+        self,
+        scene_name: Option<SceneOrGroup<'a>>,
+        item: Either<&'a str, SceneItemSpecification<'a>>,
+    ) -> SceneItemProperties<'a> {
+        SceneItemProperties {
+            scene_name,
+            item,
+            position: self.position,
+            rotation: self.rotation,
+            scale: self.scale,
+            crop: self.crop,
+            bounds: self.bounds,
+            ..SceneItemProperties::default()
+        }
+    }
+}
+
 /// Request information for
 /// [`set_scene_item_render`](crate::client::SceneItems::set_scene_item_render).
 #[skip_serializing_none]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct SceneItemRender<'a> {
-    /// Name of the scene the scene item belongs to. Defaults to the currently active scene.
-    pub scene_name: Option<&'a str>,
+    /// Name of the scene or group the scene item belongs to. Defaults to the currently active
+    /// scene.
+    pub scene_name: Option<SceneOrGroup<'a>>,
     /// Scene Item name.
-    pub source: &'a str,
+    pub source: &'a SourceName,
     /// Scene Item id.
-    pub item: Option<i64>,
+    pub item: Option<SceneItemId>,
     /// true = shown ; false = hidden.
     pub render: bool,
 }
 
 /// Request information for [`add_scene_item`](crate::client::SceneItems::add_scene_item).
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddSceneItem<'a> {
     /// Name of the scene to create the scene item in.
-    pub scene_name: &'a str,
+    pub scene_name: &'a SceneName,
     /// Name of the source to be added.
-    pub source_name: &'a str,
+    pub source_name: &'a SourceName,
     /// Whether to make the sceneitem visible on creation or not. Default `true`.
     pub set_visible: bool,
 }
@@ -867,9 +2284,9 @@ pub struct AddSceneItem<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct DuplicateSceneItem<'a> {
     /// Name of the scene to copy the item from. Defaults to the current scene.
-    pub from_scene: Option<&'a str>,
+    pub from_scene: Option<&'a SceneName>,
     /// Name of the scene to create the item in. Defaults to the current scene.
-    pub to_scene: Option<&'a str>,
+    pub to_scene: Option<&'a SceneName>,
     /// Scene Item to duplicate from the source scene.
     pub item: SceneItemSpecification<'a>, // TODO: fields are actually not optional
 }
@@ -877,17 +2294,18 @@ pub struct DuplicateSceneItem<'a> {
 /// Request information for
 /// [`set_scene_transition_override`](crate::client::Scenes::set_scene_transition_override).
 #[skip_serializing_none]
+#[serde_as]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SceneTransitionOverride<'a> {
     /// Name of the scene to switch to.
-    pub scene_name: &'a str,
+    pub scene_name: &'a SceneName,
     /// Name of the transition to use.
     pub transition_name: &'a str,
     /// Duration in milliseconds of the transition if transition is not fixed. Defaults to the
     /// current duration specified in the UI if there is no current override and this value is not
     /// given.
-    #[serde(serialize_with = "ser::duration_millis_opt")]
+    #[serde_as(as = "Option<DurationMillis>")]
     pub transition_duration: Option<Duration>,
 }
 
@@ -903,6 +2321,30 @@ pub struct SetStreamSettings<'a> {
     pub save: bool,
 }
 
+/// Request information for
+/// [`patch_stream_settings`](crate::client::Streaming::patch_stream_settings).
+///
+/// Partial variant of [`SetStreamSettings`]. Fields that are `None` aren't sent and keep their
+/// current value, including the stream type.
+#[derive(Debug, Default)]
+pub struct StreamSettingsPatch<'a> {
+    /// The type of streaming service configuration. If it differs from the current type, all
+    /// settings are required.
+    pub ty: Option<StreamType>,
+    /// The publish URL.
+    pub server: Option<&'a str>,
+    /// The publish key of the stream.
+    pub key: Option<&'a str>,
+    /// Indicates whether authentication should be used when connecting to the streaming server.
+    pub use_auth: Option<bool>,
+    /// If authentication is enabled, the username for the streaming server.
+    pub username: Option<&'a str>,
+    /// If authentication is enabled, the password for the streaming server.
+    pub password: Option<&'a str>,
+    /// Persist the settings to disk.
+    pub save: bool,
+}
+
 /// Request information for
 /// [`set_text_gdi_plus_properties`](crate::client::Sources::set_text_gdi_plus_properties) as part
 /// of [`TextGdiPlusProperties`] and
@@ -933,6 +2375,37 @@ impl<'a> From<&'a crate::responses::Font> for Font<'a> {
     }
 }
 
+/// Identifier for the container of scene items, which can be either a scene or a group. Both are
+/// identified by their name and can be used interchangeably for the scene item functions of
+/// [`SceneItems`](crate::client::SceneItems).
+///
+/// Groups are scenes within OBS, so both variants serialize to the plain name and are identical on
+/// the wire. The variant only documents the intent at the call site. A [`SceneName`] converts into
+/// [`SceneOrGroup::Scene`].
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(untagged)]
+pub enum SceneOrGroup<'a> {
+    /// Name of a scene.
+    Scene(&'a SceneName),
+    /// Name of a group.
+    Group(&'a SceneName),
+}
+
+impl<'a> SceneOrGroup<'a> {
+    /// Name of the scene or group.
+    pub fn name(self) -> &'a SceneName {
+        match self {
+            Self::Scene(name) | Self::Group(name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a SceneName> for SceneOrGroup<'a> {
+    fn from(name: &'a SceneName) -> Self {
+        Self::Scene(name)
+    }
+}
+
 /// Request information for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties),
 /// [`set_scene_item_properties`](crate::client::SceneItems::set_scene_item_properties) as part of
@@ -941,12 +2414,12 @@ impl<'a> From<&'a crate::responses::Font> for Font<'a> {
 /// [`duplicate_scene_item`](crate::client::SceneItems::duplicate_scene_item) as part of
 /// [`DuplicateSceneItem`].
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Serialize)]
 pub struct SceneItemSpecification<'a> {
     /// Scene Item name.
     pub name: Option<&'a str>,
     /// Scene Item ID.
-    pub id: Option<i64>,
+    pub id: Option<SceneItemId>,
 }
 
 /// Request information for
@@ -1059,11 +2532,156 @@ impl From<&crate::common::Bounds> for Bounds {
 #[derive(Debug, Default, Serialize)]
 pub struct SceneItem<'a> {
     /// Id of a specific scene item. Unique on a scene by scene basis.
-    pub id: Option<i64>,
+    pub id: Option<SceneItemId>,
     /// Name of a scene item. Sufficiently unique if no scene items share sources within the scene.
     pub name: Option<&'a str>,
 }
 
+/// Request information for [`anchor`](crate::client::SceneItems::anchor), describing the corner
+/// of the canvas to place a scene item in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl From<Corner> for Alignment {
+    fn from(corner: Corner) -> Self {
+        match corner {
+            Corner::TopLeft => Self::TOP | Self::LEFT,
+            Corner::TopRight => Self::TOP | Self::RIGHT,
+            Corner::BottomLeft => Self::BOTTOM | Self::LEFT,
+            Corner::BottomRight => Self::BOTTOM | Self::RIGHT,
+        }
+    }
+}
+
+/// Request information for [`reorder_items`](crate::client::Scenes::reorder_items),
+/// [`move_to_top`](crate::client::Scenes::move_to_top),
+/// [`move_to_bottom`](crate::client::Scenes::move_to_bottom) and
+/// [`move_above`](crate::client::Scenes::move_above), referencing a scene item either by its ID
+/// or by its name.
+#[derive(Clone, Copy, Debug)]
+pub enum ItemRef<'a> {
+    /// Unique ID of the scene item within its scene.
+    ById(SceneItemId),
+    /// Name of the scene item. Only unique if no scene items share sources within the scene.
+    ByName(&'a str),
+}
+
+impl<'a> From<SceneItemId> for ItemRef<'a> {
+    fn from(id: SceneItemId) -> Self {
+        Self::ById(id)
+    }
+}
+
+impl<'a> From<i64> for ItemRef<'a> {
+    fn from(id: i64) -> Self {
+        Self::ById(SceneItemId(id))
+    }
+}
+
+impl<'a> From<&'a str> for ItemRef<'a> {
+    fn from(name: &'a str) -> Self {
+        Self::ByName(name)
+    }
+}
+
+impl<'a> From<&'a SourceName> for ItemRef<'a> {
+    fn from(name: &'a SourceName) -> Self {
+        Self::ByName(name)
+    }
+}
+
+impl<'a> From<ItemRef<'a>> for SceneItem<'a> {
+    fn from(item: ItemRef<'a>) -> Self {
+        match item {
+            ItemRef::ById(id) => Self {
+                id: Some(id),
+                name: None,
+            },
+            ItemRef::ByName(name) => Self {
+                id: None,
+                name: Some(name),
+            },
+        }
+    }
+}
+
+/// Request information for [`fade_volume_with`](crate::client::Sources::fade_volume_with).
+#[derive(Clone, Copy, Debug)]
+pub struct VolumeFade {
+    /// Time between two intermediate volume changes. Shorter steps give a smoother fade but send
+    /// more requests.
+    pub step: Duration,
+    /// Curve that the volume follows from the start to the target value.
+    pub easing: Easing,
+}
+
+impl Default for VolumeFade {
+    fn default() -> Self {
+        Self {
+            step: Duration::milliseconds(50),
+            easing: Easing::Linear,
+        }
+    }
+}
+
+/// Timing curve of a [`VolumeFade`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant rate of change.
+    Linear,
+    /// Start slow and speed up towards the end.
+    EaseIn,
+    /// Start fast and slow down towards the end.
+    EaseOut,
+    /// Start and end slow, with the fastest change in the middle.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map the linear `progress` of a fade, between `0.0` and `1.0`, onto this curve.
+    pub fn apply(self, progress: f64) -> f64 {
+        let t = progress.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+        }
+    }
+}
+
+/// Request information for
+/// [`rescale_scene_items`](crate::client::SceneItems::rescale_scene_items), describing the size of
+/// the base (canvas) resolution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CanvasSize {
+    /// Width of the canvas.
+    pub width: u64,
+    /// Height of the canvas.
+    pub height: u64,
+}
+
+impl From<&crate::responses::VideoInfo> for CanvasSize {
+    fn from(v: &crate::responses::VideoInfo) -> Self {
+        v.base_size()
+    }
+}
+
 /// Request information for [`start_streaming`](crate::client::Streaming::start_streaming).
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize)]
@@ -1105,12 +2723,13 @@ pub struct StreamSettings<'a> {
 /// Request information for
 /// [`transition_to_program`](crate::client::StudioMode::transition_to_program).
 #[skip_serializing_none]
+#[serde_as]
 #[derive(Debug, Serialize)]
 pub struct Transition<'a> {
     /// Name of the transition.
     pub name: &'a str,
     /// Transition duration (in milliseconds).
-    #[serde(serialize_with = "ser::duration_millis_opt")]
+    #[serde_as(as = "Option<DurationMillis>")]
     pub duration: Option<Duration>,
 }
 
@@ -1145,6 +2764,28 @@ pub struct QtRect {
     pub bottom: i32,
 }
 
+impl QtRect {
+    /// Create a new rectangle from its top left corner and size.
+    pub fn from_size(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            left: x,
+            top: y,
+            right: x + width,
+            bottom: y + height,
+        }
+    }
+
+    /// Width of the rectangle.
+    pub fn width(&self) -> i32 {
+        self.right - self.left
+    }
+
+    /// Height of the rectangle.
+    pub fn height(&self) -> i32 {
+        self.bottom - self.top
+    }
+}
+
 bitflags! {
     /// Request information for [`open_projector`](crate::client::General::open_projector) as part of
     /// [`Projector`].
@@ -1160,7 +2801,7 @@ bitflags! {
 
 impl QtWindowState {
     /// Convert the state into a byte array for usage in [`QtGeometry::serialize`] .
-    fn to_be_bytes(&self) -> [u8; 2] {
+    fn to_be_bytes(self) -> [u8; 2] {
         [
             if self.contains(Self::MAXIMIZED) { 1 } else { 0 },
             if self.contains(Self::FULLSCREEN) {
@@ -1200,6 +2841,36 @@ impl QtGeometry {
         }
     }
 
+    /// Create a new geometry instance from a position and size, as a starting point to further
+    /// set the screen and window state.
+    ///
+    /// ```
+    /// use obws::requests::QtGeometry;
+    ///
+    /// let geometry = QtGeometry::from_size(0, 0, 1920, 1080).screen(1).fullscreen();
+    /// ```
+    pub fn from_size(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self::new(QtRect::from_size(x, y, width, height))
+    }
+
+    /// Show the projector on the screen with the given index, starting at 0.
+    pub fn screen(mut self, screen_number: i32) -> Self {
+        self.screen_number = screen_number;
+        self
+    }
+
+    /// Show the projector in fullscreen mode, taking up the whole screen.
+    pub fn fullscreen(mut self) -> Self {
+        self.window_state |= QtWindowState::FULLSCREEN;
+        self
+    }
+
+    /// Show the projector maximized, but still with its window frame.
+    pub fn maximized(mut self) -> Self {
+        self.window_state |= QtWindowState::MAXIMIZED;
+        self
+    }
+
     /// Serialize this instance into a base64 encoded byte array.
     ///
     /// The exact format can be found in the
@@ -1264,3 +2935,64 @@ impl Default for QtGeometry {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_version() {
+        let v4_9_1 = Some(Version::new(4, 9, 1));
+
+        assert_eq!(
+            v4_9_1,
+            RequestType::GetSourceActive { source_name: "a" }.min_version()
+        );
+        assert_eq!(
+            v4_9_1,
+            RequestType::GetAudioActive { source_name: "a" }.min_version()
+        );
+        assert_eq!(
+            v4_9_1,
+            RequestType::GetAudioTracks { source_name: "a" }.min_version()
+        );
+        assert_eq!(
+            v4_9_1,
+            RequestType::SetAudioTracks {
+                source_name: "a",
+                track: 1,
+                active: true,
+            }
+            .min_version()
+        );
+        assert_eq!(None, RequestType::GetVersion.min_version());
+    }
+
+    #[test]
+    fn patch_skips_unset_fields() {
+        let patch = FfmpegSource {
+            speed_percent: Some(50),
+            ..FfmpegSource::default()
+        };
+
+        assert_eq!(
+            serde_json::json!({ "speed_percent": 50 }),
+            serde_json::to_value(&patch).unwrap()
+        );
+        assert_eq!("ffmpeg_source", patch.kind());
+    }
+
+    #[test]
+    fn vlc_network_caching_minimum() {
+        assert!(MinDuration::<100>::new(Duration::milliseconds(99)).is_err());
+
+        let settings = VlcSource {
+            network_caching: Some(MinDuration::new(Duration::milliseconds(100)).unwrap()),
+            ..VlcSource::default()
+        };
+        assert_eq!(
+            serde_json::json!({ "network_caching": 100 }),
+            serde_json::to_value(&settings).unwrap()
+        );
+    }
+}
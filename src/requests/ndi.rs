@@ -0,0 +1,110 @@
+//! Settings of the sources and outputs that the [obs-ndi] plugin adds to OBS. These are not part
+//! of OBS itself and only work if the plugin is installed.
+//!
+//! [obs-ndi]: https://github.com/Palakis/obs-ndi
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use super::SourceKindSettings;
+
+/// Name of the main output of the NDI plugin, that sends the program of OBS.
+pub const NDI_MAIN_OUTPUT: &str = "NDI Main Output";
+/// Name of the preview output of the NDI plugin, that sends the preview of OBS in studio mode.
+pub const NDI_PREVIEW_OUTPUT: &str = "NDI Preview Output";
+
+/// Settings of a NDI source, as part of [`SourceKindSettings`]. Fields that are `None` keep the
+/// default value of the plugin.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+pub struct NdiSource<'a> {
+    /// Name of the NDI sender to receive, like `MACHINE (Sender)`.
+    #[serde(rename = "ndi_source_name")]
+    pub source_name: Option<&'a str>,
+    /// Bandwidth to request from the sender.
+    #[serde(rename = "ndi_bw_mode")]
+    pub bandwidth: Option<NdiBandwidth>,
+    /// How received frames are synchronized with OBS.
+    #[serde(rename = "ndi_sync")]
+    pub sync: Option<NdiSyncMode>,
+    /// Latency of the receiver.
+    #[serde(rename = "latency")]
+    pub latency: Option<NdiLatency>,
+    /// Allow hardware acceleration for decoding.
+    #[serde(rename = "ndi_recv_hw_accel")]
+    pub hardware_acceleration: Option<bool>,
+    /// Fix the alpha blending of senders that send premultiplied alpha.
+    #[serde(rename = "ndi_fix_alpha_blending")]
+    pub fix_alpha_blending: Option<bool>,
+}
+
+impl<'a> NdiSource<'a> {
+    /// Internal kind of the NDI source.
+    pub const KIND: &'static str = "ndi_source";
+}
+
+impl<'a> SourceKindSettings for NdiSource<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Bandwidth of a [`NdiSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NdiBandwidth {
+    /// Full quality video and audio.
+    Highest = 0,
+    /// Low quality video and full audio, like for multiviewers.
+    Lowest = 1,
+    /// Audio only, without video.
+    AudioOnly = 2,
+}
+
+impl Serialize for NdiBandwidth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Synchronization of a [`NdiSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NdiSyncMode {
+    /// Use the internal clock of OBS.
+    Internal = 0,
+    /// Use the timestamps of the received frames.
+    NetworkTimestamp = 1,
+    /// Use the timecode set by the sender.
+    SourceTimecode = 2,
+}
+
+impl Serialize for NdiSyncMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// Latency of a [`NdiSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NdiLatency {
+    /// Regular buffering.
+    Normal = 0,
+    /// Reduced buffering.
+    Low = 1,
+    /// Minimal buffering, which may cause stutter on unstable networks.
+    Lowest = 2,
+}
+
+impl Serialize for NdiLatency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
@@ -1,39 +1,6 @@
-use chrono::Duration;
-use rgb::RGBA8;
-use serde::ser::{self, Serializer};
+use serde::ser::{SerializeStruct, Serializer};
 
-#[derive(Debug, thiserror::Error)]
-enum Error {
-    #[error("duration of {} days is too big to be serialized as nanoseconds", .0.num_days())]
-    DurationTooBig(Duration),
-}
-
-pub fn duration_millis_opt<S>(value: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    match value {
-        Some(duration) => serializer.serialize_some(&duration.num_milliseconds()),
-        None => serializer.serialize_none(),
-    }
-}
-
-pub fn duration_millis<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_i64(value.num_milliseconds())
-}
-
-pub fn duration_nanos<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    match value.num_nanoseconds() {
-        Some(nanos) => serializer.serialize_i64(nanos),
-        None => Err(ser::Error::custom(Error::DurationTooBig(*value))),
-    }
-}
+use super::KeyModifiers;
 
 pub fn bitflags_u8_opt<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -46,130 +13,26 @@ where
     }
 }
 
-pub fn rgba8_inverse_opt<S>(value: &Option<RGBA8>, serializer: S) -> Result<S::Ok, S::Error>
+pub fn key_modifiers<S>(value: &KeyModifiers, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    match value {
-        Some(rgba) => {
-            let abgr = (rgba.a as u32) << 24
-                | (rgba.b as u32) << 16
-                | (rgba.g as u32) << 8
-                | (rgba.r as u32);
-            serializer.serialize_some(&abgr)
-        }
-        None => serializer.serialize_none(),
-    }
+    let mut s = serializer.serialize_struct("KeyModifiers", 4)?;
+    s.serialize_field("shift", &value.contains(KeyModifiers::SHIFT))?;
+    s.serialize_field("alt", &value.contains(KeyModifiers::ALT))?;
+    s.serialize_field("control", &value.contains(KeyModifiers::CONTROL))?;
+    s.serialize_field("command", &value.contains(KeyModifiers::COMMAND))?;
+    s.end()
 }
 
 #[cfg(test)]
 mod tests {
     use bitflags::bitflags;
     use serde::Serialize;
-    use serde_test::{assert_ser_tokens, assert_ser_tokens_error, Token};
+    use serde_test::{assert_ser_tokens, Token};
 
     use super::*;
 
-    #[test]
-    fn ser_duration_millis_opt() {
-        #[derive(Serialize)]
-        struct SimpleDuration {
-            #[serde(serialize_with = "duration_millis_opt")]
-            value: Option<Duration>,
-        }
-
-        assert_ser_tokens(
-            &SimpleDuration {
-                value: Some(Duration::milliseconds(150)),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::Some,
-                Token::I64(150),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_ser_tokens(
-            &SimpleDuration { value: None },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::None,
-                Token::StructEnd,
-            ],
-        );
-    }
-
-    #[test]
-    fn ser_duration_millis() {
-        #[derive(Serialize)]
-        struct SimpleDuration {
-            #[serde(serialize_with = "duration_millis")]
-            value: Duration,
-        }
-
-        assert_ser_tokens(
-            &SimpleDuration {
-                value: Duration::milliseconds(150),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::I64(150),
-                Token::StructEnd,
-            ],
-        );
-    }
-
-    #[test]
-    fn ser_duration_nanos() {
-        #[derive(Serialize)]
-        struct SimpleDuration {
-            #[serde(serialize_with = "duration_nanos")]
-            value: Duration,
-        }
-
-        assert_ser_tokens(
-            &SimpleDuration {
-                value: Duration::nanoseconds(150),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-                Token::I64(150),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_ser_tokens_error(
-            &SimpleDuration {
-                value: Duration::days(365_000_000),
-            },
-            &[
-                Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
-                },
-                Token::Str("value"),
-            ],
-            "duration of 365000000 days is too big to be serialized as nanoseconds",
-        );
-    }
-
     #[test]
     fn ser_bitflags_u8_opt() {
         bitflags! {
@@ -222,38 +85,36 @@ mod tests {
     }
 
     #[test]
-    fn ser_rgba8_inverse_opt() {
+    fn ser_key_modifiers() {
         #[derive(Serialize)]
-        struct SimpleDuration {
-            #[serde(serialize_with = "rgba8_inverse_opt")]
-            value: Option<RGBA8>,
+        struct SimpleModifiers {
+            #[serde(serialize_with = "key_modifiers")]
+            value: KeyModifiers,
         }
 
         assert_ser_tokens(
-            &SimpleDuration {
-                value: Some(RGBA8::new(1, 2, 3, 4)),
+            &SimpleModifiers {
+                value: KeyModifiers::CONTROL | KeyModifiers::SHIFT,
             },
             &[
                 Token::Struct {
-                    name: "SimpleDuration",
+                    name: "SimpleModifiers",
                     len: 1,
                 },
                 Token::Str("value"),
-                Token::Some,
-                Token::U32(0x04030201),
-                Token::StructEnd,
-            ],
-        );
-
-        assert_ser_tokens(
-            &SimpleDuration { value: None },
-            &[
                 Token::Struct {
-                    name: "SimpleDuration",
-                    len: 1,
+                    name: "KeyModifiers",
+                    len: 4,
                 },
-                Token::Str("value"),
-                Token::None,
+                Token::Str("shift"),
+                Token::Bool(true),
+                Token::Str("alt"),
+                Token::Bool(false),
+                Token::Str("control"),
+                Token::Bool(true),
+                Token::Str("command"),
+                Token::Bool(false),
+                Token::StructEnd,
                 Token::StructEnd,
             ],
         );
@@ -1,21 +1,27 @@
 //! All responses that can be received from the API.
 
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::path::PathBuf;
 
 use chrono::Duration;
 use serde::Deserialize;
+use serde_json::value::RawValue;
+use serde_with::serde_as;
 
 pub use rgb::RGBA8;
 pub use semver::Version as SemVerVersion;
 
 use crate::common::{
-    Align, Bounds, Crop, FontFlags, MonitorType, Position, Scale, SceneItem, SceneItemTransform,
-    StreamType, Valign,
+    Align, Bounds, Color, Crop, DeviceValue, DurationMillis, DurationNanos, FontFlags, MonitorType,
+    Position, Scale, SceneItem, SceneItemId, SceneItemTransform, SceneName, SourceKind, SourceName,
+    StreamType, TrackMask, Valign,
 };
+use crate::requests::CanvasSize;
 
 mod de;
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub(crate) struct Response<T> {
@@ -26,6 +32,41 @@ pub(crate) struct Response<T> {
     pub details: T,
 }
 
+/// A response as it was received from obs-websocket. Borrowed variants of responses, like
+/// [`SourceSettingsRef`] or [`SceneItemListRef`], can be decoded from it with
+/// [`parse`](Self::parse). They point into the received text instead of allocating new strings,
+/// and can be turned into the owned responses with their `into_owned` function.
+#[derive(Clone, Debug)]
+pub struct RawResponse(pub(crate) String);
+
+impl RawResponse {
+    /// The full response message as JSON text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Decode the response into `T`, which may borrow from the received text.
+    pub fn parse<'a, T>(&'a self) -> crate::Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_json::from_str(&self.0).map_err(crate::Error::DeserializeResponse)
+    }
+}
+
+/// Minimal view on any incoming message, borrowing from the raw text. It allows to route messages
+/// and detect errors without decoding the full message twice.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct MessageHeader<'a> {
+    #[serde(borrow)]
+    pub message_id: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub error: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub update_type: Option<Cow<'a, str>>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub(crate) enum Status {
@@ -105,12 +146,59 @@ pub struct VideoInfo {
     pub color_range: ColorRange,
 }
 
+impl VideoInfo {
+    /// Size of the base (canvas) resolution, that scene items are positioned in.
+    pub fn base_size(&self) -> CanvasSize {
+        CanvasSize {
+            width: self.base_width,
+            height: self.base_height,
+        }
+    }
+
+    /// Size of the output resolution, that the canvas is scaled to for streaming and recording.
+    pub fn output_size(&self) -> CanvasSize {
+        CanvasSize {
+            width: self.output_width,
+            height: self.output_height,
+        }
+    }
+
+    /// Horizontal and vertical factor that the canvas is scaled by for the output.
+    pub fn output_scale(&self) -> (f64, f64) {
+        (
+            self.output_width as f64 / self.base_width as f64,
+            self.output_height as f64 / self.base_height as f64,
+        )
+    }
+
+    /// Convert a point on the canvas to the matching point in the output.
+    pub fn base_to_output(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sx, sy) = self.output_scale();
+        (x * sx, y * sy)
+    }
+
+    /// Convert a point in the output to the matching point on the canvas, for example to position
+    /// a scene item at coordinates measured in a recording.
+    pub fn output_to_base(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sx, sy) = self.output_scale();
+        (x / sx, y / sy)
+    }
+
+    /// Time between two rendered frames.
+    pub fn frame_interval(&self) -> Duration {
+        Duration::nanoseconds((1_000_000_000.0 / self.fps).round() as i64)
+    }
+}
+
 /// Possible scaling types for the output.
 ///
 /// Response value for [`get_video_info`](crate::client::General::get_video_info) as part of
 /// [`VideoInfo`].
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum ScaleType {
+    /// Nearest neighbor scaling.
+    #[serde(rename = "VIDEO_SCALE_POINT")]
+    Point,
     /// Fastest, but blurry scaling.
     #[serde(rename = "VIDEO_SCALE_BILINEAR")]
     Bilinear,
@@ -123,13 +211,16 @@ pub enum ScaleType {
     /// Sharpened scaling, 36 samples.
     #[serde(rename = "VIDEO_SCALE_BICUBIC")]
     Lanczos,
+    /// Scaling type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Supported formats for video output.
 ///
 /// Response value for [`get_video_info`](crate::client::General::get_video_info) as part of
 /// [`VideoInfo`].
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum VideoFormat {
     /// NV12 format.
     #[serde(rename = "VIDEO_FORMAT_NV12")]
@@ -143,13 +234,16 @@ pub enum VideoFormat {
     /// RGBA format.
     #[serde(rename = "VIDEO_FORMAT_RGBA")]
     Rgba,
+    /// Video format not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Supported color spaces for video output.
 ///
 /// Response value for [`get_video_info`](crate::client::General::get_video_info) as part of
 /// [`VideoInfo`].
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum ColorSpace {
     /// 709 color space.
     #[serde(rename = "VIDEO_CS_709")]
@@ -160,13 +254,16 @@ pub enum ColorSpace {
     /// sRGB color space.
     #[serde(rename = "VIDEO_CS_DEFAULT")]
     CsSRgb,
+    /// Color space not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Supported color ranges for video output.
 ///
 /// Response value for [`get_video_info`](crate::client::General::get_video_info) as part of
 /// [`VideoInfo`].
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum ColorRange {
     /// Partial color range.
     #[serde(rename = "VIDEO_RANGE_PARTIAL")]
@@ -174,22 +271,27 @@ pub enum ColorRange {
     /// Full range.
     #[serde(rename = "VIDEO_RANGE_FULL")]
     Full,
+    /// Color range not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Response value for [`get_media_duration`](crate::client::MediaControl::get_media_duration).
+#[serde_as]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct MediaDuration {
     /// The total length of media in milliseconds.
-    #[serde(deserialize_with = "crate::de::duration_millis")]
+    #[serde_as(as = "DurationMillis")]
     pub media_duration: Duration,
 }
 
 /// Response value for [`get_media_time`](crate::client::MediaControl::get_media_time).
+#[serde_as]
 #[derive(Debug, Deserialize)]
 pub(crate) struct MediaTime {
     /// The time in milliseconds since the start of the media.
-    #[serde(deserialize_with = "crate::de::duration_millis")]
+    #[serde_as(as = "DurationMillis")]
     pub timestamp: Duration,
 }
 
@@ -239,7 +341,7 @@ pub(crate) struct MediaSourcesList {
 #[serde(rename_all = "camelCase")]
 pub struct MediaSource {
     /// Unique source name.
-    pub source_name: String,
+    pub source_name: SourceName,
     /// Unique source internal type (a.k.a `ffmpeg_source` or `vlc_source`).
     pub source_kind: String,
     /// The current state of media for that source.
@@ -251,7 +353,7 @@ pub struct MediaSource {
 #[serde(rename_all = "camelCase")]
 pub(crate) struct SourceItemId {
     /// ID of the SceneItem in the scene.
-    pub item_id: i64,
+    pub item_id: SceneItemId,
 }
 
 /// Response value for [`get_sources_list`](crate::client::Sources::get_sources_list).
@@ -272,9 +374,10 @@ pub(crate) struct SourceTypesList {
 #[derive(Debug, Deserialize)]
 pub struct Volume {
     /// Source name.
-    pub name: String,
-    /// Volume of the source. Between `0.0` and `20.0` if using mul, under `26.0` if using dB.
-    pub volume: f64,
+    pub name: SourceName,
+    /// Volume of the source.
+    #[serde(deserialize_with = "crate::de::volume_mul")]
+    pub volume: crate::common::Volume,
     /// Indicates whether the source is muted.
     pub muted: bool,
 }
@@ -283,11 +386,19 @@ pub struct Volume {
 #[derive(Debug, Deserialize)]
 pub struct Mute {
     /// Source name.
-    pub name: String,
+    pub name: SourceName,
     /// Mute status of the source.
     pub muted: bool,
 }
 
+/// Response value for [`get_source_active`](crate::client::Sources::get_source_active).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SourceActive {
+    /// Source active status of the source.
+    pub source_active: bool,
+}
+
 /// Response value for [`get_audio_active`](crate::client::Sources::get_audio_active).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -297,12 +408,13 @@ pub(crate) struct AudioActive {
 }
 
 /// Response value for [`get_sync_offset`](crate::client::Sources::get_sync_offset).
+#[serde_as]
 #[derive(Debug, Deserialize)]
 pub struct SyncOffset {
     /// Source name.
-    pub name: String,
+    pub name: SourceName,
     /// The audio sync offset (in nanoseconds).
-    #[serde(deserialize_with = "crate::de::duration_nanos")]
+    #[serde_as(as = "DurationNanos")]
     pub offset: Duration,
 }
 
@@ -312,19 +424,55 @@ pub struct SyncOffset {
 #[serde(rename_all = "camelCase")]
 pub struct SourceSettings<T> {
     /// Source name.
-    pub source_name: String,
+    pub source_name: SourceName,
     /// Type of the specified source.
     pub source_type: String,
     /// Source settings (varies between source types, may require some probing around).
     pub source_settings: T,
 }
 
+/// Borrowed variant of [`SourceSettings`], decoded from a [`RawResponse`] of
+/// [`get_source_settings_raw`](crate::client::Sources::get_source_settings_raw). Names point into
+/// the received text where possible and the settings are kept as raw JSON.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceSettingsRef<'a> {
+    /// Source name.
+    #[serde(borrow)]
+    pub source_name: Cow<'a, str>,
+    /// Type of the specified source.
+    #[serde(borrow)]
+    pub source_type: Cow<'a, str>,
+    /// Source settings (varies between source types, may require some probing around).
+    #[serde(borrow)]
+    pub source_settings: &'a RawValue,
+}
+
+impl<'a> SourceSettingsRef<'a> {
+    /// Deserialize the settings into `T`, which may borrow from the response as well.
+    pub fn settings<T>(&self) -> crate::Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_json::from_str(self.source_settings.get()).map_err(crate::Error::DeserializeResponse)
+    }
+
+    /// Convert into the owned [`SourceSettings`], keeping the settings as raw JSON.
+    pub fn into_owned(self) -> SourceSettings<Box<RawValue>> {
+        SourceSettings {
+            source_name: self.source_name.into_owned().into(),
+            source_type: self.source_type.into_owned(),
+            source_settings: self.source_settings.to_owned(),
+        }
+    }
+}
+
 /// Response value for
 /// [`get_text_gdi_plus_properties`](crate::client::Sources::get_text_gdi_plus_properties).
 #[derive(Debug, Deserialize)]
 pub struct TextGdiPlusProperties {
     /// Source name.
-    pub source: String,
+    pub source: SourceName,
     /// Text Alignment ("left", "center", "right").
     pub align: Align,
     /// Background color.
@@ -379,13 +527,13 @@ pub struct TextGdiPlusProperties {
 #[derive(Debug, Deserialize)]
 pub struct TextFreetype2Properties {
     /// Source name.
-    pub source: String,
+    pub source: SourceName,
     /// Gradient top color.
-    #[serde(default, deserialize_with = "de::rgba8_inverse_opt")]
-    pub color1: Option<RGBA8>,
+    #[serde(default)]
+    pub color1: Option<Color>,
     /// Gradient bottom color.
-    #[serde(default, deserialize_with = "de::rgba8_inverse_opt")]
-    pub color2: Option<RGBA8>,
+    #[serde(default)]
+    pub color2: Option<Color>,
     /// Custom width (0 to disable).
     pub custom_width: Option<u32>,
     /// Drop shadow.
@@ -428,6 +576,23 @@ pub struct SpecialSources {
     pub mic_3: Option<String>,
 }
 
+/// Response value for
+/// [`resolve_special_sources`](crate::client::Sources::resolve_special_sources), with the full
+/// information of each configured special source.
+#[derive(Clone, Debug)]
+pub struct SpecialSourcesInfo {
+    /// The first Desktop Audio capture source.
+    pub desktop_1: Option<SourceListItem>,
+    /// The second Desktop Audio capture source.
+    pub desktop_2: Option<SourceListItem>,
+    /// The first Mic/Aux input source.
+    pub mic_1: Option<SourceListItem>,
+    /// The second Mic/Aux input source.
+    pub mic_2: Option<SourceListItem>,
+    /// The third Mic/Aux input source.
+    pub mic_3: Option<SourceListItem>,
+}
+
 /// Response value for [`get_source_filters`](crate::client::Sources::get_source_filters).
 #[derive(Debug, Deserialize)]
 pub(crate) struct SourceFilters {
@@ -435,7 +600,15 @@ pub(crate) struct SourceFilters {
     pub filters: Vec<SourceFilter>,
 }
 
-/// Response value for [`get_source_filter_info`](crate::client::Sources::get_source_filter_info).
+/// Response value for [`get_source_filters_as`](crate::client::Sources::get_source_filters_as).
+#[derive(Debug, Deserialize)]
+pub(crate) struct TypedSourceFilters<T> {
+    /// List of filters for the specified source.
+    pub filters: Vec<SourceFilterInfo<T>>,
+}
+
+/// Response value for [`get_source_filter_info`](crate::client::Sources::get_source_filter_info)
+/// and [`get_source_filters_as`](crate::client::Sources::get_source_filters_as).
 #[derive(Debug, Deserialize)]
 pub struct SourceFilterInfo<T> {
     /// Filter status (enabled or not).
@@ -449,11 +622,46 @@ pub struct SourceFilterInfo<T> {
     pub settings: T,
 }
 
+/// Response value for [`get_audio_tracks`](crate::client::Sources::get_audio_tracks).
+#[derive(Debug, Deserialize)]
+pub(crate) struct AudioTracks {
+    /// Whether the first audio track is active.
+    pub track1: bool,
+    /// Whether the second audio track is active.
+    pub track2: bool,
+    /// Whether the third audio track is active.
+    pub track3: bool,
+    /// Whether the fourth audio track is active.
+    pub track4: bool,
+    /// Whether the fifth audio track is active.
+    pub track5: bool,
+    /// Whether the sixth audio track is active.
+    pub track6: bool,
+}
+
+impl From<AudioTracks> for TrackMask {
+    fn from(tracks: AudioTracks) -> Self {
+        [
+            tracks.track1,
+            tracks.track2,
+            tracks.track3,
+            tracks.track4,
+            tracks.track5,
+            tracks.track6,
+        ]
+        .iter()
+        .zip(1..)
+        .filter(|(active, _)| **active)
+        .filter_map(|(_, number)| Self::track(number))
+        .collect()
+    }
+}
+
 /// Response value for [`get_audio_monitor_type`](crate::client::Sources::get_audio_monitor_type).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AudioMonitorType {
-    /// The monitor type in use. Options: `none`, `monitorOnly`, `monitorAndOutput`.
+    /// The monitor type in use.
     pub monitor_type: MonitorType,
 }
 
@@ -461,11 +669,11 @@ pub(crate) struct AudioMonitorType {
 /// [`get_source_default_settings`](crate::client::Sources::get_source_default_settings).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SourceDefaultSettings {
+pub struct SourceDefaultSettings<T> {
     /// Source kind. Same value as the `source_kind` parameter.
     pub source_kind: String,
     /// Settings object for source.
-    pub default_settings: serde_json::Value,
+    pub default_settings: T,
 }
 
 /// Response value for [`take_source_screenshot`](crate::client::Sources::take_source_screenshot).
@@ -473,7 +681,7 @@ pub struct SourceDefaultSettings {
 #[serde(rename_all = "camelCase")]
 pub struct SourceScreenshot {
     /// Source name.
-    pub source_name: String,
+    pub source_name: SourceName,
     /// Image Data URI (if
     /// [`embed_picture_format`](crate::requests::SourceScreenshot::embed_picture_format) was
     /// specified in the request).
@@ -570,7 +778,7 @@ pub(crate) struct SceneCollections {
 #[serde(rename_all = "camelCase")]
 pub struct SceneItemList {
     /// Name of the requested (or current) scene.
-    pub scene_name: String,
+    pub scene_name: SceneName,
     /// Array of scene items.
     pub scene_items: Vec<SceneItemListItem>,
 }
@@ -582,24 +790,80 @@ pub struct SceneItemList {
 #[serde(rename_all = "camelCase")]
 pub struct SceneItemListItem {
     /// Unique item id of the source item
-    pub item_id: i64,
+    pub item_id: SceneItemId,
     /// ID if the scene item's source. For example `vlc_source` or `image_source`.
     pub source_kind: String,
     /// Name of the scene item's source.
-    pub source_name: String,
+    pub source_name: SourceName,
     /// Type of the scene item's source. Either `input`, `group`, or `scene`.
     pub source_type: String,
 }
 
+/// Borrowed variant of [`SceneItemList`], decoded from a [`RawResponse`] of
+/// [`get_scene_item_list_raw`](crate::client::SceneItems::get_scene_item_list_raw).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneItemListRef<'a> {
+    /// Name of the requested (or current) scene.
+    #[serde(borrow)]
+    pub scene_name: Cow<'a, str>,
+    /// Array of scene items.
+    #[serde(borrow)]
+    pub scene_items: Vec<SceneItemListItemRef<'a>>,
+}
+
+impl<'a> SceneItemListRef<'a> {
+    /// Convert into the owned [`SceneItemList`].
+    pub fn into_owned(self) -> SceneItemList {
+        SceneItemList {
+            scene_name: self.scene_name.into_owned().into(),
+            scene_items: self
+                .scene_items
+                .into_iter()
+                .map(SceneItemListItemRef::into_owned)
+                .collect(),
+        }
+    }
+}
+
+/// Borrowed variant of [`SceneItemListItem`] as part of [`SceneItemListRef`].
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneItemListItemRef<'a> {
+    /// Unique item id of the source item
+    pub item_id: SceneItemId,
+    /// ID if the scene item's source. For example `vlc_source` or `image_source`.
+    #[serde(borrow)]
+    pub source_kind: Cow<'a, str>,
+    /// Name of the scene item's source.
+    #[serde(borrow)]
+    pub source_name: Cow<'a, str>,
+    /// Type of the scene item's source. Either `input`, `group`, or `scene`.
+    #[serde(borrow)]
+    pub source_type: Cow<'a, str>,
+}
+
+impl<'a> SceneItemListItemRef<'a> {
+    /// Convert into the owned [`SceneItemListItem`].
+    pub fn into_owned(self) -> SceneItemListItem {
+        SceneItemListItem {
+            item_id: self.item_id,
+            source_kind: self.source_kind.into_owned(),
+            source_name: self.source_name.into_owned().into(),
+            source_type: self.source_type.into_owned(),
+        }
+    }
+}
+
 /// Response value for
 /// [`get_scene_item_properties`](crate::client::SceneItems::get_scene_item_properties).
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SceneItemProperties {
     /// Scene Item name.
-    pub name: String,
+    pub name: SourceName,
     /// Scene Item ID.
-    pub item_id: i64,
+    pub item_id: SceneItemId,
     /// Position of the source.
     pub position: Position,
     /// The clockwise rotation of the item in degrees around the point of alignment.
@@ -625,27 +889,35 @@ pub struct SceneItemProperties {
     pub width: f64,
     /// Scene item height (base source height multiplied by the vertical scaling factor).
     pub height: f64,
-    // pub alignment: u8,
     /// Name of the item's parent (if this item belongs to a group).
-    pub parent_group_name: Option<String>,
-    /// List of children (if this item is a group).
+    pub parent_group_name: Option<SceneName>,
+    /// List of children (if this item is a group), with their own children in turn if they are
+    /// groups as well. obs-websocket doesn't report the names and IDs of children, use
+    /// [`get_group_children`](crate::client::Scenes::get_group_children) to get them.
     #[serde(default)]
     pub group_children: Vec<SceneItemTransform>,
 }
 
+impl SceneItemProperties {
+    /// Whether this item is a group, that is, whether it has any children.
+    pub fn is_group(&self) -> bool {
+        !self.group_children.is_empty()
+    }
+}
+
 /// Response value for [`add_scene_item`](crate::client::SceneItems::add_scene_item).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct SceneItemId {
+pub(crate) struct NewSceneItem {
     /// Numerical ID of the created scene item.
-    pub item_id: i64,
+    pub item_id: SceneItemId,
 }
 
 /// Response value for [`duplicate_scene_item`](crate::client::SceneItems::duplicate_scene_item).
 #[derive(Debug, Deserialize)]
 pub struct DuplicateSceneItem {
     /// Name of the scene where the new item was created.
-    pub scene: String,
+    pub scene: SceneName,
     /// New item info.
     pub item: SceneItemSpecification,
 }
@@ -654,7 +926,7 @@ pub struct DuplicateSceneItem {
 #[derive(Debug, Deserialize)]
 pub struct CurrentScene {
     /// Name of the currently active scene.
-    pub name: String,
+    pub name: SceneName,
     /// Ordered list of the current scene's source items.
     pub sources: Vec<SceneItem>,
 }
@@ -664,11 +936,42 @@ pub struct CurrentScene {
 #[serde(rename_all = "kebab-case")]
 pub struct SceneList {
     /// Name of the currently active scene.
-    pub current_scene: String,
+    pub current_scene: SceneName,
     /// Ordered list of the current profile's scenes.
     pub scenes: Vec<Scene>,
 }
 
+/// Response value for [`snapshot`](crate::client::Scenes::snapshot).
+#[derive(Debug)]
+pub struct SceneGraph {
+    /// Name of the currently active scene.
+    pub current_scene: SceneName,
+    /// Ordered list of the current profile's scenes, including all their items.
+    pub scenes: Vec<SceneNode>,
+}
+
+/// Response value for [`snapshot`](crate::client::Scenes::snapshot) as part of [`SceneGraph`].
+#[derive(Debug)]
+pub struct SceneNode {
+    /// Name of the scene.
+    pub name: SceneName,
+    /// Ordered list of the scene's items.
+    pub items: Vec<SceneItemNode>,
+}
+
+/// Response value for [`snapshot`](crate::client::Scenes::snapshot) as part of [`SceneNode`].
+#[derive(Debug)]
+pub struct SceneItemNode {
+    /// ID of the scene item's source. For example `vlc_source` or `image_source`.
+    pub source_kind: String,
+    /// Type of the scene item's source. Either `input`, `group`, or `scene`.
+    pub source_type: String,
+    /// All scene specific properties of the item.
+    pub properties: SceneItemProperties,
+    /// Ordered list of the items in this group. Always empty if the item is not a group.
+    pub children: Vec<SceneItemNode>,
+}
+
 /// Response value for
 /// [`get_scene_transition_override`](crate::client::Scenes::get_scene_transition_override).
 #[derive(Debug, Deserialize)]
@@ -725,7 +1028,7 @@ pub(crate) struct StudioModeStatus {
 #[derive(Debug, Deserialize)]
 pub struct PreviewScene {
     /// The name of the active preview scene.
-    pub name: String,
+    pub name: SceneName,
     /// Array of scene items of the active preview scene.
     pub sources: Vec<SceneItem>,
 }
@@ -753,11 +1056,12 @@ pub struct CurrentTransition {
 
 /// Response value for
 /// [`get_transition_duration`](crate::client::Transitions::get_transition_duration).
+#[serde_as]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub(crate) struct TransitionDuration {
     /// Duration of the current transition (in milliseconds).
-    #[serde(deserialize_with = "crate::de::duration_millis")]
+    #[serde_as(as = "DurationMillis")]
     pub transition_duration: Duration,
 }
 
@@ -806,24 +1110,35 @@ pub struct ObsStats {
 }
 
 /// Response value for [`get_sources_list`](crate::client::Sources::get_sources_list).
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceListItem {
     /// Unique source name.
-    pub name: String,
+    pub name: SourceName,
     /// Non-unique source internal type (a.k.a kind).
-    pub type_id: String,
+    pub type_id: SourceKind,
     /// Source type.
     #[serde(rename = "type")]
     pub ty: String,
 }
 
+/// Response value for [`get_device_candidates`](crate::client::Sources::get_device_candidates).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceCandidate {
+    /// Value of the device setting, that can be used in the settings of a new source.
+    pub value: DeviceValue,
+    /// Whether this is the default value of the source kind.
+    pub is_default: bool,
+    /// Existing sources that capture this device.
+    pub sources: Vec<SourceName>,
+}
+
 /// Response value for [`get_sources_types_list`](crate::client::Sources::get_sources_types_list).
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceTypeItem {
     /// Non-unique internal source type ID.
-    pub type_id: String,
+    pub type_id: SourceKind,
     /// Display name of the source type.
     pub display_name: String,
     /// Type. Value is one of the following: "input", "filter", "transition" or "other".
@@ -851,7 +1166,7 @@ pub enum SourceType {
 
 /// Response value for [`get_sources_types_list`](crate::client::Sources::get_sources_types_list) as
 /// part of [`SourceTypeItem`].
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Caps {
     /// True if source of this type provide frames asynchronously.
@@ -976,9 +1291,9 @@ pub struct SceneCollection {
 #[derive(Debug, Deserialize)]
 pub struct SceneItemSpecification {
     /// New item ID.
-    pub id: i64,
+    pub id: SceneItemId,
     /// New item name.
-    pub name: String,
+    pub name: SourceName,
 }
 
 /// Response value for [`get_scene_list`](crate::client::Scenes::get_scene_list) as part of
@@ -987,7 +1302,7 @@ pub struct SceneItemSpecification {
 #[derive(Clone, Debug, Deserialize)]
 pub struct Scene {
     /// Name of the scene.
-    pub name: String,
+    pub name: SceneName,
     /// Ordered list of the scene's source items.
     #[serde(default)]
     pub sources: Vec<SceneItem>,
@@ -1018,3 +1333,112 @@ pub struct Transition {
     /// Name of the transition.
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::{Alignment, BoundsType};
+
+    fn transform() -> serde_json::Value {
+        json!({
+            "position": { "x": 10.0, "y": 20.0, "alignment": 5 },
+            "rotation": 0.0,
+            "scale": { "x": 1.0, "y": 1.0 },
+            "crop": { "top": 0, "right": 4, "bottom": 0, "left": 2 },
+            "visible": true,
+            "muted": false,
+            "locked": false,
+            "bounds": { "type": "OBS_BOUNDS_SCALE_INNER", "alignment": 0, "x": 0.0, "y": 0.0 },
+            "sourceWidth": 1920,
+            "sourceHeight": 1080,
+            "width": 1920.0,
+            "height": 1080.0,
+        })
+    }
+
+    #[test]
+    fn scene_item_properties_with_children() {
+        let mut inner = transform();
+        inner["parentGroupName"] = json!("Nested");
+        let mut nested = transform();
+        nested["parentGroupName"] = json!("Outer");
+        nested["groupChildren"] = json!([inner]);
+        let mut props = transform();
+        props["name"] = json!("Outer");
+        props["itemId"] = json!(3);
+        props["groupChildren"] = json!([nested]);
+
+        let props = serde_json::from_value::<SceneItemProperties>(props).unwrap();
+
+        assert!(props.is_group());
+        assert_eq!(Alignment::LEFT | Alignment::TOP, props.position.alignment);
+        assert_eq!(BoundsType::ScaleInner, props.bounds.ty);
+        assert_eq!(4, props.crop.right);
+
+        let nested = &props.group_children[0];
+        assert!(nested.is_group());
+        assert_eq!(Some("Outer"), nested.parent_group_name.as_deref());
+        assert_eq!(
+            Some("Nested"),
+            nested.group_children[0].parent_group_name.as_deref()
+        );
+        assert!(!nested.group_children[0].is_group());
+    }
+
+    #[test]
+    fn borrowed_source_settings() {
+        let raw = RawResponse(
+            json!({
+                "message-id": "1",
+                "status": "ok",
+                "sourceName": "Text",
+                "sourceType": "text_ft2_source_v2",
+                "sourceSettings": { "text": "hello" },
+            })
+            .to_string(),
+        );
+
+        let settings = raw.parse::<SourceSettingsRef<'_>>().unwrap();
+        assert!(matches!(settings.source_name, Cow::Borrowed("Text")));
+        assert_eq!(
+            "hello",
+            settings.settings::<HashMap<&str, &str>>().unwrap()["text"]
+        );
+
+        let settings = settings.into_owned();
+        assert_eq!("Text", settings.source_name.as_str());
+        assert_eq!(r#"{"text":"hello"}"#, settings.source_settings.get());
+    }
+
+    #[test]
+    fn borrowed_scene_item_list() {
+        let raw = RawResponse(
+            json!({
+                "message-id": "2",
+                "status": "ok",
+                "sceneName": "Scene",
+                "sceneItems": [{
+                    "itemId": 4,
+                    "sourceKind": "image_source",
+                    "sourceName": "Logo",
+                    "sourceType": "input",
+                }],
+            })
+            .to_string(),
+        );
+
+        let list = raw.parse::<SceneItemListRef<'_>>().unwrap();
+        assert!(matches!(
+            list.scene_items[0].source_name,
+            Cow::Borrowed("Logo")
+        ));
+
+        let list = list.into_owned();
+        assert_eq!("Scene", list.scene_name.as_str());
+        assert_eq!(SceneItemId(4), list.scene_items[0].item_id);
+    }
+}
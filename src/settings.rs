@@ -0,0 +1,193 @@
+//! Helpers to work with the JSON settings of sources, like combining user defined values with the
+//! defaults of a source kind or finding the minimal change between two sets of settings.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+/// Overlay the given `settings` onto the `defaults` of a source kind, so only the fields that were
+/// set explicitly are changed, and all others keep the value that OBS would pick.
+///
+/// Nested objects are merged recursively. Fields that serialize to `null`, like unset [`Option`]
+/// fields, are skipped and keep their default value.
+///
+/// ```
+/// use obws::settings;
+/// use serde_json::json;
+///
+/// let defaults = json!({ "url": "https://obsproject.com", "width": 800, "height": 600 });
+/// let merged = settings::merge(defaults, &json!({ "width": 1920, "height": null }))?;
+///
+/// assert_eq!(
+///     merged,
+///     json!({ "url": "https://obsproject.com", "width": 1920, "height": 600 })
+/// );
+/// # Ok::<(), obws::Error>(())
+/// ```
+pub fn merge<T>(defaults: Value, settings: &T) -> Result<Value>
+where
+    T: Serialize,
+{
+    let settings = serde_json::to_value(settings).map_err(Error::SerializeCustomData)?;
+    let mut merged = defaults;
+    merge_value(&mut merged, settings);
+    Ok(merged)
+}
+
+/// Compare the `current` settings of a source with the `desired` ones and create the minimal patch
+/// that turns the former into the latter, to be sent with
+/// [`set_source_settings`](crate::client::Sources::set_source_settings).
+///
+/// Changing the settings of a source makes OBS re-initialize it, like browser sources reloading
+/// their page. Sending only the actual differences, or nothing at all if there are none, avoids
+/// this where possible.
+///
+/// Only fields present in `desired` are compared, fields that serialize to `null` are ignored.
+/// Numbers are compared by value, so `1` and `1.0` are considered equal. OBS replaces nested
+/// objects as a whole, so a changed nested object is part of the patch with all its fields, based
+/// on the current value.
+///
+/// Returns `None` if the settings already match.
+///
+/// ```
+/// use obws::settings;
+/// use serde_json::json;
+///
+/// let current = json!({ "url": "https://obsproject.com", "width": 800, "height": 600 });
+/// let desired = json!({ "url": "https://obsproject.com", "width": 1920.0 });
+/// let patch = settings::diff(&current, &desired)?;
+///
+/// assert_eq!(patch, Some(json!({ "width": 1920.0 })));
+/// # Ok::<(), obws::Error>(())
+/// ```
+pub fn diff<T>(current: &Value, desired: &T) -> Result<Option<Value>>
+where
+    T: Serialize,
+{
+    let desired = serde_json::to_value(desired).map_err(Error::SerializeCustomData)?;
+
+    let patch = match (current, desired) {
+        (Value::Object(current), Value::Object(desired)) => {
+            let changes = desired
+                .into_iter()
+                .filter_map(|(key, value)| match current.get(&key) {
+                    _ if value.is_null() => None,
+                    Some(existing) if contains(existing, &value) => None,
+                    Some(existing @ Value::Object(_)) => {
+                        let mut merged = existing.clone();
+                        merge_value(&mut merged, value);
+                        Some((key, merged))
+                    }
+                    _ => Some((key, value)),
+                })
+                .collect::<serde_json::Map<_, _>>();
+
+            if changes.is_empty() {
+                None
+            } else {
+                Some(Value::Object(changes))
+            }
+        }
+        (current, desired) if contains(current, &desired) => None,
+        (_, desired) => Some(desired),
+    };
+
+    Ok(patch)
+}
+
+/// Check whether all values that are set in `expected` have the same value in `actual`.
+fn contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (_, Value::Null) => true,
+        (Value::Number(a), Value::Number(b)) => a == b || a.as_f64() == b.as_f64(),
+        (Value::Object(a), Value::Object(b)) => b
+            .iter()
+            .all(|(key, b)| a.get(key).map_or(b.is_null(), |a| contains(a, b))),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| contains(a, b))
+        }
+        (a, b) => a == b,
+    }
+}
+
+fn merge_value(target: &mut Value, overlay: Value) {
+    match (target, overlay) {
+        (_, Value::Null) => {}
+        (Value::Object(target), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_value(existing, value),
+                    None if !value.is_null() => {
+                        target.insert(key, value);
+                    }
+                    None => {}
+                }
+            }
+        }
+        (target, overlay) => *target = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn merge_nested() {
+        let defaults = json!({
+            "font": { "face": "Arial", "size": 32 },
+            "text": "",
+        });
+
+        let merged = merge(
+            defaults,
+            &json!({
+                "font": { "size": 64 },
+                "text": "Hello",
+                "color": null,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            merged,
+            json!({
+                "font": { "face": "Arial", "size": 64 },
+                "text": "Hello",
+            })
+        );
+    }
+
+    #[test]
+    fn diff_minimal() {
+        let current = json!({
+            "font": { "face": "Arial", "size": 32 },
+            "text": "Hello",
+            "width": 800,
+        });
+
+        assert_eq!(
+            diff(
+                &current,
+                &json!({ "text": "Hello", "width": 800.0, "color": null })
+            )
+            .unwrap(),
+            None
+        );
+
+        assert_eq!(
+            diff(
+                &current,
+                &json!({ "font": { "size": 64 }, "text": "Hello", "height": 600 })
+            )
+            .unwrap(),
+            Some(json!({
+                "font": { "face": "Arial", "size": 64 },
+                "height": 600,
+            }))
+        );
+    }
+}
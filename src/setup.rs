@@ -0,0 +1,591 @@
+//! Declarative setup of scenes and sources, to reproduce a layout from a configuration file.
+//!
+//! A [`Setup`] describes the scenes, their sources with settings and filters, and the transform of
+//! each scene item. It can be deserialized from any format that serde supports, like TOML, JSON or
+//! YAML. [`Setup::apply`] compares it with the current state of OBS and only sends the requests
+//! needed to get from one to the other.
+//!
+//! ```no_run
+//! use anyhow::Result;
+//! use obws::{setup::Setup, Client};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let setup: Setup = serde_json::from_str(
+//!         r#"{
+//!             "scenes": [{
+//!                 "name": "Live",
+//!                 "items": [{
+//!                     "source": "Webcam",
+//!                     "kind": "v4l2_input",
+//!                     "transform": { "position": [1280.0, 720.0], "scale": [0.5, 0.5] },
+//!                     "filters": [{
+//!                         "name": "Color",
+//!                         "kind": "color_filter",
+//!                         "settings": { "saturation": 0.2 }
+//!                     }]
+//!                 }]
+//!             }]
+//!         }"#,
+//!     )?;
+//!
+//!     let client = Client::connect("localhost", 4444).await?;
+//!     for change in setup.apply(&client).await? {
+//!         println!("{:?}", change);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use either::Either;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{BoundsType, Crop, SceneItem, SceneName, SourceName};
+use crate::requests::{
+    AddFilter, AddSceneItem, CreateSource, SceneItemRender, SceneItemSpecification,
+    SceneItemTransform, SceneOrGroup, SourceFilterSettings,
+};
+use crate::responses::{Scene, SceneItemProperties};
+use crate::{settings, Client, Error, Result};
+
+/// Source kind of groups, as reported by obs-websocket.
+const GROUP_KIND: &str = "group";
+/// Source kind of scenes, as reported by obs-websocket.
+const SCENE_KIND: &str = "scene";
+
+/// Description of the scenes and sources that OBS should have.
+///
+/// Everything that exists in OBS but isn't part of the setup is left untouched. Likewise, only
+/// the settings and transform values that are given are compared and changed.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Setup {
+    /// Scenes to create or update.
+    #[serde(default)]
+    pub scenes: Vec<SceneSetup>,
+}
+
+/// A single scene as part of a [`Setup`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SceneSetup {
+    /// Name of the scene.
+    pub name: SceneName,
+    /// Items of the scene.
+    #[serde(default)]
+    pub items: Vec<ItemSetup>,
+}
+
+/// A scene item and its source as part of a [`SceneSetup`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ItemSetup {
+    /// Name of the source. Sources are shared between scenes, so the settings and filters of a
+    /// source only need to be described once.
+    pub source: SourceName,
+    /// Kind of the source, like `browser_source`. Only needed if the source doesn't exist yet.
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Settings of the source that differ from its defaults.
+    #[serde(default)]
+    pub settings: Option<serde_json::Value>,
+    /// Filters of the source.
+    #[serde(default)]
+    pub filters: Vec<FilterSetup>,
+    /// Position, scale and other transform values of the scene item.
+    #[serde(default)]
+    pub transform: Option<TransformSetup>,
+    /// Whether the scene item is visible.
+    #[serde(default)]
+    pub visible: Option<bool>,
+}
+
+/// A filter of a source as part of an [`ItemSetup`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterSetup {
+    /// Name of the filter.
+    pub name: String,
+    /// Kind of the filter, like `color_filter`.
+    pub kind: String,
+    /// Settings of the filter.
+    #[serde(default)]
+    pub settings: Option<serde_json::Value>,
+    /// Whether the filter is enabled.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+}
+
+/// Transform of a scene item as part of an [`ItemSetup`]. All values are optional and only the
+/// ones that are set are applied.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransformSetup {
+    /// Position of the item as `[x, y]`.
+    #[serde(default)]
+    pub position: Option<(f64, f64)>,
+    /// Clockwise rotation in degrees.
+    #[serde(default)]
+    pub rotation: Option<f64>,
+    /// Scaling factor as `[x, y]`.
+    #[serde(default)]
+    pub scale: Option<(f64, f64)>,
+    /// Pixel cropping before scaling.
+    #[serde(default)]
+    pub crop: Option<Crop>,
+    /// Bounding box as `[type, width, height]`.
+    #[serde(default)]
+    pub bounds: Option<(BoundsType, f64, f64)>,
+}
+
+impl From<&TransformSetup> for SceneItemTransform {
+    fn from(setup: &TransformSetup) -> Self {
+        let mut transform = Self::new();
+        if let Some((x, y)) = setup.position {
+            transform = transform.position(x, y);
+        }
+        if let Some(rotation) = setup.rotation {
+            transform = transform.rotation(rotation);
+        }
+        if let Some((x, y)) = setup.scale {
+            transform = transform.scale(x, y);
+        }
+        if let Some(crop) = &setup.crop {
+            transform = transform.crop(crop.into());
+        }
+        if let Some((ty, width, height)) = setup.bounds {
+            transform = transform.bounds(ty, width, height);
+        }
+        transform
+    }
+}
+
+impl TransformSetup {
+    /// Check whether all values that are set already match the current properties of the item.
+    fn matches(&self, props: &SceneItemProperties) -> bool {
+        fn eq(a: f64, b: f64) -> bool {
+            (a - b).abs() < 1e-4
+        }
+
+        if let Some((x, y)) = self.position {
+            if !eq(x, props.position.x) || !eq(y, props.position.y) {
+                return false;
+            }
+        }
+        if let Some(rotation) = self.rotation {
+            if !eq(rotation, props.rotation) {
+                return false;
+            }
+        }
+        if let Some((x, y)) = self.scale {
+            if !eq(x, props.scale.x) || !eq(y, props.scale.y) {
+                return false;
+            }
+        }
+        if let Some(crop) = &self.crop {
+            if *crop != props.crop {
+                return false;
+            }
+        }
+        if let Some((ty, x, y)) = self.bounds {
+            if ty != props.bounds.ty || !eq(x, props.bounds.x) || !eq(y, props.bounds.y) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Full content of a scene collection, created with
+/// [`export`](crate::client::SceneCollections::export) and restored with
+/// [`import`](crate::client::SceneCollections::import).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SceneCollectionDump {
+    /// Name of the scene collection.
+    pub name: String,
+    /// Scenes and sources of the scene collection.
+    pub setup: Setup,
+}
+
+/// A single change that was made by [`Setup::apply`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Change {
+    /// A new scene was created.
+    SceneCreated {
+        /// Name of the scene.
+        scene: SceneName,
+    },
+    /// A new source was created and added to a scene.
+    SourceCreated {
+        /// Scene the source was added to.
+        scene: SceneName,
+        /// Name of the source.
+        source: SourceName,
+    },
+    /// An existing source was added to a scene.
+    ItemAdded {
+        /// Scene the source was added to.
+        scene: SceneName,
+        /// Name of the source.
+        source: SourceName,
+    },
+    /// The settings of an existing source were changed.
+    SettingsUpdated {
+        /// Name of the source.
+        source: SourceName,
+    },
+    /// A new filter was added to a source.
+    FilterAdded {
+        /// Name of the source.
+        source: SourceName,
+        /// Name of the filter.
+        filter: String,
+    },
+    /// The settings or state of an existing filter were changed.
+    FilterUpdated {
+        /// Name of the source.
+        source: SourceName,
+        /// Name of the filter.
+        filter: String,
+    },
+    /// The transform of a scene item was changed.
+    TransformApplied {
+        /// Scene of the item.
+        scene: SceneName,
+        /// Name of the item's source.
+        source: SourceName,
+    },
+    /// The visibility of a scene item was changed.
+    VisibilityChanged {
+        /// Scene of the item.
+        scene: SceneName,
+        /// Name of the item's source.
+        source: SourceName,
+    },
+}
+
+impl Setup {
+    /// Capture the scenes of the current scene collection, with the full settings, filters and
+    /// transform of all their sources.
+    ///
+    /// Groups and their content are not captured, as obs-websocket can't create them.
+    pub async fn capture(client: &Client) -> Result<Self> {
+        let scene_list = client.scenes().get_scene_list().await?;
+        let mut seen = Vec::<&str>::new();
+        let mut scenes = Vec::with_capacity(scene_list.scenes.len());
+
+        for scene in &scene_list.scenes {
+            let mut items = Vec::with_capacity(scene.sources.len());
+
+            for item in &scene.sources {
+                if item.ty == GROUP_KIND {
+                    continue;
+                }
+
+                let props = client
+                    .scene_items()
+                    .get_scene_item_properties(
+                        Some(SceneOrGroup::Scene(&scene.name)),
+                        Either::Right(SceneItemSpecification {
+                            name: Some(&item.name),
+                            id: Some(item.id),
+                        }),
+                    )
+                    .await?;
+
+                // Sources are shared between scenes, so their settings only need to be captured
+                // with the first item.
+                let (settings, filters) = if seen.contains(&item.name.as_str()) {
+                    (None, Vec::new())
+                } else {
+                    seen.push(&item.name);
+                    capture_source(client, item).await?
+                };
+
+                items.push(ItemSetup {
+                    source: item.name.clone(),
+                    kind: Some(item.ty.clone()),
+                    settings,
+                    filters,
+                    transform: Some(TransformSetup {
+                        position: Some((props.position.x, props.position.y)),
+                        rotation: Some(props.rotation),
+                        scale: Some((props.scale.x, props.scale.y)),
+                        crop: Some(props.crop),
+                        bounds: Some((props.bounds.ty, props.bounds.x, props.bounds.y)),
+                    }),
+                    visible: Some(props.visible),
+                });
+            }
+
+            scenes.push(SceneSetup {
+                name: scene.name.clone(),
+                items,
+            });
+        }
+
+        Ok(Self { scenes })
+    }
+
+    /// Compare the setup with the current state of OBS and apply all differences. Returns the list
+    /// of changes that were made, which is empty if OBS already matched the setup.
+    ///
+    /// Creating a source requires its [`kind`](ItemSetup::kind), otherwise
+    /// [`Error::MissingSourceKind`] is returned. Changes made before an error occurs are not
+    /// reverted.
+    pub async fn apply(&self, client: &Client) -> Result<Vec<Change>> {
+        let mut changes = Vec::new();
+
+        let scene_list = client.scenes().get_scene_list().await?;
+        let mut sources = client
+            .sources()
+            .get_sources_list()
+            .await?
+            .into_iter()
+            .map(|source| source.name)
+            .collect::<Vec<_>>();
+
+        // Create all scenes first, so they can be used as items of other scenes.
+        for scene in &self.scenes {
+            if !scene_list.scenes.iter().any(|s| s.name == scene.name) {
+                client.scenes().create_scene(&scene.name).await?;
+                sources.push(scene.name.as_str().into());
+                changes.push(Change::SceneCreated {
+                    scene: scene.name.clone(),
+                });
+            }
+        }
+
+        for scene in &self.scenes {
+            let existing = scene_list.scenes.iter().find(|s| s.name == scene.name);
+
+            for item in &scene.items {
+                let in_scene = existing
+                    .into_iter()
+                    .flat_map(|existing| &existing.sources)
+                    .any(|i| i.name == item.source);
+
+                if !sources.contains(&item.source) {
+                    create_source(client, &scene.name, item).await?;
+                    sources.push(item.source.clone());
+                    changes.push(Change::SourceCreated {
+                        scene: scene.name.clone(),
+                        source: item.source.clone(),
+                    });
+                } else {
+                    if !in_scene {
+                        client
+                            .scene_items()
+                            .add_scene_item(AddSceneItem {
+                                scene_name: &scene.name,
+                                source_name: &item.source,
+                                set_visible: item.visible.unwrap_or(true),
+                            })
+                            .await?;
+                        changes.push(Change::ItemAdded {
+                            scene: scene.name.clone(),
+                            source: item.source.clone(),
+                        });
+                    }
+
+                    if let Some(settings) = &item.settings {
+                        if client
+                            .sources()
+                            .sync_source_settings(&item.source, settings)
+                            .await?
+                        {
+                            changes.push(Change::SettingsUpdated {
+                                source: item.source.clone(),
+                            });
+                        }
+                    }
+                }
+
+                apply_filters(client, item, &mut changes).await?;
+                apply_item(client, &scene.name, item, existing, &mut changes).await?;
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+async fn capture_source(
+    client: &Client,
+    item: &SceneItem,
+) -> Result<(Option<serde_json::Value>, Vec<FilterSetup>)> {
+    let sources = client.sources();
+
+    let settings = if item.ty == SCENE_KIND {
+        None
+    } else {
+        let settings = sources
+            .get_source_settings::<serde_json::Value>(&item.name, None)
+            .await?
+            .source_settings;
+        Some(settings)
+    };
+
+    let filters = sources
+        .get_source_filters(&item.name)
+        .await?
+        .into_iter()
+        .map(|filter| FilterSetup {
+            name: filter.name,
+            kind: filter.ty,
+            settings: Some(filter.settings),
+            enabled: Some(filter.enabled),
+        })
+        .collect();
+
+    Ok((settings, filters))
+}
+
+async fn create_source(client: &Client, scene_name: &SceneName, item: &ItemSetup) -> Result<()> {
+    let kind = item
+        .kind
+        .as_deref()
+        .ok_or_else(|| Error::MissingSourceKind(item.source.to_string()))?;
+
+    client
+        .sources()
+        .create_source(CreateSource {
+            source_name: &item.source,
+            source_kind: kind,
+            scene_name,
+            source_settings: item.settings.as_ref(),
+            set_visible: item.visible,
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn apply_filters(client: &Client, item: &ItemSetup, changes: &mut Vec<Change>) -> Result<()> {
+    if item.filters.is_empty() {
+        return Ok(());
+    }
+
+    let sources = client.sources();
+    let existing = sources.get_source_filters(&item.source).await?;
+    let no_settings = serde_json::Value::Object(serde_json::Map::new());
+
+    for filter in &item.filters {
+        let current = existing.iter().find(|f| f.name == filter.name);
+        let current = match current {
+            Some(current) => current,
+            None => {
+                sources
+                    .add_filter_to_source(AddFilter {
+                        source_name: &item.source,
+                        filter_name: &filter.name,
+                        filter_type: &filter.kind,
+                        filter_settings: filter.settings.as_ref().unwrap_or(&no_settings),
+                    })
+                    .await?;
+                if filter.enabled == Some(false) {
+                    sources
+                        .set_filter_enabled(&item.source, &filter.name, false)
+                        .await?;
+                }
+
+                changes.push(Change::FilterAdded {
+                    source: item.source.clone(),
+                    filter: filter.name.clone(),
+                });
+                continue;
+            }
+        };
+
+        let mut updated = false;
+
+        if let Some(settings) = &filter.settings {
+            if let Some(patch) = settings::diff(&current.settings, settings)? {
+                sources
+                    .set_source_filter_settings(SourceFilterSettings {
+                        source_name: &item.source,
+                        filter_name: &filter.name,
+                        filter_settings: &patch,
+                    })
+                    .await?;
+                updated = true;
+            }
+        }
+
+        if let Some(enabled) = filter.enabled.filter(|&enabled| enabled != current.enabled) {
+            sources
+                .set_filter_enabled(&item.source, &filter.name, enabled)
+                .await?;
+            updated = true;
+        }
+
+        if updated {
+            changes.push(Change::FilterUpdated {
+                source: item.source.clone(),
+                filter: filter.name.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+async fn apply_item(
+    client: &Client,
+    scene_name: &SceneName,
+    item: &ItemSetup,
+    existing: Option<&Scene>,
+    changes: &mut Vec<Change>,
+) -> Result<()> {
+    if let Some(transform) = &item.transform {
+        let scene_items = client.scene_items();
+        let props = scene_items
+            .get_scene_item_properties(
+                Some(SceneOrGroup::Scene(scene_name)),
+                Either::Left(&item.source),
+            )
+            .await?;
+
+        if !transform.matches(&props) {
+            scene_items
+                .set_scene_item_transform(
+                    Some(SceneOrGroup::Scene(scene_name)),
+                    Either::Left(&item.source),
+                    transform.into(),
+                )
+                .await?;
+            changes.push(Change::TransformApplied {
+                scene: scene_name.into(),
+                source: item.source.clone(),
+            });
+        }
+    }
+
+    let current = existing
+        .and_then(|scene| scene.sources.iter().find(|i| i.name == item.source))
+        .map(|i| i.render);
+
+    if let (Some(visible), Some(current)) = (item.visible, current) {
+        if visible != current {
+            client
+                .scene_items()
+                .set_scene_item_render(SceneItemRender {
+                    scene_name: Some(SceneOrGroup::Scene(scene_name)),
+                    source: &item.source,
+                    item: None,
+                    render: visible,
+                })
+                .await?;
+            changes.push(Change::VisibilityChanged {
+                scene: scene_name.into(),
+                source: item.source.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,617 @@
+//! A local mirror of the OBS state, that is kept in sync through events and can be read without
+//! sending any requests.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use futures_util::{pin_mut, Stream, StreamExt};
+use tokio::{sync::broadcast, task::JoinHandle};
+
+use crate::common::{SceneItem, SceneItemId, SceneName, SourceKind, SourceName, Volume};
+use crate::events::{Event, EventType, SourceType};
+use crate::{Client, Result};
+
+/// Source type of inputs, as reported by obs-websocket.
+const INPUT_TYPE: &str = "input";
+/// Capacity of the broadcast channel for [`Renamed`] notifications.
+const RENAME_CAPACITY: usize = 16;
+
+/// Cached mirror of scenes, scene items, sources, their mute and volume states and the current
+/// program and preview scene.
+///
+/// It's loaded once when created and from then on updated automatically from the event stream of
+/// the client, so the getters are cheap and synchronous. This is useful for overlays and other
+/// tools that need the current state frequently.
+///
+/// Some changes, like switching to another scene collection, can't be reconstructed from events
+/// alone. In that case the state is marked as [stale](Self::is_stale) and can be reloaded with
+/// [`refresh`](Self::refresh).
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use obws::{state::ObsState, Client};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("localhost", 4444).await?;
+///     let state = ObsState::new(&client).await?;
+///
+///     println!("current scene: {}", state.current_scene());
+///     println!("mic muted: {:?}", state.is_muted("Mic/Aux"));
+///
+///     Ok(())
+/// }
+/// ```
+pub struct ObsState {
+    data: Arc<RwLock<StateData>>,
+    renames: broadcast::Sender<Renamed>,
+    handle: JoinHandle<()>,
+}
+
+struct StateData {
+    current_scene: SceneName,
+    preview_scene: Option<SceneName>,
+    scenes: Vec<CachedScene>,
+    sources: HashMap<SourceName, CachedSource>,
+    stale: bool,
+}
+
+/// A scene in the [`ObsState`].
+#[derive(Clone, Debug)]
+pub struct CachedScene {
+    /// Name of the scene.
+    pub name: SceneName,
+    /// The scene's items, ordered from the top to the bottom item as shown in the OBS UI.
+    pub items: Vec<CachedSceneItem>,
+}
+
+/// A scene item in the [`ObsState`] as part of [`CachedScene`].
+#[derive(Clone, Debug)]
+pub struct CachedSceneItem {
+    /// Scene item ID.
+    pub id: SceneItemId,
+    /// Name of the item's source.
+    pub name: SourceName,
+    /// Whether the item is visible.
+    pub visible: bool,
+    /// Whether the item's transform is locked.
+    pub locked: bool,
+}
+
+/// A scene or source was renamed, as reported by [`ObsState::renames`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Renamed {
+    /// A scene was renamed.
+    Scene {
+        /// Previous name of the scene.
+        previous: SceneName,
+        /// New name of the scene.
+        new: SceneName,
+    },
+    /// A source, that isn't a scene, was renamed.
+    Source {
+        /// Previous name of the source.
+        previous: SourceName,
+        /// New name of the source.
+        new: SourceName,
+    },
+}
+
+/// A source in the [`ObsState`].
+#[derive(Clone, Debug)]
+pub struct CachedSource {
+    /// Unique source name.
+    pub name: SourceName,
+    /// Source kind, for example `vlc_source` or `image_source`.
+    pub kind: SourceKind,
+    /// Source type, like `input` or `scene`.
+    pub ty: String,
+    /// Whether the source is muted. Only tracked for inputs.
+    pub muted: bool,
+    /// Volume of the source. Only tracked for inputs.
+    pub volume: Volume,
+}
+
+impl ObsState {
+    /// Load the current state from OBS and keep it in sync with the events of the given client.
+    pub async fn new(client: &Client) -> Result<Self> {
+        // Subscribe before loading, so no change gets lost in between.
+        let events = client.events()?;
+        let data = Arc::new(RwLock::new(StateData::load(client).await?));
+
+        let (renames, _) = broadcast::channel(RENAME_CAPACITY);
+
+        let task_data = Arc::clone(&data);
+        let task_renames = renames.clone();
+        let handle = tokio::spawn(async move {
+            pin_mut!(events);
+
+            while let Some(event) = events.next().await {
+                let renamed = write(&task_data).apply(event);
+                if let Some(renamed) = renamed {
+                    task_renames.send(renamed).ok();
+                }
+            }
+
+            // The connection was closed, so no further updates will be received.
+            write(&task_data).stale = true;
+        });
+
+        Ok(Self {
+            data,
+            renames,
+            handle,
+        })
+    }
+
+    /// Reload the whole state from OBS, for example after it became [stale](Self::is_stale).
+    pub async fn refresh(&self, client: &Client) -> Result<()> {
+        let data = StateData::load(client).await?;
+        *write(&self.data) = data;
+        Ok(())
+    }
+
+    /// Rename a source through the given client, and update the cached names right away instead
+    /// of waiting for the `SourceRenamed` event. Scenes are sources as well, but
+    /// [`rename_scene`](Self::rename_scene) takes typed scene names.
+    pub async fn rename_source(
+        &self,
+        client: &Client,
+        source: impl Into<SourceName>,
+        new_name: impl Into<SourceName>,
+    ) -> Result<()> {
+        let source = source.into();
+        let new_name = new_name.into();
+
+        client.sources().set_source_name(&source, &new_name).await?;
+        self.rename(&source, new_name.into());
+        Ok(())
+    }
+
+    /// Rename a scene through the given client, and update the cached names right away instead of
+    /// waiting for the `SourceRenamed` event.
+    pub async fn rename_scene(
+        &self,
+        client: &Client,
+        scene: impl Into<SceneName>,
+        new_name: impl Into<SceneName>,
+    ) -> Result<()> {
+        let scene = scene.into();
+        let new_name = new_name.into();
+
+        client.scenes().set_scene_name(&scene, &new_name).await?;
+        self.rename(&scene, new_name.into());
+        Ok(())
+    }
+
+    fn rename(&self, previous_name: &str, new_name: String) {
+        let renamed = write(&self.data).rename(previous_name, new_name);
+        if let Some(renamed) = renamed {
+            self.renames.send(renamed).ok();
+        }
+    }
+
+    /// Get a stream of all renamed scenes and sources, no matter whether they were renamed
+    /// through this state, another client or by the user in OBS. This allows long running tools
+    /// to update the names they hold on to.
+    ///
+    /// Each rename is reported once, even if it was done through
+    /// [`rename_source`](Self::rename_source) or [`rename_scene`](Self::rename_scene) and the
+    /// `SourceRenamed` event is received afterwards. Only sources that are part of the state are
+    /// reported, so renamed filters or transitions are not.
+    pub fn renames(&self) -> impl Stream<Item = Renamed> {
+        let mut receiver = self.renames.subscribe();
+
+        async_stream::stream! {
+            while let Ok(renamed) = receiver.recv().await {
+                yield renamed;
+            }
+        }
+    }
+
+    /// Whether the state may be outdated, because a change happened that can't be tracked through
+    /// events, or the connection was lost.
+    pub fn is_stale(&self) -> bool {
+        read(&self.data).stale
+    }
+
+    /// Name of the scene currently shown in the program output.
+    pub fn current_scene(&self) -> SceneName {
+        read(&self.data).current_scene.clone()
+    }
+
+    /// Name of the scene currently in preview, if Studio Mode is enabled.
+    pub fn preview_scene(&self) -> Option<SceneName> {
+        read(&self.data).preview_scene.clone()
+    }
+
+    /// Ordered list of all scene names.
+    pub fn scene_names(&self) -> Vec<SceneName> {
+        read(&self.data)
+            .scenes
+            .iter()
+            .map(|scene| scene.name.clone())
+            .collect()
+    }
+
+    /// Get a single scene with all its items.
+    pub fn scene(&self, name: &str) -> Option<CachedScene> {
+        read(&self.data)
+            .scenes
+            .iter()
+            .find(|scene| scene.name == name)
+            .cloned()
+    }
+
+    /// Get a single source.
+    pub fn source(&self, name: &str) -> Option<CachedSource> {
+        read(&self.data).sources.get(name).cloned()
+    }
+
+    /// List of all sources, in no particular order.
+    pub fn sources(&self) -> Vec<CachedSource> {
+        read(&self.data).sources.values().cloned().collect()
+    }
+
+    /// Whether the given source is muted, or `None` if the source is unknown.
+    pub fn is_muted(&self, source: &str) -> Option<bool> {
+        read(&self.data).sources.get(source).map(|s| s.muted)
+    }
+
+    /// Volume of the given source, or `None` if the source is unknown.
+    pub fn volume(&self, source: &str) -> Option<Volume> {
+        read(&self.data).sources.get(source).map(|s| s.volume)
+    }
+}
+
+impl Drop for ObsState {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Lock the data for reading. A poisoned lock is still used, as the state is never left in an
+/// inconsistent state that would be worse than slightly outdated data.
+fn read(data: &RwLock<StateData>) -> RwLockReadGuard<'_, StateData> {
+    data.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Lock the data for writing, see [`read`] about poisoned locks.
+fn write(data: &RwLock<StateData>) -> RwLockWriteGuard<'_, StateData> {
+    data.write().unwrap_or_else(|e| e.into_inner())
+}
+
+impl StateData {
+    async fn load(client: &Client) -> Result<Self> {
+        let scene_list = client.scenes().get_scene_list().await?;
+        let preview_scene = if client.studio_mode().get_studio_mode_status().await? {
+            Some(client.studio_mode().get_preview_scene().await?.name)
+        } else {
+            None
+        };
+
+        let mut sources = HashMap::new();
+        for source in client.sources().get_sources_list().await? {
+            let (muted, volume) = if source.ty == INPUT_TYPE {
+                let volume = client.sources().get_volume(&source.name).await?;
+                (volume.muted, volume.volume)
+            } else {
+                (false, Volume::default())
+            };
+
+            sources.insert(
+                source.name.clone(),
+                CachedSource {
+                    name: source.name,
+                    kind: source.type_id,
+                    ty: source.ty,
+                    muted,
+                    volume,
+                },
+            );
+        }
+
+        Ok(Self {
+            current_scene: scene_list.current_scene,
+            preview_scene,
+            scenes: scene_list
+                .scenes
+                .into_iter()
+                .map(|scene| CachedScene {
+                    name: scene.name,
+                    items: cached_items(scene.sources),
+                })
+                .collect(),
+            sources,
+            stale: false,
+        })
+    }
+
+    /// Apply an event to the state, returning the rename it caused, if any.
+    fn apply(&mut self, event: Event) -> Option<Renamed> {
+        match event.ty {
+            EventType::SwitchScenes {
+                scene_name,
+                sources,
+            } => {
+                self.set_items(&scene_name, sources);
+                self.current_scene = scene_name;
+            }
+            EventType::PreviewSceneChanged {
+                scene_name,
+                sources,
+            } => {
+                self.set_items(&scene_name, sources);
+                self.preview_scene = Some(scene_name);
+            }
+            EventType::StudioModeSwitched { new_state: false } => self.preview_scene = None,
+            EventType::ScenesChanged { scenes } => {
+                self.scenes = scenes
+                    .into_iter()
+                    .map(|scene| CachedScene {
+                        name: scene.name,
+                        items: cached_items(scene.sources),
+                    })
+                    .collect();
+            }
+            EventType::SceneCollectionChanged { .. } => self.stale = true,
+            EventType::SourceOrderChanged {
+                scene_name,
+                scene_items,
+            } => {
+                if let Some(scene) = self.scene_mut(&scene_name) {
+                    // The event lists the items from the bottom to the top.
+                    let mut items = std::mem::take(&mut scene.items);
+                    scene.items = scene_items
+                        .iter()
+                        .rev()
+                        .filter_map(|ordered| {
+                            let pos = items.iter().position(|item| item.id == ordered.item_id)?;
+                            Some(items.remove(pos))
+                        })
+                        .collect();
+                }
+            }
+            EventType::SceneItemAdded {
+                scene_name,
+                item_name,
+                item_id,
+            } => {
+                if let Some(scene) = self.scene_mut(&scene_name) {
+                    // New items are always placed on top.
+                    scene.items.insert(
+                        0,
+                        CachedSceneItem {
+                            id: item_id,
+                            name: item_name,
+                            visible: true,
+                            locked: false,
+                        },
+                    );
+                }
+            }
+            EventType::SceneItemRemoved {
+                scene_name,
+                item_id,
+                ..
+            } => {
+                if let Some(scene) = self.scene_mut(&scene_name) {
+                    scene.items.retain(|item| item.id != item_id);
+                }
+            }
+            EventType::SceneItemVisibilityChanged {
+                scene_name,
+                item_id,
+                item_visible,
+                ..
+            } => {
+                if let Some(item) = self.item_mut(&scene_name, item_id) {
+                    item.visible = item_visible;
+                }
+            }
+            EventType::SceneItemLockChanged {
+                scene_name,
+                item_id,
+                item_locked,
+                ..
+            } => {
+                if let Some(item) = self.item_mut(&scene_name, item_id) {
+                    item.locked = item_locked;
+                }
+            }
+            EventType::SourceCreated {
+                source_name,
+                source_type,
+                source_kind,
+                ..
+            } => {
+                let ty = match source_type {
+                    SourceType::Input => INPUT_TYPE,
+                    SourceType::Scene => "scene",
+                    SourceType::Transition => "transition",
+                    SourceType::Filter => "filter",
+                };
+
+                self.sources.insert(
+                    source_name.clone(),
+                    CachedSource {
+                        name: source_name,
+                        kind: source_kind,
+                        ty: ty.to_owned(),
+                        muted: false,
+                        volume: Volume::default(),
+                    },
+                );
+            }
+            EventType::SourceDestroyed { source_name, .. } => {
+                self.sources.remove(&source_name);
+            }
+            EventType::SourceRenamed {
+                previous_name,
+                new_name,
+                ..
+            } => return self.rename(&previous_name, new_name),
+            EventType::SourceVolumeChanged {
+                source_name,
+                volume,
+            } => {
+                if let Some(source) = self.sources.get_mut(&source_name) {
+                    source.volume = volume;
+                }
+            }
+            EventType::SourceMuteStateChanged { source_name, muted } => {
+                if let Some(source) = self.sources.get_mut(&source_name) {
+                    source.muted = muted;
+                }
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn scene_mut(&mut self, name: &str) -> Option<&mut CachedScene> {
+        self.scenes.iter_mut().find(|scene| scene.name == name)
+    }
+
+    fn item_mut(&mut self, scene_name: &str, id: SceneItemId) -> Option<&mut CachedSceneItem> {
+        self.scene_mut(scene_name)?
+            .items
+            .iter_mut()
+            .find(|item| item.id == id)
+    }
+
+    fn set_items(&mut self, scene_name: &str, items: Vec<SceneItem>) {
+        if let Some(scene) = self.scene_mut(scene_name) {
+            scene.items = cached_items(items);
+        }
+    }
+
+    /// Rename a source, which may be a scene as well, as scenes are sources in OBS. Returns the
+    /// rename if the source was known, so it's only reported once.
+    fn rename(&mut self, previous_name: &str, new_name: String) -> Option<Renamed> {
+        let new_source = SourceName::from(&new_name);
+        let new_scene = SceneName::from(new_name);
+        let mut known_source = false;
+        let mut known_scene = false;
+
+        if let Some(mut source) = self.sources.remove(previous_name) {
+            source.name = new_source.clone();
+            self.sources.insert(new_source.clone(), source);
+            known_source = true;
+        }
+
+        for scene in &mut self.scenes {
+            if scene.name == previous_name {
+                scene.name = new_scene.clone();
+                known_scene = true;
+            }
+            for item in &mut scene.items {
+                if item.name == previous_name {
+                    item.name = new_source.clone();
+                }
+            }
+        }
+
+        if self.current_scene == previous_name {
+            self.current_scene = new_scene.clone();
+        }
+        if self.preview_scene.as_deref() == Some(previous_name) {
+            self.preview_scene = Some(new_scene.clone());
+        }
+
+        if known_scene {
+            Some(Renamed::Scene {
+                previous: previous_name.into(),
+                new: new_scene,
+            })
+        } else if known_source {
+            Some(Renamed::Source {
+                previous: previous_name.into(),
+                new: new_source,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn cached_items(items: Vec<SceneItem>) -> Vec<CachedSceneItem> {
+    items
+        .into_iter()
+        .map(|item| CachedSceneItem {
+            id: item.id,
+            name: item.name,
+            visible: item.render,
+            locked: item.locked,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn item(id: i64) -> CachedSceneItem {
+        CachedSceneItem {
+            id: SceneItemId(id),
+            name: format!("Item {}", id).into(),
+            visible: true,
+            locked: false,
+        }
+    }
+
+    fn data() -> StateData {
+        StateData {
+            current_scene: "Scene".into(),
+            preview_scene: None,
+            scenes: vec![CachedScene {
+                name: "Scene".into(),
+                items: vec![item(3), item(2), item(1)],
+            }],
+            sources: HashMap::new(),
+            stale: false,
+        }
+    }
+
+    fn item_ids(data: &StateData) -> Vec<i64> {
+        data.scenes[0].items.iter().map(|item| item.id.0).collect()
+    }
+
+    #[test]
+    fn added_item_on_top() {
+        let mut data = data();
+        data.apply(
+            serde_json::from_value(json!({
+                "update-type": "SceneItemAdded",
+                "scene-name": "Scene",
+                "item-name": "Item 4",
+                "item-id": 4,
+            }))
+            .unwrap(),
+        );
+
+        assert_eq!(vec![4, 3, 2, 1], item_ids(&data));
+    }
+
+    #[test]
+    fn reordered_items_top_first() {
+        let mut data = data();
+        data.apply(
+            serde_json::from_value(json!({
+                "update-type": "SourceOrderChanged",
+                "scene-name": "Scene",
+                "scene-items": [
+                    { "source-name": "Item 2", "item-id": 2 },
+                    { "source-name": "Item 1", "item-id": 1 },
+                    { "source-name": "Item 3", "item-id": 3 },
+                ],
+            }))
+            .unwrap(),
+        );
+
+        assert_eq!(vec![3, 1, 2], item_ids(&data));
+    }
+}
@@ -0,0 +1,381 @@
+//! A queue for scene changes, that doesn't interrupt running transitions.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use futures_util::{future, pin_mut, stream, Stream, StreamExt};
+use log::warn;
+use tokio::{
+    sync::Notify,
+    task::JoinHandle,
+    time::{self, Instant},
+};
+
+use crate::common::SceneName;
+use crate::events::{Event, EventType};
+use crate::{Client, Result};
+
+/// Maximum time to wait for the end of a scene change, before the next one is sent anyway. This
+/// prevents the switcher from getting stuck if an event is lost. The time counts from the start of
+/// the scene change, other events received in the meantime don't extend it.
+const TRANSITION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Helper to switch scenes one after another, waiting for each transition to finish before
+/// starting the next one.
+///
+/// Calling [`Scenes::set_current_scene`](crate::client::Scenes::set_current_scene) several times
+/// in quick succession while a transition is running can leave OBS in an odd state. The switcher
+/// queues scene changes instead, and only sends the next one after the `TransitionEnd` event (or
+/// `SwitchScenes` for the "cut" transition, which doesn't report transition events) was received.
+///
+/// Queued changes are coalesced. Only the latest requested scene is switched to once the running
+/// transition ended, and requests for the scene that is already shown are dropped. Transitions
+/// started by the user in OBS are waited for as well.
+///
+/// Scene changes are sent from a background task, that holds a clone of the client. Errors are
+/// therefore only logged.
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use obws::{switcher::SceneSwitcher, Client};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("localhost", 4444).await?;
+///     let switcher = SceneSwitcher::new(&client).await?;
+///
+///     switcher.switch_to("Intro");
+///     switcher.switch_to("Break");
+///     // Still transitioning to "Intro", so "Break" waits.
+///     println!("pending scene: {:?}", switcher.pending());
+///
+///     Ok(())
+/// }
+/// ```
+pub struct SceneSwitcher {
+    data: Arc<Mutex<SwitcherData>>,
+    notify: Arc<Notify>,
+    handle: JoinHandle<()>,
+}
+
+struct SwitcherData {
+    /// Scene shown in the program output, or the destination of the running transition.
+    current: SceneName,
+    /// Scene to switch to once the running transition ended.
+    pending: Option<SceneName>,
+    /// Progress of the latest scene change.
+    phase: Phase,
+    /// Point in time to give up waiting for the end of the running scene change.
+    deadline: Option<Instant>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Phase {
+    /// No scene change is running.
+    Idle,
+    /// A scene change was sent, but no transition started yet.
+    Requested,
+    /// A transition is running.
+    Transition,
+}
+
+/// Input of the background task.
+enum Input {
+    /// A scene change was queued.
+    Queued,
+    /// An event was received.
+    Event(Box<EventType>),
+    /// The connection was closed.
+    Closed,
+}
+
+impl SceneSwitcher {
+    /// Create a new switcher, that sends scene changes through the given client.
+    pub async fn new(client: &Client) -> Result<Self> {
+        // Subscribe before loading, so no scene change gets lost in between.
+        let events = client.events()?;
+        let current = client.scenes().get_current_scene().await?.name;
+
+        let data = Arc::new(Mutex::new(SwitcherData {
+            current,
+            pending: None,
+            phase: Phase::Idle,
+            deadline: None,
+        }));
+        let notify = Arc::new(Notify::new());
+
+        let handle = tokio::spawn(run(
+            client.clone(),
+            events,
+            Arc::clone(&data),
+            Arc::clone(&notify),
+        ));
+
+        Ok(Self {
+            data,
+            notify,
+            handle,
+        })
+    }
+
+    /// Queue a switch to the given scene. It's sent right away if no transition is running, and
+    /// otherwise replaces the currently pending scene.
+    pub fn switch_to(&self, scene: impl Into<SceneName>) {
+        lock(&self.data).queue(scene.into());
+        self.notify.notify_one();
+    }
+
+    /// The scene that will be switched to once the running transition ended, if any.
+    pub fn pending(&self) -> Option<SceneName> {
+        lock(&self.data).pending.clone()
+    }
+
+    /// Name of the scene currently shown in the program output, or the destination of the
+    /// running transition.
+    pub fn current_scene(&self) -> SceneName {
+        lock(&self.data).current.clone()
+    }
+
+    /// Whether a scene change is currently running.
+    pub fn is_transitioning(&self) -> bool {
+        lock(&self.data).phase != Phase::Idle
+    }
+}
+
+impl Drop for SceneSwitcher {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Lock the data. A poisoned lock is still used, as the data is never left in an inconsistent
+/// state.
+fn lock(data: &Mutex<SwitcherData>) -> MutexGuard<'_, SwitcherData> {
+    data.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+async fn run(
+    client: Client,
+    events: impl Stream<Item = Event>,
+    data: Arc<Mutex<SwitcherData>>,
+    notify: Arc<Notify>,
+) {
+    let queued = stream::unfold(notify, |notify| async move {
+        notify.notified().await;
+        Some((Input::Queued, notify))
+    });
+    let events = events
+        .map(|event| Input::Event(Box::new(event.ty)))
+        .chain(stream::once(future::ready(Input::Closed)));
+    let inputs = stream::select(events, queued);
+
+    pin_mut!(inputs);
+
+    loop {
+        match next_input(&mut inputs, &data).await {
+            Some(Input::Queued) => {}
+            Some(Input::Event(event)) => lock(&data).apply(*event),
+            Some(Input::Closed) | None => break,
+        }
+
+        let next = lock(&data).next();
+        if let Some((scene, previous)) = next {
+            if let Err(e) = client.scenes().set_current_scene(&scene).await {
+                warn!("failed switching to scene `{}`: {}", scene, e);
+                lock(&data).failed(previous);
+            }
+        }
+    }
+}
+
+/// Wait for the next input. If a scene change is running and doesn't finish before its deadline,
+/// the switcher stops waiting for it and continues with the next one.
+async fn next_input(
+    inputs: &mut (impl Stream<Item = Input> + Unpin),
+    data: &Mutex<SwitcherData>,
+) -> Option<Input> {
+    let deadline = lock(data).deadline;
+    let deadline = match deadline {
+        Some(deadline) => deadline,
+        None => return inputs.next().await,
+    };
+
+    match time::timeout_at(deadline, inputs.next()).await {
+        Ok(input) => input,
+        Err(_) => {
+            warn!("scene change didn't finish in time, continuing with the next one");
+            lock(data).set_phase(Phase::Idle);
+            Some(Input::Queued)
+        }
+    }
+}
+
+impl SwitcherData {
+    /// Queue a scene change, replacing any previously pending one.
+    fn queue(&mut self, scene: SceneName) {
+        self.pending = Some(scene).filter(|scene| *scene != self.current);
+    }
+
+    /// Take the pending scene change if no transition is running, together with the previous
+    /// scene to restore in case the change fails.
+    fn next(&mut self) -> Option<(SceneName, SceneName)> {
+        if self.phase != Phase::Idle {
+            return None;
+        }
+
+        let scene = self.pending.take()?;
+        let previous = std::mem::replace(&mut self.current, scene.clone());
+        self.set_phase(Phase::Requested);
+
+        Some((scene, previous))
+    }
+
+    /// Revert a scene change that obs-websocket rejected.
+    fn failed(&mut self, previous: SceneName) {
+        self.current = previous;
+        self.set_phase(Phase::Idle);
+    }
+
+    /// Update the phase, starting the deadline when a scene change starts and clearing it once
+    /// it's done.
+    fn set_phase(&mut self, phase: Phase) {
+        self.phase = phase;
+        if phase == Phase::Idle {
+            self.deadline = None;
+        } else if self.deadline.is_none() {
+            self.deadline = Some(Instant::now() + TRANSITION_TIMEOUT);
+        }
+    }
+
+    fn apply(&mut self, event: EventType) {
+        match event {
+            EventType::TransitionBegin { to_scene, .. } => {
+                self.current = to_scene;
+                self.set_phase(Phase::Transition);
+            }
+            EventType::TransitionEnd { to_scene, .. } => {
+                self.current = to_scene;
+                self.set_phase(Phase::Idle);
+            }
+            EventType::SwitchScenes { scene_name, .. } => {
+                self.current = scene_name;
+                // Cut transitions only report the scene switch.
+                if self.phase == Phase::Requested {
+                    self.set_phase(Phase::Idle);
+                }
+            }
+            _ => return,
+        }
+
+        if self.pending.as_ref() == Some(&self.current) {
+            self.pending = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(current: &str) -> SwitcherData {
+        SwitcherData {
+            current: current.into(),
+            pending: None,
+            phase: Phase::Idle,
+            deadline: None,
+        }
+    }
+
+    fn transition_end(to_scene: &str) -> EventType {
+        EventType::TransitionEnd {
+            name: "Fade".to_owned(),
+            ty: "fade_transition".to_owned(),
+            duration: chrono::Duration::milliseconds(300),
+            to_scene: to_scene.into(),
+        }
+    }
+
+    #[test]
+    fn coalesce_pending() {
+        let mut data = data("A");
+
+        data.queue("B".into());
+        assert_eq!(Some(("B".into(), "A".into())), data.next());
+
+        data.queue("C".into());
+        data.queue("D".into());
+        assert_eq!(None, data.next());
+        assert_eq!(Some("D".into()), data.pending);
+
+        data.queue("B".into());
+        assert_eq!(None, data.pending);
+
+        data.queue("A".into());
+        data.apply(transition_end("B"));
+        assert_eq!(Some(("A".into(), "B".into())), data.next());
+    }
+
+    #[test]
+    fn skip_current_scene() {
+        let mut data = data("A");
+
+        data.queue("A".into());
+        assert_eq!(None, data.next());
+
+        data.queue("B".into());
+        data.apply(EventType::SwitchScenes {
+            scene_name: "B".into(),
+            sources: Vec::new(),
+        });
+        assert_eq!(None, data.pending);
+    }
+
+    #[test]
+    fn cut_without_transition_events() {
+        let mut data = data("A");
+
+        data.queue("B".into());
+        assert!(data.next().is_some());
+        data.queue("C".into());
+        assert_eq!(None, data.next());
+
+        data.apply(EventType::SwitchScenes {
+            scene_name: "B".into(),
+            sources: Vec::new(),
+        });
+        let (scene, previous) = data.next().unwrap();
+        assert_eq!("C", scene.as_str());
+
+        data.failed(previous);
+        assert_eq!(Phase::Idle, data.phase);
+        assert_eq!("B", data.current.as_str());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timeout_despite_other_events() {
+        let data = Mutex::new(data("A"));
+        lock(&data).queue("B".into());
+        assert!(lock(&data).next().is_some());
+
+        let start = Instant::now();
+        let inputs = stream::repeat_with(|| Input::Event(Box::new(EventType::StreamStarted))).then(
+            |input| async {
+                time::sleep(Duration::from_secs(7)).await;
+                input
+            },
+        );
+        pin_mut!(inputs);
+
+        loop {
+            match next_input(&mut inputs, &data).await {
+                Some(Input::Event(event)) => lock(&data).apply(*event),
+                Some(Input::Queued) => break,
+                _ => panic!("input stream ended"),
+            }
+        }
+
+        assert_eq!(TRANSITION_TIMEOUT, start.elapsed());
+        assert_eq!(Phase::Idle, lock(&data).phase);
+        assert_eq!(None, lock(&data).deadline);
+    }
+}
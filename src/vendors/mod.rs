@@ -0,0 +1,5 @@
+//! Typed integrations for popular OBS plugins. These are not part of OBS itself, only work if the
+//! plugin is installed, and each one is enabled through its own feature.
+
+#[cfg(feature = "move-transition")]
+pub mod move_transition;
@@ -0,0 +1,131 @@
+//! Settings of the Move Value filter of the [Move Transition] plugin, which animates a single
+//! setting of a source or filter.
+//!
+//! Move Transition doesn't expose value changes as vendor requests, so the filter is configured
+//! and started through the regular filter requests.
+//!
+//! [Move Transition]: https://github.com/exeldro/obs-move-transition
+
+use chrono::Duration;
+use serde::Serialize;
+use serde_with::{serde_as, skip_serializing_none};
+
+use crate::common::{DurationMillis, SourceName};
+use crate::requests::{SourceFilterSettings, SourceKindSettings};
+use crate::{Client, Error, Result};
+
+/// Settings of a Move Value filter, as part of [`SourceKindSettings`]. Fields that are `None` keep
+/// the default value of the plugin.
+#[skip_serializing_none]
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+pub struct MoveValue<'a> {
+    /// Name of the filter whose setting is moved. Empty to move a setting of the source itself.
+    pub filter: Option<&'a str>,
+    /// Name of the setting to move.
+    pub setting_name: Option<&'a str>,
+    /// Target value for integer settings.
+    pub setting_int: Option<i64>,
+    /// Target value for decimal settings.
+    pub setting_float: Option<f64>,
+    /// Duration of the move.
+    #[serde_as(as = "Option<DurationMillis>")]
+    pub duration: Option<Duration>,
+    /// Delay before the move starts.
+    #[serde_as(as = "Option<DurationMillis>")]
+    pub start_delay: Option<Duration>,
+    /// Delay after the move ended.
+    #[serde_as(as = "Option<DurationMillis>")]
+    pub end_delay: Option<Duration>,
+}
+
+impl<'a> MoveValue<'a> {
+    /// Internal kind of the Move Value filter.
+    pub const KIND: &'static str = "move_value_filter";
+}
+
+impl<'a> SourceKindSettings for MoveValue<'a> {
+    fn kind(&self) -> &str {
+        Self::KIND
+    }
+}
+
+/// Target value of a [`MoveValue`] filter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoveTarget {
+    /// Target for integer settings.
+    Int(i64),
+    /// Target for decimal settings.
+    Float(f64),
+}
+
+/// API functions of the Move Transition plugin, accessed through
+/// [`Client::move_transition`](crate::Client::move_transition).
+pub struct MoveTransition<'a> {
+    pub(crate) client: &'a Client,
+}
+
+impl<'a> MoveTransition<'a> {
+    /// Set the target value of an existing Move Value filter and start the move, by enabling the
+    /// filter again. This requires the start trigger of the filter to be "Enable".
+    ///
+    /// - `source_name`: Name of the source the filter belongs to.
+    /// - `filter_name`: Name of the Move Value filter.
+    /// - `target`: The value to move the setting to.
+    pub async fn move_value(
+        &self,
+        source_name: impl Into<SourceName>,
+        filter_name: &str,
+        target: MoveTarget,
+    ) -> Result<()> {
+        let source_name = source_name.into();
+        let settings = match target {
+            MoveTarget::Int(value) => MoveValue {
+                setting_int: Some(value),
+                ..MoveValue::default()
+            },
+            MoveTarget::Float(value) => MoveValue {
+                setting_float: Some(value),
+                ..MoveValue::default()
+            },
+        };
+
+        let sources = self.client.sources();
+        sources
+            .set_source_filter_settings(SourceFilterSettings {
+                source_name: &source_name,
+                filter_name,
+                filter_settings: &serde_json::to_value(&settings)
+                    .map_err(Error::SerializeCustomData)?,
+            })
+            .await?;
+        sources
+            .set_filter_enabled(&source_name, filter_name, false)
+            .await?;
+        sources
+            .set_filter_enabled(&source_name, filter_name, true)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn move_value() {
+        let settings = MoveValue {
+            setting_name: Some("opacity"),
+            setting_float: Some(50.0),
+            duration: Some(Duration::milliseconds(300)),
+            ..MoveValue::default()
+        };
+
+        assert_eq!(
+            json!({ "setting_name": "opacity", "setting_float": 50.0, "duration": 300 }),
+            serde_json::to_value(&settings).unwrap()
+        );
+    }
+}
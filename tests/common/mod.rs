@@ -4,6 +4,7 @@ use std::time::Duration;
 
 use anyhow::{ensure, Result};
 use obws::{
+    common::SourceKind,
     requests::SceneItem,
     responses::{Output, Profile, Scene, SceneCollection, SourceListItem, Transition},
     Client,
@@ -152,7 +153,7 @@ async fn ensure_obs_setup(client: &Client) -> Result<()> {
     client.scenes().set_current_scene(TEST_SCENE).await?;
     client
         .scenes()
-        .reorder_scene_items(Some(TEST_SCENE), SCENE_ORDER)
+        .reorder_scene_items(Some(&TEST_SCENE.into()), SCENE_ORDER)
         .await?;
     client
         .transitions()
@@ -195,15 +196,15 @@ fn is_required_media_source(source: &SourceListItem) -> bool {
 }
 
 fn is_text_input_source(source: &SourceListItem) -> bool {
-    source.ty == "input" && source.type_id == SOURCE_KIND_TEXT_FT2
+    source.ty == "input" && source.type_id == SourceKind::from(SOURCE_KIND_TEXT_FT2)
 }
 
 fn is_browser_input_source(source: &SourceListItem) -> bool {
-    source.ty == "input" && source.type_id == SOURCE_KIND_BROWSER
+    source.ty == "input" && source.type_id == SourceKind::from(SOURCE_KIND_BROWSER)
 }
 
 fn is_media_input_source(source: &SourceListItem) -> bool {
-    source.ty == "input" && source.type_id == SOURCE_KIND_VLC
+    source.ty == "input" && source.type_id == SourceKind::from(SOURCE_KIND_VLC)
 }
 
 fn is_required_profile(profile: &Profile) -> bool {
@@ -0,0 +1,47 @@
+#![cfg(feature = "test-integration")]
+
+use std::env;
+
+use anyhow::{ensure, Result};
+use obws::controller::ObsController;
+
+use common::{TEST_SCENE, TEST_SCENE_2, TEXT_SOURCE};
+
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    common::new_client().await?;
+
+    let host = env::var("OBS_HOST").unwrap_or_else(|_| "localhost".to_owned());
+    let mut obs = ObsController::connect(host, 4444, env::var("OBS_PASSWORD").ok()).await?;
+
+    let original = obs.client().scenes().get_current_scene().await?.name;
+    obs.switch_scene(TEST_SCENE_2).await?;
+    obs.switch_scene(&original).await?;
+
+    obs.set_text(TEXT_SOURCE, "obws controller").await?;
+
+    let scene = TEST_SCENE.into();
+    let text_source = TEXT_SOURCE.into();
+    obs.hide_overlay(Some(&scene), TEXT_SOURCE).await?;
+    obs.show_overlay(Some(&scene), TEXT_SOURCE).await?;
+    ensure!(
+        obs.scene(&scene)
+            .item(&text_source)
+            .properties()
+            .await?
+            .visible,
+        "overlay wasn't shown"
+    );
+
+    obs.reconnect().await?;
+    obs.client().general().get_version().await?;
+    #[cfg(feature = "events")]
+    ensure!(
+        obs.state().current_scene() == original,
+        "state wasn't loaded after reconnect"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "test-integration")]
+
+use anyhow::{ensure, Result};
+use futures_util::{pin_mut, StreamExt};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+const REALM: &str = "obws-test";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Greeting {
+    greeting: String,
+}
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let obs = common::new_client().await?;
+    let client = obs.custom_messages();
+
+    let messages = client.subscribe::<Greeting>(REALM)?;
+    pin_mut!(messages);
+
+    let greeting = Greeting {
+        greeting: "hello".to_owned(),
+    };
+    client
+        .send("other", &serde_json::json! {{"greeting": "ignored"}})
+        .await?;
+    client.send(REALM, &greeting).await?;
+
+    ensure!(
+        messages.next().await.as_ref() == Some(&greeting),
+        "didn't receive the custom message"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::convert::TryFrom;
+
+use chrono::Duration;
+use obws::common::FontFlags;
+use obws::derive::SourceSettings;
+use obws::requests::{SourceKindSettings, RGBA8};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, SourceSettings)]
+#[obs(kind = "my_plugin_source")]
+struct MyPluginSource {
+    url: String,
+    #[obs(rename = "refresh_interval", duration_millis)]
+    refresh: Duration,
+    #[obs(rgba_inverse)]
+    background: Option<RGBA8>,
+    #[obs(bitflags)]
+    flags: Option<FontFlags>,
+    #[obs(default)]
+    width: u32,
+    #[obs(duration_millis, default)]
+    timeout: Duration,
+    height: Option<u32>,
+}
+
+#[derive(SourceSettings)]
+#[obs(kind = "borrowed_source")]
+struct BorrowedSource<'a> {
+    url: &'a str,
+}
+
+#[test]
+fn kind() {
+    assert_eq!("my_plugin_source", MyPluginSource::KIND);
+    assert_eq!("borrowed_source", BorrowedSource { url: "" }.kind());
+}
+
+#[test]
+fn serialize() {
+    let settings = MyPluginSource {
+        url: "https://obsproject.com".to_owned(),
+        refresh: Duration::seconds(2),
+        background: Some(RGBA8::new(1, 2, 3, 4)),
+        flags: Some(FontFlags::BOLD | FontFlags::ITALIC),
+        width: 1920,
+        timeout: Duration::milliseconds(500),
+        height: None,
+    };
+
+    assert_eq!(
+        json!({
+            "url": "https://obsproject.com",
+            "refresh_interval": 2000,
+            "background": 0x0403_0201,
+            "flags": 3,
+            "width": 1920,
+            "timeout": 500,
+        }),
+        serde_json::to_value(&settings).unwrap()
+    );
+    assert_eq!(
+        json!({ "url": "a" }),
+        serde_json::to_value(&BorrowedSource { url: "a" }).unwrap()
+    );
+}
+
+#[test]
+fn deserialize() {
+    let settings = serde_json::from_value::<MyPluginSource>(json!({
+        "url": "https://obsproject.com",
+        "refresh_interval": 1500,
+        "flags": 2,
+        "height": 720,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        MyPluginSource {
+            url: "https://obsproject.com".to_owned(),
+            refresh: Duration::milliseconds(1500),
+            background: None,
+            flags: Some(FontFlags::try_from(2).unwrap()),
+            width: 0,
+            timeout: Duration::zero(),
+            height: Some(720),
+        },
+        settings
+    );
+
+    assert!(serde_json::from_value::<MyPluginSource>(json!({ "url": "" })).is_err());
+}
@@ -1,7 +1,7 @@
 #![cfg(feature = "test-integration")]
 
-use anyhow::Result;
-use obws::requests::{Projector, ProjectorType, QtGeometry, QtRect};
+use anyhow::{ensure, Result};
+use obws::requests::{Key, KeyModifiers, Projector, ProjectorType, QtGeometry, QtRect};
 use serde_json::json;
 
 mod common;
@@ -21,11 +21,24 @@ async fn main() -> Result<()> {
 
     client.get_stats().await?;
 
+    client.set_heartbeat(true).await?;
+    client.set_heartbeat(false).await?;
+
     client
         .broadcast_custom_message("test", &json! {{"greeting":"hello"}})
         .await?;
 
-    client.get_video_info().await?;
+    client.announce_presence("obws-test").await?;
+    client.withdraw_presence("obws-test").await?;
+
+    let video = client.get_video_info().await?;
+    let (x, y) = video.base_to_output(100.0, 50.0);
+    let (bx, by) = video.output_to_base(x, y);
+    ensure!(
+        (bx - 100.0).abs() < 1e-6 && (by - 50.0).abs() < 1e-6,
+        "coordinate conversion doesn't round-trip"
+    );
+    ensure!(video.frame_interval() > chrono::Duration::zero());
 
     // Currently no API function available to close the projector again.
     client
@@ -40,9 +53,19 @@ async fn main() -> Result<()> {
             ..Default::default()
         })
         .await?;
+    client
+        .open_projector(Projector {
+            ty: Some(ProjectorType::Preview),
+            geometry: Some(&QtGeometry::from_size(100, 100, 200, 200).maximized()),
+            ..Default::default()
+        })
+        .await?;
 
     client.trigger_hotkey_by_name("ReplayBuffer.Save").await?;
     client.trigger_hotkey_by_sequence("OBS_KEY_P", &[]).await?;
+    client
+        .trigger_hotkey_sequence(Key::P, KeyModifiers::empty())
+        .await?;
 
     Ok(())
 }
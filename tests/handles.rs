@@ -0,0 +1,39 @@
+#![cfg(feature = "test-integration")]
+
+use anyhow::Result;
+use obws::common::{SceneName, SourceName};
+use obws::requests::SceneItemTransform;
+
+use common::{TEST_SCENE, TEXT_SOURCE};
+
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let client = common::new_client().await?;
+
+    let scene_name = SceneName::from(TEST_SCENE);
+    let text_source = SourceName::from(TEXT_SOURCE);
+
+    let scene = client.scene(&scene_name);
+    scene.items().await?;
+
+    let item = scene.item(&text_source);
+    let props = item.properties().await?;
+    item.hide().await?;
+    item.show().await?;
+    item.set_transform(
+        SceneItemTransform::new()
+            .position(props.position.x, props.position.y)
+            .alignment(props.position.alignment),
+    )
+    .await?;
+
+    let source = client.source(&text_source);
+    source.mute().await?;
+    source.unmute().await?;
+    source.set_volume_db(0.0).await?;
+    source.settings::<serde_json::Value>().await?;
+
+    Ok(())
+}
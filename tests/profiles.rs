@@ -5,7 +5,7 @@ use std::time::Duration;
 use anyhow::Result;
 use tokio::time;
 
-use common::TEST_PROFILE;
+use common::{TEST_PROFILE, TEST_SCENE};
 
 mod common;
 
@@ -24,5 +24,15 @@ async fn main() -> Result<()> {
 
     client.set_current_profile(&original).await?;
 
+    // Give OBS some time to switch profiles
+    time::sleep(Duration::from_millis(200)).await;
+
+    client
+        .set_current_profile_rescaled(TEST_PROFILE, &[TEST_SCENE.into()])
+        .await?;
+    client
+        .set_current_profile_rescaled(&original, &[TEST_SCENE.into()])
+        .await?;
+
     Ok(())
 }
@@ -21,19 +21,19 @@ async fn main() -> Result<()> {
     client.get_replay_buffer_status().await?;
 
     client.start_stop_replay_buffer().await?;
-    wait_for!(events, EventType::ReplayStarted { .. });
+    wait_for!(events, EventType::ReplayStarted);
     client.start_stop_replay_buffer().await?;
-    wait_for!(events, EventType::ReplayStopped { .. });
+    wait_for!(events, EventType::ReplayStopped);
 
     // Wait a little more as the replay buffer sometimes doesn't start when started/stopped
     // frequently.
     time::sleep(Duration::from_secs(1)).await;
 
     client.start_replay_buffer().await?;
-    wait_for!(events, EventType::ReplayStarted { .. });
+    wait_for!(events, EventType::ReplayStarted);
     client.save_replay_buffer().await?;
     client.stop_replay_buffer().await?;
-    wait_for!(events, EventType::ReplayStopped { .. });
+    wait_for!(events, EventType::ReplayStopped);
 
     Ok(())
 }
@@ -30,5 +30,8 @@ async fn main() -> Result<()> {
 
     client.set_current_scene_collection(&original).await?;
 
+    let dump = client.export(&other).await?;
+    client.import(&dump).await?;
+
     Ok(())
 }
@@ -2,11 +2,16 @@
 
 use anyhow::Result;
 use either::Either;
-use obws::requests::{
-    DuplicateSceneItem, SceneItemProperties, SceneItemRender, SceneItemSpecification,
+use obws::{
+    common::{SceneName, SourceName},
+    requests::{
+        Corner, DuplicateSceneItem, ItemRef, SceneItemProperties, SceneItemRender,
+        SceneItemSpecification, SceneItemTransform,
+    },
+    responses::SceneItemListRef,
 };
 
-use common::{TEST_SCENE, TEXT_SOURCE};
+use common::{TEST_SCENE, TEST_SCENE_2, TEXT_SOURCE};
 
 mod common;
 
@@ -14,18 +19,44 @@ mod common;
 async fn main() -> Result<()> {
     let client = common::new_client().await?;
     let client = client.scene_items();
+    let scene = SceneName::from(TEST_SCENE);
+    let scene_2 = SceneName::from(TEST_SCENE_2);
+    let text_source = SourceName::from(TEXT_SOURCE);
 
-    client.get_scene_item_list(Some(TEST_SCENE)).await?;
+    client.get_scene_item_list(Some((&scene).into())).await?;
+    client
+        .get_scene_item_list_raw(Some((&scene).into()))
+        .await?
+        .parse::<SceneItemListRef<'_>>()?
+        .into_owned();
 
     let props = client
-        .get_scene_item_properties(Some(TEST_SCENE), Either::Left(TEXT_SOURCE))
+        .get_scene_item_properties(Some((&scene).into()), Either::Left(TEXT_SOURCE))
+        .await?;
+    client
+        .reset_scene_item(Some((&scene).into()), Either::Left(TEXT_SOURCE))
+        .await?;
+    let snapshots = client
+        .capture_scene_items((&scene).into(), &[ItemRef::ByName(TEXT_SOURCE)])
+        .await?;
+    client
+        .fit_to_canvas(Some((&scene).into()), Either::Left(TEXT_SOURCE))
+        .await?;
+    client
+        .center(Some((&scene).into()), Either::Left(TEXT_SOURCE))
         .await?;
     client
-        .reset_scene_item(Some(TEST_SCENE), Either::Left(TEXT_SOURCE))
+        .anchor(
+            Some((&scene).into()),
+            Either::Left(TEXT_SOURCE),
+            Corner::BottomRight,
+            10.0,
+        )
         .await?;
+    client.restore_scene_items(&snapshots).await?;
     client
         .set_scene_item_properties(SceneItemProperties {
-            scene_name: Some(TEST_SCENE),
+            scene_name: Some((&scene).into()),
             item: Either::Left(TEXT_SOURCE),
             position: Some((&props.position).into()),
             rotation: Some(props.rotation),
@@ -37,18 +68,31 @@ async fn main() -> Result<()> {
         })
         .await?;
 
+    client
+        .set_scene_item_transform(
+            Some((&scene).into()),
+            Either::Left(TEXT_SOURCE),
+            SceneItemTransform::new()
+                .position(props.position.x, props.position.y)
+                .alignment(props.position.alignment)
+                .rotation(props.rotation)
+                .scale(props.scale.x, props.scale.y)
+                .crop((&props.crop).into()),
+        )
+        .await?;
+
     client
         .set_scene_item_render(SceneItemRender {
-            scene_name: Some(TEST_SCENE),
-            source: TEXT_SOURCE,
+            scene_name: Some((&scene).into()),
+            source: &text_source,
             item: None,
             render: !props.visible,
         })
         .await?;
     client
         .set_scene_item_render(SceneItemRender {
-            scene_name: Some(TEST_SCENE),
-            source: TEXT_SOURCE,
+            scene_name: Some((&scene).into()),
+            source: &text_source,
             item: None,
             render: props.visible,
         })
@@ -56,8 +100,8 @@ async fn main() -> Result<()> {
 
     let item = client
         .duplicate_scene_item(DuplicateSceneItem {
-            from_scene: Some(TEST_SCENE),
-            to_scene: Some(TEST_SCENE),
+            from_scene: Some(&scene),
+            to_scene: Some(&scene),
             item: SceneItemSpecification {
                 id: None,
                 name: Some(TEXT_SOURCE),
@@ -66,7 +110,7 @@ async fn main() -> Result<()> {
         .await?;
     client
         .delete_scene_item(
-            Some(TEST_SCENE),
+            Some((&scene).into()),
             SceneItemSpecification {
                 id: Some(item.item.id),
                 name: None,
@@ -74,6 +118,26 @@ async fn main() -> Result<()> {
         )
         .await?;
 
+    let id = client
+        .duplicate_scene_item_to(
+            TEST_SCENE,
+            SceneItemSpecification {
+                id: None,
+                name: Some(TEXT_SOURCE),
+            },
+            TEST_SCENE_2,
+        )
+        .await?;
+    client
+        .delete_scene_item(
+            Some((&scene_2).into()),
+            SceneItemSpecification {
+                id: Some(id),
+                name: None,
+            },
+        )
+        .await?;
+
     // TODO: Need to create a source first, but there is no way to delete it afterwards.
     // Therefore, we don't call this function until a method becomes available.
     //client.add_scene_item(AddSceneItem{});
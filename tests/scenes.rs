@@ -1,8 +1,8 @@
 #![cfg(feature = "test-integration")]
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use chrono::Duration;
-use obws::requests::{SceneItem, SceneTransitionOverride};
+use obws::requests::{ItemRef, SceneItem, SceneTransitionOverride};
 
 use common::{
     TEST_BROWSER, TEST_MEDIA, TEST_SCENE, TEST_SCENE_2, TEST_TRANSITION, TEXT_SOURCE, TEXT_SOURCE_2,
@@ -21,13 +21,25 @@ async fn main() -> Result<()> {
 
     client.get_scene_list().await?;
 
+    let graph = client.snapshot().await?;
+    ensure!(
+        graph.scenes.iter().any(|scene| scene.name == TEST_SCENE),
+        "test scene missing in snapshot"
+    );
+
+    for group in client.get_group_list().await? {
+        let settings = client.get_group_settings(&group).await?;
+        client.set_group_settings(&group, &settings).await?;
+        client.get_group_children(&group).await?;
+    }
+
     // TODO: Currently no way of deleting scenes so we skip this to not
     // fill up OBS with random scenes on every run.
     // client.create_scene("__TEMP").await?;
 
     client
         .reorder_scene_items(
-            Some(TEST_SCENE),
+            Some(&TEST_SCENE.into()),
             &[
                 SceneItem {
                     id: None,
@@ -48,9 +60,33 @@ async fn main() -> Result<()> {
             ],
         )
         .await?;
+    client
+        .move_to_top(TEST_SCENE, ItemRef::ByName(TEXT_SOURCE))
+        .await?;
+    client
+        .move_above(
+            TEST_SCENE,
+            ItemRef::ByName(TEST_MEDIA),
+            ItemRef::ByName(TEXT_SOURCE_2),
+        )
+        .await?;
+    client
+        .move_to_bottom(TEST_SCENE, ItemRef::ByName(TEST_BROWSER))
+        .await?;
+    client
+        .reorder_items(
+            TEST_SCENE,
+            &[
+                TEXT_SOURCE.into(),
+                TEXT_SOURCE_2.into(),
+                TEST_BROWSER.into(),
+                TEST_MEDIA.into(),
+            ],
+        )
+        .await?;
     client
         .reorder_scene_items(
-            Some(TEST_SCENE),
+            Some(&TEST_SCENE.into()),
             &[
                 SceneItem {
                     id: None,
@@ -74,7 +110,7 @@ async fn main() -> Result<()> {
 
     client
         .set_scene_transition_override(SceneTransitionOverride {
-            scene_name: TEST_SCENE,
+            scene_name: &TEST_SCENE.into(),
             transition_name: TEST_TRANSITION,
             transition_duration: Some(Duration::milliseconds(10)),
         })
@@ -0,0 +1,49 @@
+#![cfg(feature = "test-integration")]
+
+use anyhow::{ensure, Result};
+use either::Either;
+use obws::{
+    requests::SceneOrGroup,
+    setup::{ItemSetup, SceneSetup, Setup, TransformSetup},
+};
+
+use common::{TEST_SCENE, TEXT_SOURCE};
+
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let client = common::new_client().await?;
+
+    let props = client
+        .scene_items()
+        .get_scene_item_properties(
+            Some(SceneOrGroup::Scene(&TEST_SCENE.into())),
+            Either::Left(TEXT_SOURCE),
+        )
+        .await?;
+
+    // Describe the current state, so applying it must not change anything.
+    let setup = Setup {
+        scenes: vec![SceneSetup {
+            name: TEST_SCENE.into(),
+            items: vec![ItemSetup {
+                source: TEXT_SOURCE.into(),
+                kind: None,
+                settings: None,
+                filters: Vec::new(),
+                transform: Some(TransformSetup {
+                    position: Some((props.position.x, props.position.y)),
+                    rotation: Some(props.rotation),
+                    ..TransformSetup::default()
+                }),
+                visible: Some(props.visible),
+            }],
+        }],
+    };
+
+    let changes = setup.apply(&client).await?;
+    ensure!(changes.is_empty(), "unexpected changes: {:?}", changes);
+
+    Ok(())
+}
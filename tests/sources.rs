@@ -1,17 +1,18 @@
 #![cfg(feature = "test-integration")]
 
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
 use chrono::Duration;
 use obws::{
-    common::MonitorType,
+    common::{Color, MonitorType, SourceName, TrackMask, Volume},
     requests::{
-        AddFilter, MoveFilter, ReorderFilter, SourceFilterSettings, SourceFilterVisibility,
-        SourceScreenshot, SourceSettings, Volume,
+        AddFilter, CustomSource, MoveFilter, MovementType, ReorderFilter, SourceFilterSettings,
+        SourceFilterVisibility, SourceScreenshot, SourceSettings, SourceSettingsPatch, TextSource,
     },
+    responses::SourceSettingsRef,
 };
-use serde_json::json;
+use serde_json::{json, value::RawValue};
 
-use common::{SOURCE_KIND_VLC, TEST_BROWSER, TEST_MEDIA, TEXT_SOURCE};
+use common::{SOURCE_KIND_TEXT_FT2, SOURCE_KIND_VLC, TEST_BROWSER, TEST_MEDIA, TEXT_SOURCE};
 
 mod common;
 
@@ -19,36 +20,32 @@ mod common;
 async fn main() -> Result<()> {
     let client = common::new_client().await?;
     let client = client.sources();
+    let text_source = SourceName::from(TEXT_SOURCE);
 
     client.get_media_sources_list().await?;
     client.get_sources_list().await?;
     client.get_sources_types_list().await?;
 
     client.get_audio_active(TEST_MEDIA).await?;
-    client.get_source_default_settings(SOURCE_KIND_VLC).await?;
+    client.get_source_active(TEXT_SOURCE).await?;
+    client
+        .get_source_default_settings::<serde_json::Value>(SOURCE_KIND_VLC)
+        .await?;
+    client
+        .merge_default_settings(SOURCE_KIND_VLC, &json!({ "loop": true }))
+        .await?;
 
     client.refresh_browser_source(TEST_BROWSER).await?;
 
     // Volume
 
-    let original = client.get_volume(TEXT_SOURCE, None).await?.volume;
-    client.get_volume(TEXT_SOURCE, Some(true)).await?;
-
-    client
-        .set_volume(Volume {
-            source: TEXT_SOURCE,
-            volume: 0.5,
-            use_decibel: None,
-        })
-        .await?;
-
+    let original = client.get_volume(TEXT_SOURCE).await?.volume;
+    client.set_volume(TEXT_SOURCE, Volume::mul(0.5)).await?;
+    client.set_volume(TEXT_SOURCE, Volume::db(-6.0)).await?;
     client
-        .set_volume(Volume {
-            source: TEXT_SOURCE,
-            volume: original,
-            use_decibel: None,
-        })
+        .fade_volume(TEXT_SOURCE, Volume::mul(0.5), Duration::milliseconds(200))
         .await?;
+    client.set_volume(TEXT_SOURCE, original).await?;
 
     // Mute
 
@@ -82,6 +79,49 @@ async fn main() -> Result<()> {
             source_settings: &settings.source_settings,
         })
         .await?;
+    let raw = client
+        .get_source_settings::<Box<RawValue>>(TEXT_SOURCE, None)
+        .await?;
+    client
+        .set_source_settings::<Box<RawValue>>(SourceSettings {
+            source_name: &raw.source_name,
+            source_type: Some(&raw.source_type),
+            source_settings: &*raw.source_settings,
+        })
+        .await?;
+    let response = client.get_source_settings_raw(TEXT_SOURCE, None).await?;
+    let borrowed = response.parse::<SourceSettingsRef<'_>>()?;
+    ensure!(
+        borrowed.source_name == TEXT_SOURCE,
+        "borrowed settings of another source"
+    );
+    borrowed.into_owned();
+    client.set_text(TEXT_SOURCE, "obws test").await?;
+    client
+        .set_text_source(
+            TEXT_SOURCE,
+            TextSource {
+                color: Some(Color::rgb(255, 255, 255)),
+                outline: Some(false),
+                ..TextSource::default()
+            },
+        )
+        .await?;
+    client
+        .patch_source_settings::<_, serde_json::Value>(SourceSettingsPatch {
+            source_name: &text_source,
+            source_settings: &CustomSource {
+                kind: SOURCE_KIND_TEXT_FT2,
+                settings: json!({ "text": settings.source_settings["text"] }),
+            },
+        })
+        .await?;
+    client
+        .sync_source_settings(
+            TEXT_SOURCE,
+            &json!({ "text": settings.source_settings["text"] }),
+        )
+        .await?;
 
     // TODO: GDI+ only on windows?
 
@@ -95,6 +135,7 @@ async fn main() -> Result<()> {
     // Special sources
 
     client.get_special_sources().await?;
+    client.resolve_special_sources().await?;
 
     // Filters
     const FILTER1: &str = "Scroll-Test1";
@@ -103,7 +144,7 @@ async fn main() -> Result<()> {
     client.get_source_filters(TEXT_SOURCE).await?;
     client
         .add_filter_to_source(AddFilter {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER1,
             filter_type: "scroll_filter",
             filter_settings: &json! {{
@@ -118,7 +159,7 @@ async fn main() -> Result<()> {
         .await?;
     client
         .add_filter_to_source(AddFilter {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER2,
             filter_type: "scroll_filter",
             filter_settings: &json! {{
@@ -131,21 +172,21 @@ async fn main() -> Result<()> {
 
     client
         .reorder_source_filter(ReorderFilter {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER1,
             new_index: 1,
         })
         .await?;
     client
         .move_source_filter(MoveFilter {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER1,
-            movement_type: obws::requests::MovementType::Up,
+            movement_type: MovementType::Up,
         })
         .await?;
     client
         .set_source_filter_settings(SourceFilterSettings {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER1,
             filter_settings: &json! {{
                 "limit_cx": false,
@@ -156,11 +197,20 @@ async fn main() -> Result<()> {
         .await?;
     client
         .set_source_filter_visibility(SourceFilterVisibility {
-            source_name: TEXT_SOURCE,
+            source_name: &text_source,
             filter_name: FILTER1,
             filter_enabled: false,
         })
         .await?;
+    client
+        .set_filter_enabled(TEXT_SOURCE, FILTER1, true)
+        .await?;
+    client
+        .move_filter(TEXT_SOURCE, FILTER2, MovementType::Bottom)
+        .await?;
+    client
+        .get_source_filters_as::<serde_json::Value>(TEXT_SOURCE)
+        .await?;
 
     client
         .remove_filter_from_source(TEXT_SOURCE, FILTER1)
@@ -169,6 +219,14 @@ async fn main() -> Result<()> {
         .remove_filter_from_source(TEXT_SOURCE, FILTER2)
         .await?;
 
+    // Audio tracks
+
+    let original = client.get_audio_tracks(TEXT_SOURCE).await?;
+    client
+        .set_audio_tracks(TEXT_SOURCE, TrackMask::TRACK_1 | TrackMask::TRACK_3)
+        .await?;
+    client.set_audio_tracks(TEXT_SOURCE, original).await?;
+
     // Audio monitor type
 
     let source = client
@@ -187,7 +245,7 @@ async fn main() -> Result<()> {
 
     client
         .take_source_screenshot(SourceScreenshot {
-            source_name: Some(TEXT_SOURCE),
+            source_name: Some(&text_source),
             embed_picture_format: Some("png"),
             width: Some(10),
             ..Default::default()
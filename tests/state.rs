@@ -0,0 +1,72 @@
+#![cfg(feature = "test-integration")]
+
+use std::time::Duration;
+
+use anyhow::{ensure, Result};
+use futures_util::{pin_mut, StreamExt};
+use obws::{
+    events::{Event, EventType},
+    state::{ObsState, Renamed},
+};
+use tokio::time;
+
+use common::{TEST_SCENE, TEST_SCENE_2, TEXT_SOURCE};
+
+#[macro_use]
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let client = common::new_client().await?;
+    let events = client.events()?;
+    let state = ObsState::new(&client).await?;
+
+    pin_mut!(events);
+
+    ensure!(state.scene(TEST_SCENE).is_some(), "test scene not cached");
+    ensure!(
+        state.source(TEXT_SOURCE).is_some(),
+        "text source not cached"
+    );
+
+    let original = state.current_scene();
+    client.scenes().set_current_scene(TEST_SCENE_2).await?;
+    wait_for!(events, EventType::SwitchScenes { .. });
+    time::sleep(Duration::from_millis(100)).await;
+    ensure!(
+        state.current_scene() == TEST_SCENE_2,
+        "scene switch not tracked"
+    );
+    client.scenes().set_current_scene(&original).await?;
+
+    let muted = state.is_muted(TEXT_SOURCE).unwrap_or_default();
+    client.sources().set_mute(TEXT_SOURCE, !muted).await?;
+    wait_for!(events, EventType::SourceMuteStateChanged { .. });
+    time::sleep(Duration::from_millis(100)).await;
+    ensure!(
+        state.is_muted(TEXT_SOURCE) == Some(!muted),
+        "mute change not tracked"
+    );
+    client.sources().set_mute(TEXT_SOURCE, muted).await?;
+
+    let renames = state.renames();
+    pin_mut!(renames);
+    let renamed = format!("{}-renamed", TEST_SCENE_2);
+    state
+        .rename_scene(&client, TEST_SCENE_2, renamed.as_str())
+        .await?;
+    ensure!(
+        state.scene(&renamed).is_some() && state.scene(TEST_SCENE_2).is_none(),
+        "scene rename not applied"
+    );
+    ensure!(
+        matches!(renames.next().await, Some(Renamed::Scene { new, .. }) if new == renamed),
+        "scene rename not reported"
+    );
+    state.rename_scene(&client, renamed, TEST_SCENE_2).await?;
+
+    ensure!(!state.is_stale(), "state unexpectedly stale");
+    state.refresh(&client).await?;
+
+    Ok(())
+}
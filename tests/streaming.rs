@@ -0,0 +1,31 @@
+#![cfg(feature = "test-integration")]
+
+use anyhow::Result;
+use chrono::Duration;
+use obws::requests::StreamSettingsPatch;
+
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let client = common::new_client().await?;
+    let client = client.streaming();
+
+    client.get_streaming_status().await?;
+    let settings = client.get_stream_settings().await?;
+    client
+        .patch_stream_settings(StreamSettingsPatch {
+            server: Some(&settings.settings.server),
+            ..Default::default()
+        })
+        .await?;
+
+    client.send_captions("obws test").await?;
+
+    let mut feeder = client.caption_feeder(Duration::milliseconds(10));
+    feeder
+        .push("a caption that is too long to fit into a single row")
+        .await?;
+
+    Ok(())
+}
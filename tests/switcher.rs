@@ -0,0 +1,39 @@
+#![cfg(feature = "test-integration")]
+
+use std::time::Duration;
+
+use anyhow::{ensure, Result};
+use obws::switcher::SceneSwitcher;
+use tokio::time;
+
+use common::{TEST_SCENE, TEST_SCENE_2};
+
+#[macro_use]
+mod common;
+
+#[tokio::test]
+async fn main() -> Result<()> {
+    let client = common::new_client().await?;
+    let switcher = SceneSwitcher::new(&client).await?;
+
+    let original = switcher.current_scene();
+    switcher.switch_to(TEST_SCENE_2);
+    switcher.switch_to(TEST_SCENE);
+    switcher.switch_to(TEST_SCENE_2);
+
+    time::timeout(Duration::from_secs(5), async {
+        while switcher.is_transitioning() || switcher.pending().is_some() {
+            time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await?;
+
+    ensure!(
+        client.scenes().get_current_scene().await?.name == TEST_SCENE_2,
+        "scene changes not applied"
+    );
+
+    switcher.switch_to(original);
+
+    Ok(())
+}
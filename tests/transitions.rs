@@ -21,6 +21,11 @@ async fn main() -> Result<()> {
     client.set_transition_duration(original * 2).await?;
     client.set_transition_duration(original).await?;
 
+    let transition = client.get_current_transition().await?.name;
+    client
+        .set_current_transition_with_duration(&transition, original)
+        .await?;
+
     client.get_transition_position().await?;
     let settings = client.get_transition_settings(TEST_TRANSITION_2).await?;
     client
@@ -31,6 +36,12 @@ async fn main() -> Result<()> {
     client.set_t_bar_position(0.5, Some(false)).await?;
     client.set_t_bar_position(0.0, Some(false)).await?;
     client.release_t_bar().await?;
+
+    let mut t_bar = client.t_bar();
+    t_bar.set_position(0.5).await?;
+    t_bar.set_position(0.0).await?;
+    t_bar.release().await?;
+
     studio_mode.disable_studio_mode().await?;
 
     Ok(())